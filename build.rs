@@ -0,0 +1,55 @@
+//! Embeds build metadata for `bypass --version --json`.
+//!
+//! Sets `BYPASS_GIT_COMMIT` (short hash, or "unknown" outside a git
+//! checkout) and `BYPASS_BUILD_DATE` (UTC, `YYYY-MM-DD`; honours
+//! `SOURCE_DATE_EPOCH` for reproducible builds).
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".into());
+
+    let epoch = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|s| s.parse::<i64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default()
+        });
+
+    println!("cargo:rustc-env=BYPASS_GIT_COMMIT={commit}");
+    println!("cargo:rustc-env=BYPASS_BUILD_DATE={}", civil_date(epoch));
+    println!(
+        "cargo:rustc-env=BYPASS_BUILD_TARGET={}",
+        std::env::var("TARGET").unwrap_or_default()
+    );
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+}
+
+/// Convert seconds since the Unix epoch to a `YYYY-MM-DD` date (UTC).
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_date(epoch_secs: i64) -> String {
+    let z = epoch_secs.div_euclid(86_400) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
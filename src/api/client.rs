@@ -1,54 +1,195 @@
-use std::time::Duration;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, RequestBuilder};
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
 
 use super::models::*;
+use crate::cli::AuthHeader;
 use crate::error::BypassError;
+use crate::resolver::fold_name;
 
-const BASE_URL: &str = "https://api.app.shortcut.com/api/v3";
+pub const BASE_URL: &str = "https://api.app.shortcut.com/api/v3";
 
 /// Retryable HTTP status codes.
-const RETRYABLE: &[u16] = &[429, 500, 503, 504];
+pub const RETRYABLE: &[u16] = &[429, 500, 503, 504];
 /// Maximum number of retry attempts (not counting the initial request).
-const MAX_RETRIES: u32 = 5;
+pub const MAX_RETRIES: u32 = 5;
 /// Base delay for exponential backoff.
-const BASE_DELAY: Duration = Duration::from_secs(1);
+pub const BASE_DELAY: Duration = Duration::from_secs(1);
 /// Upper bound on any single backoff delay.
-const MAX_DELAY: Duration = Duration::from_secs(30);
+pub const MAX_DELAY: Duration = Duration::from_secs(30);
+/// Results per page for the `GET /search/*` endpoints (the API maximum).
+const SEARCH_PAGE_SIZE: &str = "25";
 
 pub struct ShortcutClient {
     http: Client,
+    /// [`BASE_URL`], or a mock server's in tests.
+    base_url: String,
     token: String,
+    auth_header: AuthHeader,
+    retried_requests: AtomicU64,
+    retry_attempts: AtomicU64,
+    backoff_ms: AtomicU64,
+    /// Rate-limit gate: after any 429, no request is dispatched before this
+    /// instant, so concurrent requests back off together.
+    paused_until: Mutex<Option<Instant>>,
+}
+
+/// Timing of a single logical request across its retry attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct AttemptTiming {
+    /// Round-trip time of the first attempt alone.
+    pub first_attempt: Duration,
+    /// Attempts made, including the first.
+    pub attempts: u32,
+}
+
+/// Result of [`ShortcutClient::ping`].
+#[derive(Debug, Clone, Copy)]
+pub struct Ping {
+    /// HTTP status of the final attempt.
+    pub status: u16,
+    /// Wall-clock time including any retries and backoff.
+    pub total: Duration,
+    pub timing: AttemptTiming,
+}
+
+/// Snapshot of the client's retry counters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetryStats {
+    /// Requests that needed at least one retry.
+    pub retried_requests: u64,
+    /// Total retry attempts across all requests.
+    pub retry_attempts: u64,
+    /// Total time spent sleeping between attempts.
+    pub backoff: Duration,
+}
+
+/// How requests are sent, from the global command-line options.
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// `--auth-header`.
+    pub auth_header: AuthHeader,
+    /// `--header`: extra headers sent with every request, already checked
+    /// by [`parse_header`].
+    pub headers: Vec<(String, String)>,
+}
+
+/// Headers bypass sets itself, which `--header` may not override.
+const RESERVED_HEADERS: &[&str] = &["shortcut-token", "authorization", "user-agent"];
+
+/// Parse a `--header "Name: Value"` argument.
+pub fn parse_header(s: &str) -> Result<(String, String), String> {
+    let Some((name, value)) = s.split_once(':') else {
+        return Err(format!("expected 'Name: Value', got '{s}'"));
+    };
+    let (name, value) = (name.trim(), value.trim());
+    HeaderName::from_bytes(name.as_bytes()).map_err(|_| format!("invalid header name '{name}'"))?;
+    HeaderValue::from_str(value).map_err(|_| format!("invalid value for header '{name}'"))?;
+    if RESERVED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+        return Err(format!(
+            "'{name}' is set by bypass and cannot be overridden (see --auth-header)"
+        ));
+    }
+    Ok((name.to_string(), value.to_string()))
 }
 
 impl ShortcutClient {
-    pub fn new(token: String) -> Result<Self> {
+    pub fn new(token: String, opts: &ClientOptions) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &opts.headers {
+            headers.append(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
         let http = Client::builder()
             .user_agent(concat!("bypass-cli/", env!("CARGO_PKG_VERSION")))
+            .default_headers(headers)
             .build()?;
-        Ok(Self { http, token })
+        Ok(Self {
+            http,
+            base_url: BASE_URL.to_string(),
+            token,
+            auth_header: opts.auth_header,
+            retried_requests: AtomicU64::new(0),
+            retry_attempts: AtomicU64::new(0),
+            backoff_ms: AtomicU64::new(0),
+            paused_until: Mutex::new(None),
+        })
+    }
+
+    /// Retry counters accumulated since the client was created.
+    pub fn retry_stats(&self) -> RetryStats {
+        RetryStats {
+            retried_requests: self.retried_requests.load(Ordering::Relaxed),
+            retry_attempts: self.retry_attempts.load(Ordering::Relaxed),
+            backoff: Duration::from_millis(self.backoff_ms.load(Ordering::Relaxed)),
+        }
     }
 
     // ------------------------------------------------------------------
     // Internal helpers
     // ------------------------------------------------------------------
 
+    /// Wait until the rate-limit gate opens.  The gate may be pushed back by
+    /// another request while waiting, so re-check after each sleep.
+    async fn wait_for_gate(&self) {
+        loop {
+            let until = *self.paused_until.lock().unwrap();
+            match until.map(|u| u.saturating_duration_since(Instant::now())) {
+                Some(wait) if !wait.is_zero() => tokio::time::sleep(wait).await,
+                _ => return,
+            }
+        }
+    }
+
+    /// Close the rate-limit gate for `delay` (never shortening a pause set
+    /// by another request).
+    fn pause_all(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut gate = self.paused_until.lock().unwrap();
+        if gate.is_none_or(|current| current < until) {
+            *gate = Some(until);
+        }
+    }
+
     /// Execute a cloneable request, retrying on transient errors with
-    /// exponential backoff.  Honors the `Retry-After` header on 429s.
+    /// exponential backoff.  Honors the `Retry-After` header on 429s, which
+    /// pauses every request made through this client, not just this one.
     async fn send_with_retry(&self, req: reqwest::Request) -> Result<reqwest::Response> {
+        self.send_with_retry_timed(req).await.map(|(resp, _)| resp)
+    }
+
+    /// [`send_with_retry`](Self::send_with_retry), also reporting how long
+    /// the first attempt took and how many attempts were made.
+    async fn send_with_retry_timed(
+        &self,
+        req: reqwest::Request,
+    ) -> Result<(reqwest::Response, AttemptTiming)> {
         let mut attempt = 0u32;
+        let mut first_attempt = None;
         loop {
             let cloned = req
                 .try_clone()
                 .ok_or_else(|| anyhow::anyhow!("request body is not cloneable"))?;
+            self.wait_for_gate().await;
+            let started = Instant::now();
             let resp = self.http.execute(cloned).await?;
+            let first_attempt = *first_attempt.get_or_insert(started.elapsed());
             let status = resp.status().as_u16();
 
             if !RETRYABLE.contains(&status) || attempt >= MAX_RETRIES {
-                return Ok(resp);
+                let timing = AttemptTiming {
+                    first_attempt,
+                    attempts: attempt + 1,
+                };
+                return Ok((resp, timing));
             }
 
             let delay = if status == 429 {
@@ -64,16 +205,47 @@ impl ShortcutClient {
             }
             .min(MAX_DELAY);
 
+            if attempt == 0 {
+                self.retried_requests.fetch_add(1, Ordering::Relaxed);
+            }
+            self.retry_attempts.fetch_add(1, Ordering::Relaxed);
+            self.backoff_ms
+                .fetch_add(delay.as_millis() as u64, Ordering::Relaxed);
+
             attempt += 1;
-            tokio::time::sleep(delay).await;
+            if status == 429 {
+                // Sleep at the gate (next iteration) along with everyone else.
+                self.pause_all(delay);
+            } else {
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    /// Attach the token in the header selected by `--auth-header`.
+    fn authorize(&self, req: RequestBuilder) -> RequestBuilder {
+        match self.auth_header {
+            AuthHeader::ShortcutToken => req.header("Shortcut-Token", &self.token),
+            AuthHeader::Bearer => req.bearer_auth(&self.token),
         }
     }
 
     async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         let req = self
-            .http
-            .get(format!("{BASE_URL}{path}"))
-            .header("Shortcut-Token", &self.token)
+            .authorize(self.http.get(format!("{}{path}", self.base_url)))
+            .build()?;
+        let resp = self.send_with_retry(req).await?;
+        self.handle_response(resp).await
+    }
+
+    async fn get_query<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        let req = self
+            .authorize(self.http.get(format!("{}{path}", self.base_url)))
+            .query(query)
             .build()?;
         let resp = self.send_with_retry(req).await?;
         self.handle_response(resp).await
@@ -81,20 +253,45 @@ impl ShortcutClient {
 
     async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
         let req = self
-            .http
-            .post(format!("{BASE_URL}{path}"))
-            .header("Shortcut-Token", &self.token)
+            .authorize(self.http.post(format!("{}{path}", self.base_url)))
+            .json(body)
+            .build()?;
+        let resp = self.send_with_retry(req).await?;
+        self.handle_response(resp).await
+    }
+
+    async fn put<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        let req = self
+            .authorize(self.http.put(format!("{}{path}", self.base_url)))
             .json(body)
             .build()?;
         let resp = self.send_with_retry(req).await?;
         self.handle_response(resp).await
     }
 
+    /// `DELETE`, which answers with an empty body on success.
+    async fn delete(&self, path: &str) -> Result<()> {
+        let req = self
+            .authorize(self.http.delete(format!("{}{path}", self.base_url)))
+            .build()?;
+        let resp = self.send_with_retry(req).await?;
+        if resp.status().is_success() {
+            return Ok(());
+        }
+        self.handle_response::<Value>(resp).await.map(|_| ())
+    }
+
     async fn handle_response<T: DeserializeOwned>(&self, resp: reqwest::Response) -> Result<T> {
         let status = resp.status();
         if status.is_success() {
             return Ok(resp.json::<T>().await?);
         }
+        if matches!(status.as_u16(), 401 | 403) {
+            return Err(BypassError::Auth {
+                status: status.as_u16(),
+            }
+            .into());
+        }
         let body = resp.text().await.unwrap_or_default();
         let message = serde_json::from_str::<Value>(&body)
             .ok()
@@ -115,6 +312,26 @@ impl ShortcutClient {
     // Read endpoints (used for name resolution)
     // ------------------------------------------------------------------
 
+    /// Issue a lightweight `GET /member` and time it.  Any HTTP response –
+    /// even 401 – counts as reachable; only transport failures are errors.
+    pub async fn ping(&self) -> Result<Ping> {
+        let req = self
+            .authorize(self.http.get(format!("{}/member", self.base_url)))
+            .build()?;
+        let started = Instant::now();
+        let (resp, timing) = self.send_with_retry_timed(req).await?;
+        Ok(Ping {
+            status: resp.status().as_u16(),
+            total: started.elapsed(),
+            timing,
+        })
+    }
+
+    /// The member that owns the API token.
+    pub async fn current_member(&self) -> Result<CurrentMember> {
+        self.get("/member").await
+    }
+
     pub async fn list_members(&self) -> Result<Vec<Member>> {
         self.get("/members").await
     }
@@ -123,10 +340,162 @@ impl ShortcutClient {
         self.get("/groups").await
     }
 
+    pub async fn list_custom_fields(&self) -> Result<Vec<CustomField>> {
+        self.get("/custom-fields").await
+    }
+
+    pub async fn list_categories(&self) -> Result<Vec<Category>> {
+        self.get("/categories").await
+    }
+
     pub async fn list_workflows(&self) -> Result<Vec<Workflow>> {
         self.get("/workflows").await
     }
 
+    pub async fn list_projects(&self) -> Result<Vec<Project>> {
+        self.get("/projects").await
+    }
+
+    pub async fn list_labels(&self) -> Result<Vec<Label>> {
+        self.get("/labels").await
+    }
+
+    pub async fn list_iterations(&self) -> Result<Vec<Iteration>> {
+        self.get("/iterations").await
+    }
+
+    pub async fn list_objectives(&self) -> Result<Vec<Objective>> {
+        self.get("/objectives").await
+    }
+
+    pub async fn get_objective(&self, id: i64) -> Result<Objective> {
+        self.get(&format!("/objectives/{id}")).await
+    }
+
+    pub async fn get_epic(&self, id: i64) -> Result<Epic> {
+        self.get(&format!("/epics/{id}")).await
+    }
+
+    pub async fn get_story(&self, id: i64) -> Result<Story> {
+        self.get(&format!("/stories/{id}")).await
+    }
+
+    pub async fn list_epics(&self) -> Result<Vec<Epic>> {
+        self.get("/epics").await
+    }
+
+    /// Every result of `GET /search/{entity}` for `query`, following the
+    /// `next` cursor until the last page.  Each page is an ordinary request,
+    /// so retries and the rate-limit gate apply per page.
+    pub async fn search_all<T: DeserializeOwned>(
+        &self,
+        entity: &str,
+        query: &str,
+    ) -> Result<Vec<T>> {
+        let mut page: SearchResults<T> = self
+            .get_query(
+                &format!("/search/{entity}"),
+                &[("query", query), ("page_size", SEARCH_PAGE_SIZE)],
+            )
+            .await?;
+        let mut results = std::mem::take(&mut page.data);
+        while let Some(next) = page.next.take() {
+            // `next` is a path from the API root, e.g. `/api/v3/search/…`.
+            let path = next.strip_prefix("/api/v3").unwrap_or(&next);
+            page = self.get(path).await?;
+            results.append(&mut page.data);
+        }
+        Ok(results)
+    }
+
+    /// Epics carrying the label `name` (exact, case-sensitive match).
+    pub async fn search_epics_by_label(&self, name: &str) -> Result<Vec<Epic>> {
+        let query = format!("label:\"{}\"", name.replace('"', "\\\""));
+        let epics: Vec<Epic> = self.search_all("epics", &query).await?;
+        // Search is fuzzy; keep only exact label matches.
+        Ok(epics
+            .into_iter()
+            .filter(|e| e.labels.iter().any(|l| l.name == name))
+            .collect())
+    }
+
+    /// Epics named exactly `name` (via full-text search).
+    pub async fn search_epics_by_name(&self, name: &str) -> Result<Vec<Epic>> {
+        let query = format!("\"{}\"", name.replace('"', "\\\""));
+        let epics: Vec<Epic> = self.search_all("epics", &query).await?;
+        Ok(epics.into_iter().filter(|e| e.name == name).collect())
+    }
+
+    /// Stories named exactly `name` (via full-text search).
+    pub async fn search_stories_by_name(&self, name: &str) -> Result<Vec<Story>> {
+        let query = format!("\"{}\"", name.replace('"', "\\\""));
+        let stories: Vec<Story> = self.search_all("stories", &query).await?;
+        Ok(stories.into_iter().filter(|s| s.name == name).collect())
+    }
+
+    /// Objectives whose name matches `name` ignoring case and extra
+    /// whitespace, as resolver lookups do (`--if-exists`).
+    pub async fn find_objectives_named(&self, name: &str) -> Result<Vec<Objective>> {
+        let folded = fold_name(name);
+        let objectives = self.list_objectives().await?;
+        Ok(objectives
+            .into_iter()
+            .filter(|o| fold_name(&o.name) == folded)
+            .collect())
+    }
+
+    /// As [`ShortcutClient::find_objectives_named`], for labels.
+    pub async fn find_labels_named(&self, name: &str) -> Result<Vec<Label>> {
+        let folded = fold_name(name);
+        let labels = self.list_labels().await?;
+        Ok(labels
+            .into_iter()
+            .filter(|l| fold_name(&l.name) == folded)
+            .collect())
+    }
+
+    /// As [`ShortcutClient::find_objectives_named`], for iterations.
+    pub async fn find_iterations_named(&self, name: &str) -> Result<Vec<Iteration>> {
+        let folded = fold_name(name);
+        let iterations = self.list_iterations().await?;
+        Ok(iterations
+            .into_iter()
+            .filter(|i| fold_name(&i.name) == folded)
+            .collect())
+    }
+
+    /// As [`ShortcutClient::find_objectives_named`], for epics (via search).
+    pub async fn find_epics_named(&self, name: &str) -> Result<Vec<Epic>> {
+        let folded = fold_name(name);
+        let query = format!("\"{}\"", folded.replace('"', "\\\""));
+        let epics: Vec<Epic> = self.search_all("epics", &query).await?;
+        Ok(epics
+            .into_iter()
+            .filter(|e| fold_name(&e.name) == folded)
+            .collect())
+    }
+
+    /// As [`ShortcutClient::find_objectives_named`], for stories (via search).
+    pub async fn find_stories_named(&self, name: &str) -> Result<Vec<Story>> {
+        let folded = fold_name(name);
+        let query = format!("\"{}\"", folded.replace('"', "\\\""));
+        let stories: Vec<Story> = self.search_all("stories", &query).await?;
+        Ok(stories
+            .into_iter()
+            .filter(|s| fold_name(&s.name) == folded)
+            .collect())
+    }
+
+    /// All stories in the workspace (including archived), via the story
+    /// search endpoint with no filters.
+    pub async fn list_stories(&self) -> Result<Vec<Story>> {
+        self.post("/stories/search", &serde_json::json!({})).await
+    }
+
+    pub async fn search_stories(&self, req: &SearchStoriesRequest) -> Result<Vec<Story>> {
+        self.post("/stories/search", req).await
+    }
+
     // ------------------------------------------------------------------
     // Create endpoints
     // ------------------------------------------------------------------
@@ -139,7 +508,186 @@ impl ShortcutClient {
         self.post("/epics", req).await
     }
 
+    pub async fn create_label(&self, req: &CreateLabelRequest) -> Result<Label> {
+        self.post("/labels", req).await
+    }
+
+    pub async fn create_iteration(&self, req: &CreateIterationRequest) -> Result<Iteration> {
+        self.post("/iterations", req).await
+    }
+
     pub async fn create_story(&self, req: &CreateStoryRequest) -> Result<Story> {
         self.post("/stories", req).await
     }
+
+    pub async fn create_task(&self, story_id: i64, req: &CreateTaskRequest) -> Result<Task> {
+        self.post(&format!("/stories/{story_id}/tasks"), req).await
+    }
+
+    pub async fn create_comment(
+        &self,
+        story_id: i64,
+        req: &CreateCommentRequest,
+    ) -> Result<Comment> {
+        self.post(&format!("/stories/{story_id}/comments"), req)
+            .await
+    }
+
+    pub async fn create_linked_file(&self, req: &CreateLinkedFileRequest) -> Result<LinkedFile> {
+        self.post("/linked-files", req).await
+    }
+
+    pub async fn create_story_link(&self, req: &CreateStoryLinkRequest) -> Result<StoryLink> {
+        self.post("/story-links", req).await
+    }
+
+    pub async fn create_epic_health(
+        &self,
+        epic_id: i64,
+        req: &CreateEpicHealthRequest,
+    ) -> Result<Health> {
+        self.post(&format!("/epics/{epic_id}/health"), req).await
+    }
+
+    // ------------------------------------------------------------------
+    // Update endpoints
+    // ------------------------------------------------------------------
+
+    pub async fn update_objective(
+        &self,
+        id: i64,
+        req: &UpdateObjectiveRequest,
+    ) -> Result<Objective> {
+        self.put(&format!("/objectives/{id}"), req).await
+    }
+
+    /// Overwrite an epic's fields with a full create payload.
+    pub async fn overwrite_epic(&self, id: i64, req: &CreateEpicRequest) -> Result<Epic> {
+        self.put(&format!("/epics/{id}"), req).await
+    }
+
+    pub async fn update_epic(&self, id: i64, req: &UpdateEpicRequest) -> Result<Epic> {
+        self.put(&format!("/epics/{id}"), req).await
+    }
+
+    /// Overwrite a story's fields with a full create payload.
+    pub async fn overwrite_story(&self, id: i64, req: &CreateStoryRequest) -> Result<Story> {
+        self.put(&format!("/stories/{id}"), req).await
+    }
+
+    pub async fn update_story(&self, id: i64, req: &UpdateStoryRequest) -> Result<Story> {
+        self.put(&format!("/stories/{id}"), req).await
+    }
+
+    // ------------------------------------------------------------------
+    // Delete endpoints
+    // ------------------------------------------------------------------
+
+    pub async fn delete_objective(&self, id: i64) -> Result<()> {
+        self.delete(&format!("/objectives/{id}")).await
+    }
+
+    pub async fn delete_epic(&self, id: i64) -> Result<()> {
+        self.delete(&format!("/epics/{id}")).await
+    }
+
+    pub async fn delete_story(&self, id: i64) -> Result<()> {
+        self.delete(&format!("/stories/{id}")).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// A client that sends every request to `server`.
+    fn client(server: &MockServer) -> ShortcutClient {
+        let opts = ClientOptions {
+            auth_header: AuthHeader::ShortcutToken,
+            headers: Vec::new(),
+        };
+        ShortcutClient {
+            base_url: server.uri(),
+            ..ShortcutClient::new("token".into(), &opts).unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limit_pauses_concurrent_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/member"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/member"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "m-ada",
+                "name": "Ada Lovelace",
+                "mention_name": "ada",
+            })))
+            .mount(&server)
+            .await;
+        let client = client(&server);
+
+        let started = Instant::now();
+        let (first, (second, second_done)) = tokio::join!(client.current_member(), async {
+            // Let the first request hit the 429 and close the gate.
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            (client.current_member().await, Instant::now())
+        });
+
+        assert_eq!(first.unwrap().mention_name, "ada");
+        assert_eq!(second.unwrap().mention_name, "ada");
+        // The second request waited at the gate for the rest of the
+        // Retry-After, though it never saw a 429 itself.
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(second_done.duration_since(started) >= Duration::from_secs(1));
+        let stats = client.retry_stats();
+        assert_eq!(stats.retried_requests, 1);
+        assert_eq!(stats.retry_attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn search_all_follows_next_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search/stories"))
+            .and(query_param("query", "label:x"))
+            .and(query_param("page_size", "25"))
+            .and(query_param_is_missing("next"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{"id": 1}, {"id": 2}],
+                "next": "/api/v3/search/stories?query=label%3Ax&page_size=25&next=page2",
+                "total": 3,
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search/stories"))
+            .and(query_param("next", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{"id": 3}],
+                "next": null,
+                "total": 3,
+            })))
+            .mount(&server)
+            .await;
+
+        let results: Vec<Value> = client(&server)
+            .search_all("stories", "label:x")
+            .await
+            .unwrap();
+
+        let ids: Vec<i64> = results.iter().filter_map(|r| r["id"].as_i64()).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
 }
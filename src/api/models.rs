@@ -9,6 +9,66 @@ pub struct CreateLabelParams {
     pub name: String,
 }
 
+/// A request field that can be left unchanged (omitted), cleared (sent as
+/// an explicit `null`), or set.  `Option` with `skip_serializing_if` can
+/// only express the first and last.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Patch<T> {
+    #[default]
+    Unchanged,
+    Clear,
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Patch::Unchanged)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Set(value) => serializer.serialize_some(value),
+            Patch::Unchanged | Patch::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct Label {
+    pub id: i64,
+    pub name: String,
+    /// Hex color, e.g. "#3b82f6".
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// POST /api/v3/labels
+#[derive(Debug, Serialize)]
+pub struct CreateLabelRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// One page of results from a `GET /api/v3/search/*` endpoint.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SearchResults<T> {
+    pub data: Vec<T>,
+    /// Cursor path for the next page, if any.
+    pub next: Option<String>,
+    pub total: Option<i64>,
+}
+
 // ---------------------------------------------------------------------------
 // Objectives
 // ---------------------------------------------------------------------------
@@ -23,6 +83,29 @@ pub struct CreateObjectiveRequest {
     /// "in progress" | "to do" | "done"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
+    /// Existing categories, by name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<CreateCategoryParams>,
+}
+
+/// A category on an objective request.  The API matches it by name, so
+/// bypass only sends names it has resolved against `GET /categories`.
+#[derive(Debug, Serialize)]
+pub struct CreateCategoryParams {
+    pub name: String,
+}
+
+/// PUT /api/v3/objectives/{id}.  Omitted fields are left unchanged.
+#[derive(Debug, Serialize, Default)]
+pub struct UpdateObjectiveRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,6 +116,10 @@ pub struct Objective {
     pub description: Option<String>,
     pub state: String,
     pub app_url: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub categories: Vec<Category>,
 }
 
 // ---------------------------------------------------------------------------
@@ -54,20 +141,23 @@ pub struct CreateEpicRequest {
     /// Owner member UUIDs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner_ids: Option<Vec<String>>,
+    /// Follower member UUIDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_ids: Option<Vec<String>>,
     /// Team/group UUIDs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_ids: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<CreateLabelParams>>,
     /// ISO 8601 date, e.g. "2024-01-15".
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub planned_start_date: Option<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub planned_start_date: Patch<String>,
     /// ISO 8601 date.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline: Option<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub deadline: Patch<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct Epic {
     pub id: i64,
@@ -75,6 +165,57 @@ pub struct Epic {
     pub description: Option<String>,
     pub state: String,
     pub app_url: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub owner_ids: Vec<String>,
+    #[serde(default)]
+    pub follower_ids: Vec<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub objective_ids: Vec<i64>,
+    #[serde(default)]
+    pub group_ids: Vec<String>,
+    #[serde(default)]
+    pub planned_start_date: Option<String>,
+    #[serde(default)]
+    pub deadline: Option<String>,
+}
+
+/// PUT /api/v3/epics/{id}.  Omitted fields are left unchanged.
+#[derive(Debug, Serialize, Default)]
+pub struct UpdateEpicRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Replaces the epic's owners.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_ids: Option<Vec<String>>,
+    /// Replaces the epic's followers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_ids: Option<Vec<String>>,
+    /// Replaces the epic's teams.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub planned_start_date: Patch<String>,
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub deadline: Patch<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// Replaces the epic's full label set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<CreateLabelParams>>,
+    /// Move this epic to directly after the epic with this ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<i64>,
+    /// Replaces the epic's full objective set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objective_ids: Option<Vec<i64>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -93,6 +234,12 @@ pub struct CreateStoryRequest {
     /// Owner member UUIDs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner_ids: Option<Vec<String>>,
+    /// Follower member UUIDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_ids: Option<Vec<String>>,
+    /// Member UUID; the token's owner when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_by_id: Option<String>,
     /// Single team/group UUID for stories.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<String>,
@@ -108,23 +255,270 @@ pub struct CreateStoryRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub estimate: Option<i64>,
     /// ISO 8601 date.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub deadline: Patch<String>,
+    /// Project integer ID (legacy workspaces that still use Projects).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline: Option<String>,
+    pub project_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iteration_id: Option<i64>,
+    /// Checklist tasks; only accepted on creation, not by `PUT /stories/{id}`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<CreateTaskParams>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_fields: Vec<CustomFieldValueParams>,
 }
 
-#[derive(Debug, Deserialize)]
+/// One custom field value on a story, by UUID.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CustomFieldValueParams {
+    pub field_id: String,
+    pub value_id: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CreateTaskParams {
+    pub description: String,
+}
+
+/// POST /api/v3/stories/{story-public-id}/tasks
+#[derive(Debug, Serialize)]
+pub struct CreateTaskRequest {
+    pub description: String,
+    /// Member UUIDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_ids: Option<Vec<String>>,
+    pub complete: bool,
+}
+
+/// POST /api/v3/stories/{story-public-id}/comments
+#[derive(Debug, Serialize)]
+pub struct CreateCommentRequest {
+    pub text: String,
+    /// Member UUID; the token's owner when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct Comment {
+    pub id: i64,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub author_id: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct Task {
+    pub id: i64,
+    pub description: String,
+    #[serde(default)]
+    pub complete: bool,
+    #[serde(default)]
+    pub owner_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
 #[allow(dead_code)]
 pub struct Story {
     pub id: i64,
     pub name: String,
     pub story_type: String,
     pub app_url: Option<String>,
+    /// Absent from search results; read with `GET /stories/{id}`.
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub owner_ids: Vec<String>,
+    #[serde(default)]
+    pub follower_ids: Vec<String>,
+    #[serde(default)]
+    pub requested_by_id: Option<String>,
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub deadline: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    pub estimate: Option<i64>,
+    pub workflow_state_id: Option<i64>,
+    pub epic_id: Option<i64>,
+    #[serde(default)]
+    pub iteration_id: Option<i64>,
+    /// Absent from search results, like `description`.
+    #[serde(default)]
+    pub tasks: Vec<Task>,
+    /// Absent from search results, like `description`.
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldValueParams>,
+    /// Links where the story is either subject or object.  Absent from
+    /// search results, like `description`.
+    #[serde(default)]
+    pub story_links: Vec<StoryLink>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// POST /api/v3/linked-files – a URL attached to a story.
+#[derive(Debug, Serialize)]
+pub struct CreateLinkedFileRequest {
+    pub name: String,
+    pub url: String,
+    /// Always "url" (the other types are storage integrations).
+    #[serde(rename = "type")]
+    pub file_type: &'static str,
+    pub story_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct LinkedFile {
+    pub id: i64,
+    pub name: String,
+    pub url: String,
+}
+
+/// POST /api/v3/story-links – a relationship between two stories: the
+/// subject `verb`s the object.
+#[derive(Debug, Serialize)]
+pub struct CreateStoryLinkRequest {
+    pub subject_id: i64,
+    pub object_id: i64,
+    /// "blocks", "duplicates" or "relates to".
+    pub verb: &'static str,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct StoryLink {
+    pub id: i64,
+    pub subject_id: i64,
+    pub object_id: i64,
+    pub verb: String,
+}
+
+/// POST /api/v3/epics/{id}/health – a health update on an epic.
+#[derive(Debug, Serialize)]
+pub struct CreateEpicHealthRequest {
+    /// "On Track", "At Risk", "Off Track" or "No Health".
+    pub status: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct Health {
+    pub id: String,
+    pub status: String,
+}
+
+/// Body for `POST /stories/search`.
+#[derive(Debug, Default, Serialize)]
+pub struct SearchStoriesRequest {
+    /// Member UUID; matches stories this member owns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflow_state_id: Option<i64>,
+    /// Exact label name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+/// Body for `PUT /stories/{id}`.  Omitted fields are left unchanged.
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateStoryRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iteration_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_ids: Option<Vec<String>>,
+    /// Replaces the story's followers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follower_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_by_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflow_state_id: Option<i64>,
+    /// Replaces the story's full label set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<CreateLabelParams>>,
+    /// ISO 8601 date; [`Patch::Clear`] removes the deadline.
+    #[serde(skip_serializing_if = "Patch::is_unchanged")]
+    pub deadline: Patch<String>,
 }
 
 // ---------------------------------------------------------------------------
-// Members / Groups / Workflows  (read-only, for name resolution)
+// Iterations
 // ---------------------------------------------------------------------------
 
+/// POST /api/v3/iterations
+#[derive(Debug, Serialize)]
+pub struct CreateIterationRequest {
+    pub name: String,
+    /// ISO 8601 date.
+    pub start_date: String,
+    /// ISO 8601 date.
+    pub end_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Team/group UUIDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct Iteration {
+    pub id: i64,
+    pub name: String,
+    /// "unstarted" | "started" | "done"
+    #[serde(default)]
+    pub status: String,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub app_url: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Members / Groups / Custom fields / Projects / Workflows  (read-only, for
+// name resolution)
+// ---------------------------------------------------------------------------
+
+/// GET /api/v3/member – the member that owns the API token.
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct CurrentMember {
+    pub id: String,
+    pub name: String,
+    pub mention_name: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Member {
     pub id: String,
@@ -149,6 +543,38 @@ pub struct Group {
     pub archived: bool,
 }
 
+/// An objective category (`GET /categories`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct Category {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// A custom field and its enum values (`GET /custom-fields`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomField {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub values: Vec<CustomFieldEnumValue>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomFieldEnumValue {
+    pub id: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Project {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct Workflow {
@@ -165,3 +591,42 @@ pub struct WorkflowState {
     #[serde(rename = "type")]
     pub state_type: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Request {
+        #[serde(skip_serializing_if = "Patch::is_unchanged")]
+        deadline: Patch<String>,
+    }
+
+    #[test]
+    fn patch_unchanged_omits_field() {
+        let value = serde_json::to_value(Request {
+            deadline: Patch::Unchanged,
+        })
+        .unwrap();
+        assert_eq!(value, json!({}));
+    }
+
+    #[test]
+    fn patch_clear_sends_null() {
+        let value = serde_json::to_value(Request {
+            deadline: Patch::Clear,
+        })
+        .unwrap();
+        assert_eq!(value, json!({"deadline": null}));
+    }
+
+    #[test]
+    fn patch_set_sends_value() {
+        let value = serde_json::to_value(Request {
+            deadline: Patch::Set("2026-12-01".into()),
+        })
+        .unwrap();
+        assert_eq!(value, json!({"deadline": "2026-12-01"}));
+    }
+}
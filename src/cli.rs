@@ -1,10 +1,25 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 /// Shortcut API CLI – bulk create Objectives, Epics, and Stories.
 #[derive(Parser, Debug)]
-#[command(name = "bypass", version, about)]
+#[command(
+    name = "bypass",
+    version,
+    about,
+    disable_version_flag = true,
+    arg_required_else_help = true
+)]
 pub struct Cli {
+    /// Print version.
+    #[arg(short = 'V', long, action = ArgAction::SetTrue)]
+    pub version: bool,
+
+    /// With --version, print build metadata (version, git commit, build
+    /// date, target) as a JSON object.
+    #[arg(long, requires = "version")]
+    pub json: bool,
+
     /// Shortcut API token [env: SHORTCUT_API_TOKEN]
     #[arg(
         long,
@@ -14,29 +29,449 @@ pub struct Cli {
     )]
     pub token: Option<String>,
 
+    /// How the API token is sent: the `Shortcut-Token` header (default) or
+    /// `Authorization: Bearer`, for proxies that strip custom headers.
+    #[arg(long, value_enum, global = true, default_value = "shortcut-token")]
+    pub auth_header: AuthHeader,
+
+    /// Extra HTTP header sent with every API request, as "Name: Value";
+    /// repeat for several.  For gateways that require their own headers.
+    #[arg(
+        long,
+        value_name = "NAME: VALUE",
+        global = true,
+        value_parser = crate::api::client::parse_header
+    )]
+    pub header: Vec<(String, String)>,
+
+    /// With --output json, indent each event over several lines for
+    /// reading.  The output is then no longer newline-delimited JSON.
+    #[arg(long, global = true)]
+    pub pretty_json: bool,
+
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
-    /// Create Shortcut resources from an input file (.yaml, .csv, .xlsx).
-    Create(CreateArgs),
+    /// Create Shortcut resources from an input file (.yaml, .json, .csv, .tsv, .xlsx, .md, .zip).
+    Create(Box<CreateArgs>),
+
+    /// Change existing resources from an input file, matched by ID or name.
+    Update(UpdateArgs),
+
+    /// Delete stories and epics by ID, label, or epic.
+    Delete(DeleteArgs),
+
+    /// Archive epics and stories by ID or label.
+    Archive(ArchiveArgs),
+
+    /// Write a workspace's objectives, epics and stories to an input file.
+    Export(ExportArgs),
+
+    /// Un-archive epics and stories created with `create --draft`.
+    Publish(PublishArgs),
+
+    /// Move staged stories to the state recorded in their `intended:` label.
+    Promote(PromoteArgs),
+
+    /// Move every story owned by one member to another.
+    Reassign(ReassignArgs),
+
+    /// Move every story in one workflow state to another.
+    MigrateStates(MigrateStatesArgs),
+
+    /// Add existing epics to an existing objective, keeping the objectives
+    /// they already belong to.
+    Associate(AssociateArgs),
+
+    /// Check that the Shortcut API is reachable and measure latency.
+    Ping(PingArgs),
+
+    /// Inspect the bypass configuration.
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// Work with description templates.
+    #[command(subcommand)]
+    Template(TemplateCommand),
+
+    /// Create stories straight from another tracker's API.
+    #[command(subcommand)]
+    Import(ImportCommand),
+}
+
+fn parse_domain_pair(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((old, new)) if !old.trim().is_empty() && !new.trim().is_empty() => {
+            Ok((old.trim().to_string(), new.trim().to_string()))
+        }
+        _ => Err(format!("expected OLD=NEW, got '{s}'")),
+    }
+}
+
+/// `--dry-run`, shared by every command that writes to Shortcut.
+///
+/// The contract is the same everywhere: resolve names and work out every
+/// write exactly as a real run would, report them, make no writes, and
+/// exit 0 when the run would succeed (non-zero when it would not).
+#[derive(clap::Args, Debug, Clone, Copy)]
+pub struct DryRun {
+    /// Resolve names and report the planned writes without making any.
+    #[arg(long = "dry-run", id = "dry_run", default_value_t = false)]
+    pub enabled: bool,
 }
 
 #[derive(clap::Args, Debug)]
-pub struct CreateArgs {
-    /// Input file (.yaml/.yml, .csv, or .xlsx).
-    /// YAML files may contain objectives, epics, and stories in a single file.
-    /// CSV/XLSX files require --type to specify which resource kind to import.
+pub struct PublishArgs {
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct UpdateArgs {
+    /// Input file listing the changes, in any format `create` reads.  Each
+    /// resource is found by its `id` or, without one, by its name.
+    #[arg(long, short, value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Resource type – required for CSV, TSV, and XLSX files.
+    #[arg(long, value_enum, value_name = "TYPE")]
+    pub r#type: Option<ResourceType>,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Skip the confirmation prompt (required when stdin is not a terminal).
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct DeleteArgs {
+    /// Resources to delete: a .txt file with one ID per line (`#` starts a
+    /// comment), or an input file such as one written by
+    /// `create --echo-created`, whose `id`s are used.
+    #[arg(
+        long,
+        short,
+        value_name = "FILE",
+        required_unless_present_any = ["label", "epics"]
+    )]
+    pub file: Option<PathBuf>,
+
+    /// Kind of the IDs in a .txt file (default: story), or the resource
+    /// type of a CSV, TSV, or XLSX file.
+    #[arg(long, value_enum, value_name = "TYPE", requires = "file")]
+    pub r#type: Option<ResourceType>,
+
+    /// Delete every story and epic carrying this label (exact name).
+    #[arg(long, value_name = "NAME")]
+    pub label: Option<String>,
+
+    /// Delete this epic and its stories; repeat for several.
+    #[arg(long = "epic", value_name = "ID")]
+    pub epics: Vec<i64>,
+
+    /// With --epic, delete only the epic's stories.
+    #[arg(long, default_value_t = false, requires = "epics")]
+    pub keep_epic: bool,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Skip the confirmation prompt (required when stdin is not a terminal).
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ArchiveArgs {
+    /// Kind of resource to archive.  Required with --ids; with --label,
+    /// limits the label match to that kind (default: epics and stories).
+    #[arg(
+        long,
+        value_enum,
+        value_name = "TYPE",
+        required_unless_present = "label"
+    )]
+    pub r#type: Option<ResourceType>,
+
+    /// IDs to archive, comma-separated or repeated.
+    #[arg(
+        long,
+        value_name = "ID",
+        value_delimiter = ',',
+        requires = "type",
+        required_unless_present = "label"
+    )]
+    pub ids: Vec<i64>,
+
+    /// Archive every epic and story carrying this label (exact name).
+    #[arg(long, value_name = "NAME")]
+    pub label: Option<String>,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Skip the confirmation prompt for --label (required when stdin is not
+    /// a terminal).
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ExportArgs {
+    /// File to write: .yaml/.yml, or .csv/.tsv together with --type.
     #[arg(long, short, value_name = "FILE")]
     pub file: PathBuf,
 
-    /// Resource type – required for CSV and XLSX files.
+    /// Export only this kind of resource (CSV/TSV hold one kind).
+    #[arg(long, value_enum, value_name = "TYPE")]
+    pub r#type: Option<ResourceType>,
+
+    /// Include archived objectives, epics and stories.
+    #[arg(long)]
+    pub include_archived: bool,
+
+    /// Leave out epic and story descriptions.  Listing endpoints omit
+    /// them, so each one otherwise costs a request per resource.
+    #[arg(long)]
+    pub no_descriptions: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PromoteArgs {
+    /// Workflow state the stories were staged in.
+    #[arg(long, value_name = "STATE", default_value = "Staging")]
+    pub staging_state: String,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ReassignArgs {
+    /// Current owner (name or mention name) whose stories are reassigned.
+    #[arg(long, value_name = "NAME")]
+    pub from_owner: String,
+
+    /// New owner (name or mention name).
+    #[arg(long, value_name = "NAME")]
+    pub to_owner: String,
+
+    /// Include archived stories.
+    #[arg(long, default_value_t = false)]
+    pub include_archived: bool,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Skip the confirmation prompt (required when stdin is not a terminal).
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct AssociateArgs {
+    /// Objective (name or ID) to add the epics to.
+    #[arg(long, value_name = "NAME")]
+    pub objective: String,
+
+    /// Epic (name or ID) to add; repeat for several.
+    #[arg(long = "epic", value_name = "NAME", required = true)]
+    pub epics: Vec<String>,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct MigrateStatesArgs {
+    /// Workflow state (name or ID) whose stories are moved.
+    #[arg(long, value_name = "NAME")]
+    pub from_state: String,
+
+    /// Workflow state (name or ID) to move them to.
+    #[arg(long, value_name = "NAME")]
+    pub to_state: String,
+
+    /// Include archived stories.
+    #[arg(long, default_value_t = false)]
+    pub include_archived: bool,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// Skip the confirmation prompt (required when stdin is not a terminal).
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PingArgs {
+    /// Output format.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Print the effective configuration (base URL, token source, retry
+    /// policy) after applying flag/env/config-file precedence.
+    /// The token itself is never printed.
+    Show,
+
+    /// Check that the config file parses and, optionally, that the
+    /// effective token authenticates.  Exits non-zero on any problem.
+    Validate(ConfigValidateArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ConfigValidateArgs {
+    /// Also call the API with the effective token to confirm it works.
+    #[arg(long, default_value_t = false)]
+    pub check_auth: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommand {
+    /// Render a template against a sample resource, print the result, and
+    /// flag `{{...}}` placeholders that were left unreplaced.
+    Check(TemplateCheckArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct TemplateCheckArgs {
+    /// Markdown template file.
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Render against the first resource of this input file instead of a
+    /// built-in sample.
+    #[arg(long, value_name = "FILE")]
+    pub sample_input: Option<PathBuf>,
+
+    /// Kind of resource the template is for (`epic`, `objective`, or `story`).
+    #[arg(long, value_enum, value_name = "TYPE", default_value = "epic")]
+    pub r#type: ResourceType,
+
+    /// Exit 1 when placeholders are left unreplaced or name unset
+    /// environment variables.
+    #[arg(long, default_value_t = false)]
+    pub strict: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ImportCommand {
+    /// Fetch a repository's issues from the GitHub API and create them as
+    /// stories, with milestones as epics and assignees as owners.
+    Github(Box<ImportGithubArgs>),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ImportGithubArgs {
+    /// Repository to read issues from.
+    #[arg(long, value_name = "OWNER/NAME")]
+    pub repo: String,
+
+    /// Only issues with this label.  Repeat to require several labels.
+    #[arg(long = "label", value_name = "LABEL")]
+    pub labels: Vec<String>,
+
+    /// Which issues to fetch by state.
+    #[arg(long, value_enum, default_value = "open")]
+    pub state: IssueState,
+
+    /// GitHub token, needed for private repositories and higher rate
+    /// limits [env: GITHUB_TOKEN]
+    #[arg(long, env = "GITHUB_TOKEN", hide_env_values = true)]
+    pub github_token: Option<String>,
+
+    /// The `create` options (--dry-run, --map-file, --github-done-state, …)
+    /// apply to the fetched stories; --file, --format, --type and --stream
+    /// do not.
+    #[command(flatten)]
+    pub create: CreateArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CreateArgs {
+    /// Input file (.yaml/.yml, .json, .csv, .tsv, .xlsx, .md, or a .zip of these).
+    /// YAML and JSON files may contain objectives, epics, and stories in a single file.
+    /// CSV/TSV/XLSX files require --type to specify which resource kind to import.
+    /// `-` (the default) reads standard input, in the layout given by --format.
+    /// Repeat the flag, list several files or give a pattern such as
+    /// 'manifests/*.yaml' to merge several inputs into one run.
+    #[arg(long, short, value_name = "FILE", default_value = "-", num_args = 1..)]
+    pub file: Vec<PathBuf>,
+
+    /// Resource type – required for CSV, TSV, and XLSX files.
     /// YAML files determine the type from top-level keys (objectives/epics/stories).
     #[arg(long, value_enum, value_name = "TYPE")]
     pub r#type: Option<ResourceType>,
 
+    /// Input format: detected from the file extension, `yaml`, `json`,
+    /// `csv` or `markdown` whatever the extension (required for standard
+    /// input), `outline` for a markdown outline of objectives, epics and
+    /// stories,
+    /// `github` for a GitHub REST issues JSON export (an array of issues),
+    /// imported as stories, `jira` for a Jira CSV export, `trello` for a
+    /// Trello board JSON export, `asana` for an Asana project CSV export,
+    /// `pivotal` for a Pivotal Tracker CSV export or `azdo` for an Azure
+    /// DevOps Boards CSV export, imported as epics and stories.  Also
+    /// accepted as --from.
+    #[arg(
+        long,
+        alias = "from",
+        value_enum,
+        default_value = "auto",
+        conflicts_with = "stream"
+    )]
+    pub format: InputFormat,
+
+    /// Workflow state for closed issues with --format github or `import
+    /// github`.
+    #[arg(long, value_name = "STATE", default_value = "Done")]
+    pub github_done_state: String,
+
     /// Markdown template file whose rendered content becomes the description for
     /// every epic that does not supply its own inline template.
     /// Template variables: {{name}}, {{description}}, {{objective}},
@@ -44,10 +479,260 @@ pub struct CreateArgs {
     #[arg(long, value_name = "FILE")]
     pub template: Option<PathBuf>,
 
-    /// Validate names and structure without creating any resources.
-    /// Still contacts the API to resolve member/group/workflow names.
+    /// Markdown template file whose rendered content becomes the description for
+    /// every objective that does not supply its own inline template.
+    /// Template variables: {{name}}, {{description}}, {{state}}.
+    #[arg(long, value_name = "FILE")]
+    pub objective_template: Option<PathBuf>,
+
+    /// Markdown template file whose rendered content becomes the description for
+    /// every story that does not supply its own inline template.
+    /// Template variables: {{name}}, {{description}}, {{epic}}, {{owners}},
+    ///   {{team}}, {{labels}}, {{estimate}}, {{due_date}}, {{workflow_state}}.
+    #[arg(long, value_name = "FILE")]
+    pub story_template: Option<PathBuf>,
+
+    /// Prefix each task created from `acceptance_criteria` with TEXT
+    /// ("AC: " when given without a value).
+    #[arg(long, value_name = "TEXT", num_args = 0..=1, default_missing_value = "AC: ")]
+    pub ac_prefix: Option<String>,
+
+    /// Fail when a template's `{{env.VAR_NAME}}` placeholder names an unset
+    /// environment variable instead of rendering it empty.
+    #[arg(long, default_value_t = false)]
+    pub strict_templates: bool,
+
+    #[command(flatten)]
+    pub dry_run: DryRun,
+
+    /// How much --dry-run checks: `online` validates every resource and
+    /// resolves names against the workspace; `offline` only checks structure
+    /// and needs no token.
+    #[arg(long, value_enum, default_value = "online", requires = "dry_run")]
+    pub dry_run_level: DryRunLevel,
+
+    /// Resolve names for --dry-run against a JSON file of members, groups,
+    /// and workflows instead of the workspace, so the run needs no token or
+    /// network and its output is reproducible.  Not supported with
+    /// --stream, --if-empty, --default-self-owner, or --if-exists.
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "dry_run",
+        conflicts_with_all = ["stream", "if_empty", "default_self_owner", "if_exists"]
+    )]
+    pub resolver_fixture: Option<PathBuf>,
+
+    /// Expand `{{objective_url}}`/`{{objective_link}}` in epic descriptions
+    /// and `{{epic_url}}`/`{{epic_link}}` in story descriptions into the
+    /// app URL of / a markdown link to the referenced resource created
+    /// earlier in the run.  Applies after template rendering.
+    #[arg(long, default_value_t = false)]
+    pub link_references: bool,
+
+    /// Policy file (YAML) with organisational rules checked during --dry-run,
+    /// e.g. `team_workflow_states` restricting which workflow states each
+    /// team's stories may use.
+    #[arg(long, value_name = "FILE")]
+    pub policy: Option<PathBuf>,
+
+    /// Story types the workspace allows (comma-separated), for workspaces
+    /// that restrict them; overrides the policy's `story_types`.
+    /// Default: bug, chore, feature.
+    #[arg(long, value_name = "LIST", value_delimiter = ',')]
+    pub story_types: Vec<String>,
+
+    /// Trim and collapse internal whitespace in resource names and in
+    /// objective/epic/owner/team references before resolution.
+    /// Names are used verbatim by default.
     #[arg(long, default_value_t = false)]
-    pub dry_run: bool,
+    pub normalize_names: bool,
+
+    /// Write a Graphviz DOT graph of the in-batch references
+    /// (objective → epic → story) to FILE.  Unresolved references appear as
+    /// dashed nodes.  Combine with --dry-run to inspect without creating.
+    #[arg(long, value_name = "FILE")]
+    pub graph: Option<PathBuf>,
+
+    /// Only load workflow states from these workflows (comma-separated
+    /// names).  Avoids ambiguous state names in workspaces with many
+    /// workflows; the default state also comes from these workflows.
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    pub workflows: Vec<String>,
+
+    /// Treat owner emails at domain OLD as being at NEW, e.g.
+    /// `--email-domain-map old-corp.com=corp.com`.  Repeatable or
+    /// comma-separated.
+    #[arg(long, value_name = "OLD=NEW", value_delimiter = ',', value_parser = parse_domain_pair)]
+    pub email_domain_map: Vec<(String, String)>,
+
+    /// YAML file mapping team aliases (e.g. Slack channels) to team names,
+    /// consulted when a team reference matches no name or mention name.
+    /// Every alias must point at an existing team.
+    #[arg(long, value_name = "FILE")]
+    pub team_alias: Option<PathBuf>,
+
+    /// Default project for stories without a `project` field.  Only needed
+    /// for older workspaces that still require Projects on stories.
+    #[arg(long, value_name = "NAME")]
+    pub project: Option<String>,
+
+    /// Stream a story CSV/TSV: create each row as it is read instead of
+    /// loading the whole file first.  Bounds memory for very large imports.
+    /// Requires --type story; rows are validated (and, with --dry-run, only
+    /// validated) one at a time, and in-batch epic references are unavailable.
+    #[arg(long, default_value_t = false)]
+    pub stream: bool,
+
+    /// Create every story in the --staging-state workflow state.  The state
+    /// it would otherwise get is kept in an `intended:<state>` label so
+    /// `bypass promote` can move it there later.
+    #[arg(long, default_value_t = false)]
+    pub stage: bool,
+
+    /// Workflow state used by --stage.
+    #[arg(long, value_name = "STATE", default_value = "Staging")]
+    pub staging_state: String,
+
+    /// Read back each created resource and compare key fields (name, state,
+    /// owners, and for stories type, workflow state, and estimate) with what
+    /// was sent; mismatches count as errors.  Doubles the request count.
+    #[arg(long, default_value_t = false)]
+    pub verify: bool,
+
+    /// Create epics and stories archived and labelled `bypass-draft`, keeping
+    /// them off active boards until `bypass publish` un-archives them.
+    #[arg(long, default_value_t = false)]
+    pub draft: bool,
+
+    /// Make re-runs idempotent: tag each epic and story with a
+    /// `bypass-hash:<hex>` label of its fields, skip items already imported
+    /// with the same hash, and overwrite those whose hash changed.  Labels,
+    /// iterations and objectives are matched by name and reused.
+    #[arg(long, default_value_t = false)]
+    pub dedupe_by_hash: bool,
+
+    /// What to do when an objective, epic, or story with the same name
+    /// already exists (ignoring case and extra whitespace): `skip` it and
+    /// reuse its ID, `create` a duplicate, or `error`.  Stories only match
+    /// stories in the same epic (or with no epic, if they have none).
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        default_value = "create",
+        conflicts_with = "dedupe_by_hash"
+    )]
+    pub if_exists: IfExists,
+
+    /// Write the run results to FILE in Prometheus textfile-collector
+    /// format (replaced atomically).  Not written for --dry-run.
+    #[arg(long, value_name = "FILE")]
+    pub metrics_file: Option<PathBuf>,
+
+    /// After the run, write the created resources with their Shortcut IDs
+    /// to FILE (.yaml, .csv or .tsv), in the input's layout.
+    #[arg(long, value_name = "FILE")]
+    pub echo_created: Option<PathBuf>,
+
+    /// YAML/JSON file of per-field value translations (e.g. source-system
+    /// user IDs → Shortcut names), applied before name resolution.
+    #[arg(long, value_name = "FILE")]
+    pub map_file: Option<PathBuf>,
+
+    /// YAML file of named label/owner/team bundles that epics and stories
+    /// reference through their `preset` field.
+    #[arg(long, value_name = "FILE")]
+    pub presets: Option<PathBuf>,
+
+    /// Treat values with no entry in --map-file as errors instead of warnings.
+    #[arg(long, default_value_t = false, requires = "map_file")]
+    pub strict: bool,
+
+    /// Print nothing but errors (text: on stderr) and rely on the exit code.
+    /// With --output json, per-item `created` events are suppressed but
+    /// errors and the final summary are kept.
+    #[arg(long, short, default_value_t = false)]
+    pub quiet: bool,
+
+    /// Only create anything if the workspace has no existing resources of
+    /// TYPE; otherwise exit successfully without creating.  Useful for
+    /// bootstrap scripts that may run repeatedly.
+    #[arg(long, value_enum, value_name = "TYPE")]
+    pub if_empty: Option<ResourceType>,
+
+    /// Make the user the API token belongs to the owner of every epic and
+    /// story that lists no owners.  Owners given in the input are kept.
+    #[arg(long, default_value_t = false)]
+    pub default_self_owner: bool,
+
+    /// Give every story that is in an iteration but has no `due_date` the
+    /// iteration's end date.  Iterations declared in the input are used
+    /// before existing ones of the same name.
+    #[arg(long, default_value_t = false)]
+    pub due_from_iteration: bool,
+
+    /// Stop starting new creates once the run has taken SECONDS; the item in
+    /// flight is finished, the remaining ones are counted as unprocessed and
+    /// the exit code is 4.
+    #[arg(long, value_name = "SECONDS")]
+    pub max_runtime: Option<u64>,
+
+    /// Create up to N resources at a time (1–16).  Objectives, epics, and
+    /// stories are still created phase by phase, so references within the
+    /// file resolve.  Not supported with --stream, which creates one story
+    /// at a time.
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 4,
+        value_parser = clap::value_parser!(u8).range(1..=16),
+        conflicts_with = "stream"
+    )]
+    pub concurrency: u8,
+
+    /// Create what resolves even when the pre-create check finds owners,
+    /// teams, workflow states, projects, objectives or epics that do not
+    /// resolve; by default the run aborts before creating anything.
+    #[arg(long, default_value_t = false)]
+    pub ignore_resolution_errors: bool,
+
+    /// After resolving names, show the counts and first few items of each
+    /// type and ask before creating anything.  Not supported with --stream.
+    #[arg(long, default_value_t = false, conflicts_with = "stream")]
+    pub confirm: bool,
+
+    /// Check the parsed input against a JSON Schema (JSON or YAML file)
+    /// before anything else happens; any violation aborts the run.  Not
+    /// supported with --stream.
+    #[arg(long, value_name = "FILE", conflicts_with = "stream")]
+    pub validate_schema: Option<PathBuf>,
+
+    /// Name the source row of the item in per-item error messages and JSON
+    /// `error` events (`source_row`): the CSV/TSV/XLSX row number, or the
+    /// item's position in its YAML list.
+    #[arg(long, default_value_t = false)]
+    pub source_rows: bool,
+
+    /// Answer yes to the --confirm prompt.
+    #[arg(long, short, default_value_t = false)]
+    pub yes: bool,
+
+    /// CSV/TSV/XLSX column (header, case-insensitive) holding resource
+    /// names, for exports that call it e.g. "Summary" or "Item".
+    #[arg(long, value_name = "HEADER", conflicts_with = "name_is_first")]
+    pub name_column: Option<String>,
+
+    /// Use the first CSV/TSV/XLSX column as the name when there is no
+    /// `name` column.
+    #[arg(long, default_value_t = false)]
+    pub name_is_first: bool,
+
+    /// YAML file renaming CSV/TSV/XLSX headers to bypass fields (e.g.
+    /// `Titel: name`), for exports with other column names.  Also accepted
+    /// as --map.
+    #[arg(long, alias = "map", value_name = "FILE")]
+    pub column_map: Option<PathBuf>,
 
     /// Output format.
     #[arg(long, value_enum, default_value = "text")]
@@ -59,6 +744,73 @@ pub enum ResourceType {
     Objective,
     Epic,
     Story,
+    Label,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum InputFormat {
+    /// From the file extension.
+    Auto,
+    /// A YAML manifest.
+    Yaml,
+    /// A JSON manifest, in the YAML layout.
+    Json,
+    /// CSV, with --type.
+    Csv,
+    /// A markdown checklist.
+    Markdown,
+    /// A markdown outline of objectives, epics and stories.
+    Outline,
+    /// GitHub issues JSON.
+    Github,
+    /// Jira CSV export.
+    Jira,
+    /// Trello board JSON export.
+    Trello,
+    /// Asana project CSV export.
+    Asana,
+    /// Pivotal Tracker CSV export.
+    Pivotal,
+    /// Azure DevOps Boards CSV export.
+    Azdo,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum IssueState {
+    /// Open issues only (default).
+    Open,
+    /// Closed issues only.
+    Closed,
+    /// Both.
+    All,
+}
+
+/// What `create --if-exists` does with a resource whose name is taken.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum IfExists {
+    /// Leave the existing resource alone and use its ID for references.
+    Skip,
+    /// Create another one anyway (default).
+    #[default]
+    Create,
+    /// Fail the row.
+    Error,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum DryRunLevel {
+    /// Required fields, enum values, dates and template files only.
+    Offline,
+    /// Everything, including name resolution against the workspace (default).
+    Online,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum AuthHeader {
+    /// `Shortcut-Token: <token>`, as documented by Shortcut.
+    ShortcutToken,
+    /// `Authorization: Bearer <token>`.
+    Bearer,
 }
 
 #[derive(Clone, Debug, ValueEnum)]
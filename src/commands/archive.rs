@@ -0,0 +1,322 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::json;
+
+use super::{confirm, json_event};
+use crate::api::models::{
+    SearchStoriesRequest, UpdateEpicRequest, UpdateObjectiveRequest, UpdateStoryRequest,
+};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{ArchiveArgs, OutputFormat, ResourceType};
+use crate::config::Config;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Archive the resources selected by `--ids` and `--label` through the
+/// update endpoints.  Resources that are already archived are reported and
+/// left alone.  A `--label` selection is previewed and confirmed first.
+pub async fn run(args: ArchiveArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    if matches!(args.r#type, Some(ResourceType::Label)) {
+        bail!("bypass does not archive labels; archive them in Shortcut");
+    }
+    if args.label.is_some() && matches!(args.r#type, Some(ResourceType::Objective)) {
+        bail!("Objectives carry no labels; select them with --ids");
+    }
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+
+    let mut errors: Vec<String> = Vec::new();
+    let mut targets: Vec<Target> = Vec::new();
+    if let Some(kind) = &args.r#type {
+        for &id in &args.ids {
+            match fetch(&client, kind, id).await {
+                Ok(target) => targets.push(target),
+                Err(e) => {
+                    let target = Target {
+                        kind: kind_name(kind),
+                        id,
+                        name: String::new(),
+                        url: None,
+                        archived: false,
+                    };
+                    let msg = e.to_string();
+                    emit(&args, &target, Some(&msg));
+                    errors.push(format!("{} #{id}: {msg}", target.kind));
+                }
+            }
+        }
+    }
+    if let Some(label) = &args.label {
+        targets.extend(labelled(&client, label, args.r#type.as_ref()).await?);
+    }
+    let mut seen = HashSet::new();
+    targets.retain(|t| seen.insert((t.kind, t.id)));
+
+    if args.label.is_some() && !args.dry_run.enabled {
+        let pending: Vec<&Target> = targets.iter().filter(|t| !t.archived).collect();
+        preview(&args, &pending);
+        if !pending.is_empty()
+            && !args.yes
+            && !confirm(&format!(
+                "Archive {} resource{}?",
+                pending.len(),
+                if pending.len() == 1 { "" } else { "s" }
+            ))?
+        {
+            eprintln!("{}", "Aborted – nothing was archived.".yellow());
+            return Ok(());
+        }
+    }
+
+    let mut archived = 0usize;
+    let mut unchanged = 0usize;
+    for target in &targets {
+        if target.archived {
+            unchanged += 1;
+            emit(&args, target, None);
+            continue;
+        }
+        let result = if args.dry_run.enabled {
+            Ok(())
+        } else {
+            archive(&client, target).await
+        };
+        match result {
+            Ok(()) => {
+                archived += 1;
+                emit(&args, target, None);
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(&args, target, Some(&msg));
+                errors.push(format!(
+                    "{} '{}' (#{}): {msg}",
+                    target.kind, target.name, target.id
+                ));
+            }
+        }
+    }
+
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            let label = if args.dry_run.enabled {
+                "To archive"
+            } else {
+                "Archived"
+            };
+            println!("  {label:<18} : {}", archived.to_string().green());
+            if unchanged > 0 {
+                println!("  Already archived   : {}", unchanged.to_string().yellow());
+            }
+            if !errors.is_empty() {
+                println!("  Errors             : {}", errors.len().to_string().red());
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "dry_run": args.dry_run.enabled,
+                "archived": archived,
+                "already_archived": unchanged,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Selection
+// ---------------------------------------------------------------------------
+
+/// One resource to archive.
+struct Target {
+    kind: &'static str,
+    id: i64,
+    name: String,
+    url: Option<String>,
+    archived: bool,
+}
+
+fn kind_name(kind: &ResourceType) -> &'static str {
+    match kind {
+        ResourceType::Objective => "objective",
+        ResourceType::Epic => "epic",
+        ResourceType::Story => "story",
+        ResourceType::Label => "label",
+    }
+}
+
+async fn fetch(client: &ShortcutClient, kind: &ResourceType, id: i64) -> Result<Target> {
+    let (name, url, archived) = match kind {
+        ResourceType::Objective => {
+            let o = client.get_objective(id).await?;
+            (o.name, o.app_url, o.archived)
+        }
+        ResourceType::Epic => {
+            let e = client.get_epic(id).await?;
+            (e.name, e.app_url, e.archived)
+        }
+        ResourceType::Story => {
+            let s = client.get_story(id).await?;
+            (s.name, s.app_url, s.archived)
+        }
+        ResourceType::Label => bail!("bypass does not archive labels"),
+    };
+    Ok(Target {
+        kind: kind_name(kind),
+        id,
+        name,
+        url,
+        archived,
+    })
+}
+
+/// Unarchived stories and epics carrying `label`, limited to `kind` when
+/// given.
+async fn labelled(
+    client: &ShortcutClient,
+    label: &str,
+    kind: Option<&ResourceType>,
+) -> Result<Vec<Target>> {
+    let mut targets = Vec::new();
+    if matches!(kind, None | Some(ResourceType::Story)) {
+        let stories = client
+            .search_stories(&SearchStoriesRequest {
+                label_name: Some(label.to_string()),
+                archived: Some(false),
+                ..Default::default()
+            })
+            .await?;
+        targets.extend(stories.into_iter().map(|s| Target {
+            kind: "story",
+            id: s.id,
+            name: s.name,
+            url: s.app_url,
+            archived: false,
+        }));
+    }
+    if matches!(kind, None | Some(ResourceType::Epic)) {
+        let epics = client.list_epics().await?;
+        targets.extend(
+            epics
+                .into_iter()
+                .filter(|e| !e.archived && e.labels.iter().any(|l| l.name == label))
+                .map(|e| Target {
+                    kind: "epic",
+                    id: e.id,
+                    name: e.name,
+                    url: e.app_url,
+                    archived: false,
+                }),
+        );
+    }
+    Ok(targets)
+}
+
+async fn archive(client: &ShortcutClient, target: &Target) -> Result<()> {
+    match target.kind {
+        "objective" => {
+            let req = UpdateObjectiveRequest {
+                archived: Some(true),
+                ..Default::default()
+            };
+            client.update_objective(target.id, &req).await.map(|_| ())
+        }
+        "epic" => {
+            let req = UpdateEpicRequest {
+                archived: Some(true),
+                ..Default::default()
+            };
+            client.update_epic(target.id, &req).await.map(|_| ())
+        }
+        _ => {
+            let req = UpdateStoryRequest {
+                archived: Some(true),
+                ..Default::default()
+            };
+            client.update_story(target.id, &req).await.map(|_| ())
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+fn preview(args: &ArchiveArgs, targets: &[&Target]) {
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "{} resource{} to archive",
+                targets.len().to_string().bold(),
+                if targets.len() == 1 { "" } else { "s" }
+            );
+            for t in targets {
+                println!("  • {} '{}'  (#{})", t.kind, t.name, t.id);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "archive_plan",
+                "resources": targets
+                    .iter()
+                    .map(|t| json!({ "kind": t.kind, "id": t.id, "name": t.name }))
+                    .collect::<Vec<_>>(),
+            }))
+        ),
+    }
+}
+
+fn emit(args: &ArchiveArgs, target: &Target, error: Option<&str>) {
+    let Target { kind, id, name, .. } = target;
+    match args.output {
+        OutputFormat::Text => match error {
+            Some(e) if name.is_empty() => println!("  {} {kind} #{id}\n    {e}", "✗".red()),
+            Some(e) => println!("  {} {kind}: {name}  (#{id})\n    {e}", "✗".red()),
+            None if target.archived => println!(
+                "  {} {kind}: {name}  (#{id})  {}",
+                "↷".yellow(),
+                "already archived".dimmed()
+            ),
+            None => println!("  {} {kind}: {name}  (#{id})", "✓".green()),
+        },
+        OutputFormat::Json => {
+            let event = match error {
+                Some(_) => "error",
+                None if target.archived => "skipped",
+                None => "archived",
+            };
+            let mut value = json!({
+                "event": event,
+                "kind": kind,
+                "id": id,
+                "name": name,
+                "url": target.url,
+                "dry_run": args.dry_run.enabled,
+            });
+            if target.archived {
+                value["reason"] = json!("already archived");
+            }
+            if let Some(e) = error {
+                value["error"] = json!(e);
+            }
+            println!("{}", json_event(&value));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::json;
+
+use super::json_event;
+use crate::api::models::{Epic, Objective, UpdateEpicRequest};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{AssociateArgs, OutputFormat};
+use crate::config::Config;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Add an existing objective to existing epics.  Each epic's current
+/// objectives are read first and kept: the objective is merged into the
+/// list, never replaces it.
+pub async fn run(args: AssociateArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+
+    let objective = find_objective(&client, &args.objective).await?;
+    let epics = find_epics(&client, &args.epics).await?;
+
+    let mut associated = 0usize;
+    let mut unchanged = 0usize;
+    let mut errors: Vec<String> = Vec::new();
+
+    for epic in &epics {
+        let before = epic.objective_ids.clone();
+        if before.contains(&objective.id) {
+            unchanged += 1;
+            emit(&args, epic, &before, &before, None);
+            continue;
+        }
+        let mut after = before.clone();
+        after.push(objective.id);
+
+        let result = if args.dry_run.enabled {
+            Ok(())
+        } else {
+            let req = UpdateEpicRequest {
+                objective_ids: Some(after.clone()),
+                ..Default::default()
+            };
+            client.update_epic(epic.id, &req).await.map(|_| ())
+        };
+        match result {
+            Ok(()) => {
+                associated += 1;
+                emit(&args, epic, &before, &after, None);
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(&args, epic, &before, &after, Some(&msg));
+                errors.push(format!("Epic '{}' (#{}): {msg}", epic.name, epic.id));
+            }
+        }
+    }
+
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            let verb = if args.dry_run.enabled {
+                "to associate"
+            } else {
+                "associated"
+            };
+            println!(
+                "  Epics {verb:<12} : {} with '{}' (#{})",
+                associated.to_string().green(),
+                objective.name,
+                objective.id
+            );
+            if unchanged > 0 {
+                println!("  Already associated : {}", unchanged.to_string().yellow());
+            }
+            if !errors.is_empty() {
+                println!("  Errors             : {}", errors.len().to_string().red());
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "dry_run": args.dry_run.enabled,
+                "objective_id": objective.id,
+                "associated": associated,
+                "unchanged": unchanged,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Lookup
+// ---------------------------------------------------------------------------
+
+/// An objective by numeric ID or exact name.
+async fn find_objective(client: &ShortcutClient, reference: &str) -> Result<Objective> {
+    let reference = reference.trim();
+    if let Ok(id) = reference.parse::<i64>() {
+        return client.get_objective(id).await;
+    }
+    let mut matches: Vec<Objective> = client
+        .list_objectives()
+        .await?
+        .into_iter()
+        .filter(|o| o.name == reference)
+        .collect();
+    match matches.len() {
+        0 => bail!("Objective '{reference}' not found"),
+        1 => Ok(matches.remove(0)),
+        _ => bail!(
+            "Objective name '{reference}' matches several objectives ({}); use the ID",
+            ids(matches.iter().map(|o| o.id))
+        ),
+    }
+}
+
+/// Epics by numeric ID or exact name, in the order given, each read with
+/// `GET /epics/{id}` so that its objective list is current.
+async fn find_epics(client: &ShortcutClient, references: &[String]) -> Result<Vec<Epic>> {
+    let mut all: Option<Vec<Epic>> = None;
+    let mut ids_wanted: Vec<i64> = Vec::new();
+    let mut missing: Vec<&str> = Vec::new();
+
+    for reference in references.iter().map(|r| r.trim()) {
+        if let Ok(id) = reference.parse::<i64>() {
+            ids_wanted.push(id);
+            continue;
+        }
+        if all.is_none() {
+            all = Some(client.list_epics().await?);
+        }
+        let matching: Vec<i64> = all
+            .iter()
+            .flatten()
+            .filter(|e| e.name == reference)
+            .map(|e| e.id)
+            .collect();
+        match matching.as_slice() {
+            [] => missing.push(reference),
+            [id] => ids_wanted.push(*id),
+            _ => bail!(
+                "Epic name '{reference}' matches several epics ({}); use the IDs",
+                ids(matching.iter().copied())
+            ),
+        }
+    }
+    if !missing.is_empty() {
+        bail!("Epic(s) not found: {}", missing.join(", "));
+    }
+
+    let mut epics = Vec::with_capacity(ids_wanted.len());
+    for id in ids_wanted {
+        if !epics.iter().any(|e: &Epic| e.id == id) {
+            epics.push(client.get_epic(id).await?);
+        }
+    }
+    Ok(epics)
+}
+
+fn ids(ids: impl Iterator<Item = i64>) -> String {
+    ids.map(|id| format!("#{id}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+/// One line per epic, showing its objective IDs before and after.
+fn emit(args: &AssociateArgs, epic: &Epic, before: &[i64], after: &[i64], error: Option<&str>) {
+    let unchanged = before == after;
+    match args.output {
+        OutputFormat::Text => {
+            let sets = format!("{} → {}", set(before), set(after));
+            match error {
+                None if unchanged => println!(
+                    "  {} {}  (#{})  {} {}",
+                    "↷".yellow(),
+                    epic.name,
+                    epic.id,
+                    set(before).dimmed(),
+                    "already associated".dimmed()
+                ),
+                None => println!(
+                    "  {} {}  (#{})  {}",
+                    "✓".green(),
+                    epic.name,
+                    epic.id,
+                    sets.dimmed()
+                ),
+                Some(e) => println!(
+                    "  {} {}  (#{})  {}\n    {e}",
+                    "✗".red(),
+                    epic.name,
+                    epic.id,
+                    sets.dimmed()
+                ),
+            }
+        }
+        OutputFormat::Json => {
+            let event = match error {
+                Some(_) => "error",
+                None if unchanged => "skipped",
+                None => "associated",
+            };
+            let mut value = json!({
+                "event": event,
+                "kind": "epic",
+                "id": epic.id,
+                "name": epic.name,
+                "objective_ids_before": before,
+                "objective_ids_after": after,
+                "dry_run": args.dry_run.enabled,
+            });
+            if let Some(e) = error {
+                value["error"] = json!(e);
+            }
+            println!("{}", json_event(&value));
+        }
+    }
+}
+
+/// `{#1, #2}`, or `{}` for none.
+fn set(ids_in: &[i64]) -> String {
+    format!("{{{}}}", ids(ids_in.iter().copied()))
+}
@@ -0,0 +1,171 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+
+use super::{confirm, json_event};
+use crate::api::ShortcutClient;
+use crate::api::models::{SearchStoriesRequest, Story, UpdateStoryRequest};
+use crate::cli::OutputFormat;
+
+/// A change applied to every story a search finds, as `reassign` and
+/// `migrate-states` make it.
+pub(super) struct StoryChange<'a> {
+    /// Verb in messages and the JSON plan event, e.g. `reassign`.
+    pub action: &'a str,
+    /// Verb of the confirmation prompt, e.g. `Reassign`.
+    pub prompt: &'a str,
+    /// Past tense for per-story events and the summary, e.g. `reassigned`.
+    pub done: &'a str,
+    /// What the stories have in common in the preview, e.g. `owned by`.
+    pub relation: &'a str,
+    /// The field changed, naming the plan's `from_*` and `to_*` keys.
+    pub field: &'a str,
+    /// The old and new values as the user gave them.
+    pub from: &'a str,
+    pub to: &'a str,
+    pub output: &'a OutputFormat,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+/// Find the stories matching `search`, preview them, ask for confirmation
+/// (unless `--yes`), and `PUT` `update(story)` to each.  A failed update is
+/// reported and the rest carry on; the process exits 1 if any failed.
+pub(super) async fn update_stories(
+    client: &ShortcutClient,
+    search: &SearchStoriesRequest,
+    change: &StoryChange<'_>,
+    update: impl Fn(&Story) -> UpdateStoryRequest,
+) -> Result<()> {
+    let stories = client.search_stories(search).await?;
+
+    preview(change, &stories);
+
+    if stories.is_empty() || change.dry_run {
+        return Ok(());
+    }
+
+    if !change.yes
+        && !confirm(&format!(
+            "{} {} stor{} from '{}' to '{}'?",
+            change.prompt,
+            stories.len(),
+            if stories.len() == 1 { "y" } else { "ies" },
+            change.from,
+            change.to
+        ))?
+    {
+        eprintln!("{}", "Aborted – no stories were changed.".yellow());
+        return Ok(());
+    }
+
+    let mut changed = 0usize;
+    let mut errors: Vec<String> = Vec::new();
+    for story in &stories {
+        match client.update_story(story.id, &update(story)).await {
+            Ok(_) => {
+                changed += 1;
+                emit(change, story, None);
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(change, story, Some(&msg));
+                errors.push(format!("Story '{}' (#{}): {msg}", story.name, story.id));
+            }
+        }
+    }
+
+    match change.output {
+        OutputFormat::Text => {
+            let label = format!("Stories {}", change.done);
+            let width = label.len();
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            println!("  {label} : {}", changed.to_string().green());
+            if !errors.is_empty() {
+                println!(
+                    "  {:<width$} : {}",
+                    "Errors",
+                    errors.len().to_string().red()
+                );
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                format!("stories_{}", change.done): changed,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn preview(change: &StoryChange, stories: &[Story]) {
+    match change.output {
+        OutputFormat::Text => {
+            println!(
+                "{} stor{} {} '{}' → '{}'",
+                stories.len().to_string().bold(),
+                if stories.len() == 1 { "y" } else { "ies" },
+                change.relation,
+                change.from,
+                change.to
+            );
+            for story in stories {
+                println!("  • {}  (#{})", story.name, story.id);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": format!("{}_plan", change.action),
+                format!("from_{}", change.field): change.from,
+                format!("to_{}", change.field): change.to,
+                "dry_run": change.dry_run,
+                "stories": stories
+                    .iter()
+                    .map(|s| json!({ "id": s.id, "name": s.name, "url": s.app_url }))
+                    .collect::<Vec<_>>(),
+            }))
+        ),
+    }
+}
+
+fn emit(change: &StoryChange, story: &Story, error: Option<&str>) {
+    match (change.output, error) {
+        (OutputFormat::Text, None) => {
+            println!("  {} {}  (#{})", "✓".green(), story.name, story.id)
+        }
+        (OutputFormat::Text, Some(e)) => {
+            println!("  {} {}  (#{})\n    {e}", "✗".red(), story.name, story.id)
+        }
+        (OutputFormat::Json, None) => println!(
+            "{}",
+            json_event(&json!({
+                "event": change.done,
+                "id": story.id,
+                "name": story.name,
+                "url": story.app_url,
+            }))
+        ),
+        (OutputFormat::Json, Some(e)) => println!(
+            "{}",
+            json_event(&json!({
+                "event": "error",
+                "kind": "story",
+                "id": story.id,
+                "name": story.name,
+                "error": e,
+            }))
+        ),
+    }
+}
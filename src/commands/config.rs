@@ -0,0 +1,134 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::api::client::{BASE_DELAY, BASE_URL, MAX_DELAY, MAX_RETRIES, RETRYABLE};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{AuthHeader, ConfigCommand, ConfigValidateArgs};
+use crate::config::{self, Config};
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+pub async fn run(cmd: ConfigCommand, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    match cmd {
+        ConfigCommand::Show => show(token, &opts),
+        ConfigCommand::Validate(args) => validate(args, token, opts).await,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// config show
+// ---------------------------------------------------------------------------
+
+fn show(token: Option<String>, opts: &ClientOptions) -> Result<()> {
+    let token_line = match Config::load(token) {
+        Ok(cfg) => format!("{} (from {})", "set".green(), cfg.token_source),
+        Err(_) => "not set".red().to_string(),
+    };
+
+    let config_file = match config::config_file_path() {
+        Some(p) if p.exists() => p.display().to_string(),
+        Some(p) => format!("{} {}", p.display(), "(not found)".dimmed()),
+        None => "(no config directory)".dimmed().to_string(),
+    };
+
+    let statuses: Vec<String> = RETRYABLE.iter().map(|s| s.to_string()).collect();
+
+    println!("Base URL     : {BASE_URL}");
+    println!("API token    : {token_line}");
+    println!("Config file  : {config_file}");
+    println!(
+        "Auth header  : {}",
+        match opts.auth_header {
+            AuthHeader::ShortcutToken => "Shortcut-Token",
+            AuthHeader::Bearer => "Authorization: Bearer",
+        }
+    );
+    if !opts.headers.is_empty() {
+        let headers: Vec<String> = opts
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect();
+        println!("Extra headers: {}", headers.join(", "));
+    }
+    let defaults = config::defaults();
+    if !defaults.is_empty() {
+        let pairs: Vec<String> = defaults
+            .iter()
+            .map(|(k, v)| {
+                let v = serde_yaml::to_string(v).unwrap_or_default();
+                format!("{k}={}", v.trim())
+            })
+            .collect();
+        println!("Defaults     : {}", pairs.join(", "));
+    }
+    println!(
+        "Retry policy : up to {MAX_RETRIES} retries on HTTP {}; backoff {}s base, {}s cap; \
+         Retry-After honored on 429",
+        statuses.join(", "),
+        BASE_DELAY.as_secs(),
+        MAX_DELAY.as_secs(),
+    );
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// config validate
+// ---------------------------------------------------------------------------
+
+async fn validate(
+    args: ConfigValidateArgs,
+    token: Option<String>,
+    opts: ClientOptions,
+) -> Result<()> {
+    let mut ok = true;
+
+    match config::config_file_path() {
+        Some(path) if path.exists() => match config::validate_file(&path) {
+            Ok(warnings) => {
+                println!("{} Config file {} parses", "✓".green(), path.display());
+                for w in warnings {
+                    println!("  {} {w}", "⚠".yellow());
+                }
+            }
+            Err(e) => {
+                println!("{} {e}", "✗".red());
+                ok = false;
+            }
+        },
+        Some(path) => println!("{} No config file at {}", "•".dimmed(), path.display()),
+        None => println!("{} No config directory on this platform", "•".dimmed()),
+    }
+
+    if args.check_auth {
+        match Config::load(token) {
+            Ok(cfg) => {
+                let source = cfg.token_source.to_string();
+                let client = ShortcutClient::new(cfg.api_token, &opts)?;
+                match client.current_member().await {
+                    Ok(me) => println!(
+                        "{} Token from {source} authenticates as {} (@{})",
+                        "✓".green(),
+                        me.name,
+                        me.mention_name
+                    ),
+                    Err(e) => {
+                        println!("{} Token from {source} failed: {e}", "✗".red());
+                        ok = false;
+                    }
+                }
+            }
+            Err(e) => {
+                println!("{} {e}", "✗".red());
+                ok = false;
+            }
+        }
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+    Ok(())
+}
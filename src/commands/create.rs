@@ -1,178 +1,1359 @@
-use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, bail};
 use colored::Colorize;
-use indicatif::{ProgressBar, ProgressStyle};
+use futures_util::{FutureExt, StreamExt, stream};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 use serde_json::json;
 
-use crate::api::ShortcutClient;
 use crate::api::models::{
-    CreateEpicRequest, CreateLabelParams, CreateObjectiveRequest, CreateStoryRequest, Epic,
-    Objective, Story,
+    CreateCategoryParams, CreateCommentRequest, CreateEpicHealthRequest, CreateEpicRequest,
+    CreateIterationRequest, CreateLabelParams, CreateLabelRequest, CreateLinkedFileRequest,
+    CreateObjectiveRequest, CreateStoryLinkRequest, CreateStoryRequest, CreateTaskParams,
+    CreateTaskRequest, Epic, Iteration, Label, Objective, Patch, Story, UpdateEpicRequest,
+    UpdateStoryRequest,
 };
-use crate::cli::{CreateArgs, OutputFormat};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{CreateArgs, DryRunLevel, IfExists, InputFormat, OutputFormat, ResourceType};
 use crate::config::Config;
-use crate::input;
-use crate::input::models::{InputEpic, InputObjective, InputStory};
-use crate::resolver::Resolver;
-use crate::template::Template;
+use crate::dedupe::{self, Outcome};
+use crate::echo;
+use crate::error::BypassError;
+use crate::graph;
+use crate::input::map::{ColumnMap, ValueMap};
+use crate::input::models::{
+    CLEAR, InputEpic, InputFile, InputIteration, InputLabel, InputLink, InputObjective, InputStory,
+    health_status, is_skipped,
+};
+use crate::input::{self, ColumnOptions, NameColumn};
+use crate::plan::{self, PlannedWrite};
+use crate::policy::Policy;
+use crate::preflight;
+use crate::preset::Presets;
+use crate::resolver::{Fixture, Resolver, epic_key, epic_label_ref, qualified_epic_key};
+use crate::schema;
+use crate::stage;
+use crate::template::{Template, link_reference};
+use crate::validate::{self, Validator};
+use crate::verify;
+
+use super::publish::DRAFT_LABEL;
+use super::{capitalized, json_event};
 
 // ---------------------------------------------------------------------------
 // Entry point
 // ---------------------------------------------------------------------------
 
-pub async fn run(args: CreateArgs, token: Option<String>) -> Result<()> {
-    let config = Config::load(token)?;
-    let client = ShortcutClient::new(config.api_token)?;
+pub async fn run(args: CreateArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    run_with(args, None, token, opts).await
+}
+
+/// Create from `input`, fetched elsewhere (`import`), instead of `--file`.
+/// Every other `create` option applies as usual.
+pub async fn run_parsed(
+    args: CreateArgs,
+    input: InputFile,
+    token: Option<String>,
+    opts: ClientOptions,
+) -> Result<()> {
+    run_with(args, Some(input), token, opts).await
+}
+
+async fn run_with(
+    args: CreateArgs,
+    input: Option<InputFile>,
+    token: Option<String>,
+    opts: ClientOptions,
+) -> Result<()> {
+    let started = Instant::now();
+    if let Some(path) = &args.echo_created {
+        echo::check(path)?;
+    }
+    let offline = args.dry_run.enabled && matches!(args.dry_run_level, DryRunLevel::Offline);
+
+    if args.stream {
+        if offline {
+            bail!("--dry-run-level offline is not supported with --stream");
+        }
+        let config = Config::load(token)?;
+        let client = ShortcutClient::new(config.api_token, &opts)?;
+        return run_stream(&args, &client).await;
+    }
 
-    // Parse the input file.
-    let input = input::parse_file(&args.file, args.r#type.as_ref())?;
+    // Parse the input file, unless the input came from elsewhere.
+    let mut input = match input {
+        Some(input) => input,
+        None => parse_input(&args)?,
+    };
+    if !args.source_rows {
+        input.labels.iter_mut().for_each(|l| l.source_row = None);
+    }
+    if !input.iterations.is_empty()
+        && let Some(path) = &args.echo_created
+        && echo::check(path)?.is_some()
+    {
+        bail!("The input defines iterations; --echo-created needs a .yaml file");
+    }
+    if !args.source_rows {
+        input
+            .iterations
+            .iter_mut()
+            .for_each(|i| i.source_row = None);
+        input
+            .objectives
+            .iter_mut()
+            .for_each(|o| o.source_row = None);
+        input.epics.iter_mut().for_each(|e| e.source_row = None);
+        input.stories.iter_mut().for_each(|s| s.source_row = None);
+    }
 
-    let total = input.objectives.len() + input.epics.len() + input.stories.len();
+    if let Some(path) = &args.validate_schema {
+        let violations = schema::check(path, &input)?;
+        if !violations.is_empty() {
+            bail!(
+                "Input does not match schema '{}' ({} violation(s)):\n  {}",
+                path.display(),
+                violations.len(),
+                violations.join("\n  ")
+            );
+        }
+    }
+
+    let mut map_misses = Vec::new();
+    if let Some(path) = &args.map_file {
+        map_misses = ValueMap::load(path)?.apply(&mut input);
+        if args.strict && !map_misses.is_empty() {
+            bail!("Unmapped values (--strict):\n  {}", map_misses.join("\n  "));
+        }
+    }
+
+    let presets = args
+        .presets
+        .as_deref()
+        .map(Presets::load)
+        .transpose()?
+        .unwrap_or_default();
+    let preset_errors = presets.expand(&mut input);
+    if !args.dry_run.enabled && !preset_errors.is_empty() {
+        bail!("{}", preset_errors.join("\n"));
+    }
+
+    if let Some(project) = &args.project {
+        for story in input.stories.iter_mut().filter(|s| s.project.is_none()) {
+            story.project = Some(project.clone());
+        }
+    }
+
+    let mut warnings = std::mem::take(&mut input.warnings);
+    warnings.extend(map_misses);
+    for obj in input
+        .objectives
+        .iter()
+        .filter(|o| !o.key_results.is_empty())
+    {
+        warnings.push(format!(
+            "Objective '{}': key results cannot be created through the Shortcut API; add them in the Shortcut UI",
+            obj.name
+        ));
+    }
+    if args.normalize_names {
+        warnings.extend(input::normalize::normalize_names(&mut input));
+    }
+
+    let total = input.labels.len()
+        + input.iterations.len()
+        + input.objectives.len()
+        + input.epics.len()
+        + input.stories.len();
     if total == 0 {
-        eprintln!("{}", "No items found in the input file.".yellow());
+        if !args.quiet {
+            eprintln!("{}", "No items found in the input file.".yellow());
+        }
         return Ok(());
     }
 
-    // Load global epic template if provided.
+    // Load global epic, objective and story templates if provided.
     let global_template = args
         .template
         .as_ref()
-        .map(|p| Template::load(p))
+        .map(|p| load_template(p, args.strict_templates))
         .transpose()?;
+    let global_objective_template = args
+        .objective_template
+        .as_ref()
+        .map(|p| load_template(p, args.strict_templates))
+        .transpose()?;
+    let global_story_template = args
+        .story_template
+        .as_ref()
+        .map(|p| load_template(p, args.strict_templates))
+        .transpose()?;
+
+    let policy = load_policy(&args)?;
 
-    if matches!(args.output, OutputFormat::Text) {
+    if chatty(&args) {
         println!(
-            "Parsed  {} objective(s)  {} epic(s)  {} story/stories",
+            "Parsed  {}{}{} objective(s)  {} epic(s)  {} story/stories{}",
+            if input.labels.is_empty() {
+                String::new()
+            } else {
+                format!("{} label(s)  ", input.labels.len().to_string().cyan())
+            },
+            if input.iterations.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{} iteration(s)  ",
+                    input.iterations.len().to_string().cyan()
+                )
+            },
             input.objectives.len().to_string().cyan(),
             input.epics.len().to_string().cyan(),
             input.stories.len().to_string().cyan(),
+            if input.skipped > 0 {
+                format!("  ({} skipped)", input.skipped)
+                    .dimmed()
+                    .to_string()
+            } else {
+                String::new()
+            }
         );
+        // Dry-run reports warnings alongside its validation errors.
+        if !args.dry_run.enabled {
+            for w in &warnings {
+                eprintln!("{} {w}", "⚠".yellow());
+            }
+        }
     }
 
-    // Fetch workspace data for name resolution.
-    let status_msg = "Fetching workspace data (members, groups, workflows)…";
-    if matches!(args.output, OutputFormat::Text) {
-        eprint!("{status_msg}");
+    if let Some(path) = &args.graph {
+        std::fs::write(path, graph::to_dot(&input))
+            .map_err(|e| anyhow::anyhow!("Cannot write graph '{}': {}", path.display(), e))?;
+        if chatty(&args) {
+            println!("Wrote dependency graph to {}", path.display());
+        }
+    }
+
+    if offline {
+        let mut errors = preset_errors;
+        validate::offline(&input, &policy, &mut errors);
+        return report_validation(
+            &errors,
+            &warnings,
+            &plan::plan(&input),
+            &args.output,
+            args.quiet,
+        );
     }
 
-    let mut resolver = Resolver::new(&client).await.inspect_err(|_| {
-        if matches!(args.output, OutputFormat::Text) {
-            eprintln!();
+    let mut epic_labels: Vec<&str> = input
+        .stories
+        .iter()
+        .filter_map(|s| s.epic.as_deref().and_then(epic_label_ref))
+        .collect();
+    epic_labels.sort_unstable();
+    epic_labels.dedup();
+
+    // A fixture stands in for the workspace; no token or network is needed.
+    let (client, mut resolver) = match &args.resolver_fixture {
+        Some(path) => {
+            let fixture = Fixture::load(path)?;
+            let resolver = Resolver::from_fixture(&fixture, &args.workflows, &epic_labels)?;
+            (None, resolver)
         }
-    })?;
+        None => {
+            let config = Config::load(token)?;
+            let client = ShortcutClient::new(config.api_token, &opts)?;
 
-    if matches!(args.output, OutputFormat::Text) {
-        eprintln!("  {}", "done".green());
+            if let Some(rt) = &args.if_empty
+                && !check_if_empty(&client, rt, &args.output, args.quiet).await?
+            {
+                return Ok(());
+            }
+
+            let resolver = fetch_workspace(&client, &args, &input, &epic_labels).await?;
+            (Some(client), resolver)
+        }
+    };
+
+    if let Some(path) = &args.team_alias {
+        resolver.load_team_aliases(path)?;
+    }
+    if !args.email_domain_map.is_empty() {
+        resolver.set_email_domain_map(args.email_domain_map.iter().cloned().collect());
     }
 
-    if args.dry_run {
-        return dry_run(&input, &resolver, global_template.as_ref(), &args.output);
+    let mut owner_defaulted = 0;
+    if args.default_self_owner
+        && let Some(client) = &client
+    {
+        let me = client.current_member().await?.mention_name;
+        for owners in input
+            .epics
+            .iter_mut()
+            .map(|e| &mut e.owners)
+            .chain(input.stories.iter_mut().map(|s| &mut s.owners))
+        {
+            if owners.is_empty() {
+                owners.push(me.clone());
+                owner_defaulted += 1;
+            }
+        }
+        if chatty(&args) && owner_defaulted > 0 {
+            println!("Owner defaulted to @{me} on {owner_defaulted} epic(s)/story(ies)");
+        }
     }
 
-    let mut results = RunResults::default();
+    if args.due_from_iteration {
+        let ends = batch_iteration_ends(&input.iterations);
+        let mut defaulted = 0;
+        for story in &mut input.stories {
+            if due_from_iteration(story, &ends, &resolver) {
+                defaulted += 1;
+            }
+        }
+        if chatty(&args) && defaulted > 0 {
+            println!("Due date defaulted to the iteration's end on {defaulted} story(ies)");
+        }
+    }
+
+    if args.stage {
+        stage::check_staging_state(&resolver, &args.staging_state)?;
+        for story in &mut input.stories {
+            stage::stage_story(story, &args.staging_state, &resolver);
+        }
+    }
+
+    if args.dry_run.enabled {
+        let mut errors = preset_errors;
+        if let Some(client) = &client
+            && args.if_exists != IfExists::Create
+        {
+            dry_run_existing(client, &args, &mut input, &mut resolver, &mut errors).await?;
+        }
+        return dry_run(
+            errors,
+            &input,
+            &resolver,
+            &policy,
+            &warnings,
+            &args.output,
+            args.quiet,
+        );
+    }
+    let Some(client) = client else {
+        bail!("--resolver-fixture requires --dry-run");
+    };
+
+    let unresolved = preflight::Report::check(&input, &resolver);
+    if !unresolved.is_empty() {
+        match args.output {
+            OutputFormat::Text => unresolved.print(),
+            OutputFormat::Json => println!("{}", json_event(&unresolved.to_json())),
+        }
+        if !args.ignore_resolution_errors {
+            bail!(
+                "{} unresolved name(s); nothing was created \
+                 (pass --ignore-resolution-errors to create the rest anyway)",
+                unresolved.names()
+            );
+        }
+    }
+
+    if args.confirm && !confirm(&input, args.yes)? {
+        eprintln!("{}", "Aborted – nothing was created.".yellow());
+        return Ok(());
+    }
+
+    let mut results = RunResults {
+        skipped_rows: input.skipped,
+        owner_defaulted,
+        ..Default::default()
+    };
+    let mut echoed = InputFile::default();
+    let deadline = args.max_runtime.map(|s| started + Duration::from_secs(s));
+
+    // Create order: labels → iterations → objectives → epics → stories so that name
+    // references within the same file resolve correctly.
+    let bars = Bars::new(&input, args.quiet);
+
+    let concurrency = usize::from(args.concurrency);
+
+    // ---- Labels ----
+    if !input.labels.is_empty() {
+        let pb = bars.labels.clone();
+        let mut created: Vec<Option<Label>> = input.labels.iter().map(|_| None).collect();
+        let mut tasks = stream::iter(input.labels.iter().enumerate())
+            .map(|(i, label)| {
+                label_task(&client, &args, label, deadline, &pb)
+                    .map(move |done| done.map(|d| (i, d)))
+            })
+            .buffer_unordered(concurrency);
+        while let Some((i, done)) = tasks.next().await.transpose()? {
+            created[i] = results.fold(done, |r| &mut r.labels_ok).map(|(_, l)| l);
+        }
+        drop(tasks);
+        pb.finish_with_message("done");
+
+        for (label, created) in input.labels.iter().zip(&created) {
+            if let Some(l) = created {
+                echoed.labels.push(InputLabel {
+                    id: Some(l.id),
+                    ..label.clone()
+                });
+            }
+        }
+    }
 
-    // Create order: objectives → epics → stories so that name references
-    // within the same file resolve correctly.
+    // ---- Iterations ----
+    if !input.iterations.is_empty() {
+        let pb = bars.iterations.clone();
+        let mut created: Vec<Option<Iteration>> = input.iterations.iter().map(|_| None).collect();
+        let mut tasks = stream::iter(input.iterations.iter().enumerate())
+            .map(|(i, iteration)| {
+                iteration_task(&client, &args, iteration, &resolver, deadline, &pb)
+                    .map(move |done| done.map(|d| (i, d)))
+            })
+            .buffer_unordered(concurrency);
+        while let Some((i, done)) = tasks.next().await.transpose()? {
+            created[i] = results
+                .fold(done, |r| &mut r.iterations_ok)
+                .map(|(_, it)| it);
+        }
+        drop(tasks);
+        pb.finish_with_message("done");
+
+        for (iteration, created) in input.iterations.iter().zip(&created) {
+            if let Some(it) = created {
+                resolver.register_iteration(iteration.name.trim().to_string(), it.id);
+                echoed.iterations.push(InputIteration {
+                    id: Some(it.id),
+                    ..iteration.clone()
+                });
+            }
+        }
+    }
 
     // ---- Objectives ----
     if !input.objectives.is_empty() {
-        let pb = make_pb(input.objectives.len() as u64, "objectives");
-        for obj in &input.objectives {
-            pb.set_message(obj.name.clone());
-            match build_and_create_objective(&client, obj).await {
-                Ok(created) => {
-                    resolver.register_objective(obj.name.clone(), created.id);
-                    results.objectives_ok += 1;
-                    emit_ok(
-                        &args.output,
-                        "objective",
-                        &created.name,
-                        created.id,
-                        created.app_url.as_deref(),
-                        &pb,
-                    );
-                }
-                Err(e) => {
-                    results
-                        .errors
-                        .push(format!("Objective '{}': {e}", obj.name));
-                    emit_err(&args.output, "objective", &obj.name, &e.to_string(), &pb);
-                }
+        let pb = bars.objectives.clone();
+        let mut created: Vec<Option<Objective>> = input.objectives.iter().map(|_| None).collect();
+        let mut tasks = stream::iter(input.objectives.iter().enumerate())
+            .map(|(i, obj)| {
+                let template = global_objective_template.as_ref();
+                objective_task(&client, &args, obj, &resolver, template, deadline, &pb)
+                    .map(move |done| done.map(|d| (i, d)))
+            })
+            .buffer_unordered(concurrency);
+        while let Some((i, done)) = tasks.next().await.transpose()? {
+            created[i] = results.fold(done, |r| &mut r.objectives_ok).map(|(_, o)| o);
+        }
+        drop(tasks);
+        pb.finish_with_message("done");
+
+        // Registered once the phase is over, in input order, so that epics
+        // can reference them.
+        for (obj, created) in input.objectives.iter().zip(&created) {
+            if let Some(o) = created {
+                resolver.register_objective(obj.name.clone(), o.id, o.app_url.clone());
+                echoed.objectives.push(InputObjective {
+                    id: Some(o.id),
+                    ..obj.clone()
+                });
             }
-            pb.inc(1);
         }
-        pb.finish_and_clear();
     }
 
     // ---- Epics ----
     if !input.epics.is_empty() {
-        let pb = make_pb(input.epics.len() as u64, "epics");
-        for epic in &input.epics {
-            pb.set_message(epic.name.clone());
-
-            // Per-epic template overrides global template.
-            let template = epic
-                .template
-                .as_ref()
-                .map(|p| Template::load(std::path::Path::new(p)))
-                .transpose()?
-                .or_else(|| global_template.clone());
-
-            match build_and_create_epic(&client, epic, &resolver, template.as_ref()).await {
-                Ok(created) => {
-                    resolver.register_epic(epic.name.clone(), created.id);
-                    results.epics_ok += 1;
-                    emit_ok(
-                        &args.output,
-                        "epic",
-                        &created.name,
-                        created.id,
-                        created.app_url.as_deref(),
-                        &pb,
-                    );
-                }
-                Err(e) => {
-                    results.errors.push(format!("Epic '{}': {e}", epic.name));
-                    emit_err(&args.output, "epic", &epic.name, &e.to_string(), &pb);
+        let pb = bars.epics.clone();
+        let mut created: Vec<Option<Epic>> = input.epics.iter().map(|_| None).collect();
+        let mut tasks = stream::iter(input.epics.iter().enumerate())
+            .map(|(i, epic)| {
+                let template = global_template.as_ref();
+                epic_task(&client, &args, epic, &resolver, template, deadline, &pb)
+                    .map(move |done| done.map(|d| (i, d)))
+            })
+            .buffer_unordered(concurrency);
+        while let Some((i, done)) = tasks.next().await.transpose()? {
+            if let Some((outcome, epic)) = results.fold(done, |r| &mut r.epics_ok) {
+                if args.draft && outcome == Outcome::Created {
+                    results.drafts += 1;
                 }
+                created[i] = Some(epic);
             }
-            pb.inc(1);
         }
-        pb.finish_and_clear();
+        drop(tasks);
+        pb.finish_with_message("done");
+
+        // Registered once the phase is over, in input order, so that
+        // stories can reference them.
+        for (epic, created) in input.epics.iter().zip(&created) {
+            if let Some(e) = created {
+                resolver.register_epic(
+                    epic.name.clone(),
+                    epic.objective.as_deref(),
+                    e.id,
+                    e.app_url.clone(),
+                );
+                resolver.register_epic_labels(&epic.labels, e.id);
+                echoed.epics.push(InputEpic {
+                    id: Some(e.id),
+                    preset: Vec::new(),
+                    ..epic.clone()
+                });
+            }
+        }
+
+        let created_ids: Vec<Option<i64>> =
+            created.iter().map(|e| e.as_ref().map(|e| e.id)).collect();
+        order_epics(&client, &input.epics, &created_ids, &mut results).await;
     }
 
     // ---- Stories ----
     if !input.stories.is_empty() {
-        let pb = make_pb(input.stories.len() as u64, "stories");
-        for story in &input.stories {
-            pb.set_message(story.name.clone());
-            match build_and_create_story(&client, story, &resolver).await {
-                Ok(created) => {
-                    results.stories_ok += 1;
-                    emit_ok(
+        let pb = bars.stories.clone();
+        let mut created: Vec<Option<(Outcome, i64)>> = input.stories.iter().map(|_| None).collect();
+        let mut tasks = stream::iter(input.stories.iter().enumerate())
+            .map(|(i, story)| {
+                let template = global_story_template.as_ref();
+                story_task(&client, &args, story, &resolver, template, deadline, &pb)
+                    .map(move |done| done.map(|d| (i, d)))
+            })
+            .buffer_unordered(concurrency);
+        while let Some((i, done)) = tasks.next().await.transpose()? {
+            if let Some((outcome, story)) = results.fold(done, |r| &mut r.stories_ok) {
+                if args.draft && outcome == Outcome::Created {
+                    results.drafts += 1;
+                }
+                created[i] = Some((outcome, story.id));
+            }
+        }
+        drop(tasks);
+
+        for (story, done) in input.stories.iter().zip(&created) {
+            if let Some((_, id)) = done {
+                resolver.register_story(story.name.clone(), *id);
+                echoed.stories.push(echo_story(story, *id));
+            }
+        }
+        // Links wait for every story, since they may point forward.
+        for (story, done) in input.stories.iter().zip(&created) {
+            if let Some((Outcome::Created, id)) = done {
+                link_stories(
+                    &client,
+                    &args.output,
+                    story,
+                    *id,
+                    &resolver,
+                    &mut results.errors,
+                    &pb,
+                )
+                .await;
+            }
+        }
+        pb.finish_with_message("done");
+    }
+    drop(bars);
+
+    if let Some(path) = &args.echo_created {
+        echo::write(path, &echoed)?;
+    }
+    if let Some(path) = &args.metrics_file {
+        write_metrics(path, &results, &client, started.elapsed())?;
+    }
+    print_summary(&results, &client, &args.output, args.quiet)
+}
+
+// ---------------------------------------------------------------------------
+// Concurrent phases (--concurrency)
+// ---------------------------------------------------------------------------
+
+/// How one item of a phase ended, before it is folded into the run's
+/// results.  Items finish in any order; folding only counts.
+enum Done<T> {
+    /// Not started because `--max-runtime` ran out.
+    Unprocessed,
+    /// The create failed; the message for the summary.
+    Failed(String),
+    /// The resource exists.  `errors` holds failed follow-ups (health,
+    /// links, `--verify`) reported against it.
+    Ok {
+        outcome: Outcome,
+        resource: T,
+        errors: Vec<String>,
+    },
+}
+
+/// Create one label and report it.  `Err` stops the run.
+async fn label_task(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    label: &InputLabel,
+    deadline: Option<Instant>,
+    pb: &ProgressBar,
+) -> Result<Done<Label>> {
+    if out_of_time(deadline) {
+        return Ok(Done::Unprocessed);
+    }
+    pb.set_message(label.name.clone());
+
+    let done = match build_and_create_label(client, label, unhashed_if_exists(args)).await {
+        Ok((outcome, created)) => {
+            emit_outcome(
+                &args.output,
+                outcome,
+                "label",
+                &created.name,
+                created.id,
+                None,
+                pb,
+            );
+            Done::Ok {
+                outcome,
+                resource: created,
+                errors: Vec::new(),
+            }
+        }
+        Err(e) => {
+            abort_on_auth(&e, pb)?;
+            let item = Item::label(label);
+            emit_err(&args.output, &item, &e.to_string(), pb);
+            Done::Failed(format!("{}: {e}", item.subject()))
+        }
+    };
+    pb.inc(1);
+    Ok(done)
+}
+
+/// Create one iteration and report it.  `Err` stops the run.
+async fn iteration_task(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    iteration: &InputIteration,
+    resolver: &Resolver,
+    deadline: Option<Instant>,
+    pb: &ProgressBar,
+) -> Result<Done<Iteration>> {
+    if out_of_time(deadline) {
+        return Ok(Done::Unprocessed);
+    }
+    pb.set_message(iteration.name.clone());
+
+    let done =
+        match build_and_create_iteration(client, iteration, resolver, unhashed_if_exists(args))
+            .await
+        {
+            Ok((outcome, created)) => {
+                emit_outcome(
+                    &args.output,
+                    outcome,
+                    "iteration",
+                    &created.name,
+                    created.id,
+                    created.app_url.as_deref(),
+                    pb,
+                );
+                Done::Ok {
+                    outcome,
+                    resource: created,
+                    errors: Vec::new(),
+                }
+            }
+            Err(e) => {
+                abort_on_auth(&e, pb)?;
+                let item = Item::iteration(iteration);
+                emit_err(&args.output, &item, &e.to_string(), pb);
+                Done::Failed(format!("{}: {e}", item.subject()))
+            }
+        };
+    pb.inc(1);
+    Ok(done)
+}
+
+/// Create one objective and report it.  `Err` stops the run.
+async fn objective_task(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    obj: &InputObjective,
+    resolver: &Resolver,
+    global_template: Option<&Template>,
+    deadline: Option<Instant>,
+    pb: &ProgressBar,
+) -> Result<Done<Objective>> {
+    if out_of_time(deadline) {
+        return Ok(Done::Unprocessed);
+    }
+    pb.set_message(obj.name.clone());
+
+    // Per-objective template overrides global template.
+    let template = obj
+        .template
+        .as_ref()
+        .map(|p| load_template(Path::new(p), args.strict_templates))
+        .transpose()?
+        .or_else(|| global_template.cloned());
+
+    let done = match build_and_create_objective(
+        client,
+        obj,
+        resolver,
+        template.as_ref(),
+        unhashed_if_exists(args),
+    )
+    .await
+    {
+        Ok((outcome, created)) => {
+            emit_outcome(
+                &args.output,
+                outcome,
+                "objective",
+                &created.name,
+                created.id,
+                created.app_url.as_deref(),
+                pb,
+            );
+            let mut errors = Vec::new();
+            if args.verify && outcome.wrote() {
+                let check = verify::objective(client, obj, created.id).await;
+                let item = Item::objective(obj);
+                report_verify(&args.output, &item, created.id, check, &mut errors, pb);
+            }
+            Done::Ok {
+                outcome,
+                resource: created,
+                errors,
+            }
+        }
+        Err(e) => {
+            abort_on_auth(&e, pb)?;
+            emit_err(&args.output, &Item::objective(obj), &e.to_string(), pb);
+            Done::Failed(format!("{}: {e}", Item::objective(obj).subject()))
+        }
+    };
+    pb.inc(1);
+    Ok(done)
+}
+
+/// Create (or dedupe) one epic, set its health, and report it.  `Err`
+/// stops the run.
+async fn epic_task(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    epic: &InputEpic,
+    resolver: &Resolver,
+    global_template: Option<&Template>,
+    deadline: Option<Instant>,
+    pb: &ProgressBar,
+) -> Result<Done<Epic>> {
+    if out_of_time(deadline) {
+        return Ok(Done::Unprocessed);
+    }
+    pb.set_message(epic.name.clone());
+
+    // Per-epic template overrides global template.
+    let template = epic
+        .template
+        .as_ref()
+        .map(|p| load_template(Path::new(p), args.strict_templates))
+        .transpose()?
+        .or_else(|| global_template.cloned());
+
+    let opts = CreateOptions::from(args);
+    let done = match build_and_create_epic(client, epic, resolver, template.as_ref(), &opts).await {
+        Ok((outcome, created)) => {
+            emit_outcome(
+                &args.output,
+                outcome,
+                "epic",
+                &created.name,
+                created.id,
+                created.app_url.as_deref(),
+                pb,
+            );
+            let mut errors = Vec::new();
+            if opts.draft && outcome == Outcome::Created {
+                archive_draft_epic(client, &args.output, epic, created.id, &mut errors, pb).await;
+            }
+            if outcome.wrote() {
+                set_health(client, &args.output, epic, created.id, &mut errors, pb).await;
+            }
+            if args.verify && outcome.wrote() {
+                let check = verify::epic(client, epic, resolver, created.id).await;
+                let item = Item::epic(epic);
+                report_verify(&args.output, &item, created.id, check, &mut errors, pb);
+            }
+            Done::Ok {
+                outcome,
+                resource: created,
+                errors,
+            }
+        }
+        Err(e) => {
+            abort_on_auth(&e, pb)?;
+            emit_err(&args.output, &Item::epic(epic), &e.to_string(), pb);
+            Done::Failed(format!("{}: {e}", Item::epic(epic).subject()))
+        }
+    };
+    pb.inc(1);
+    Ok(done)
+}
+
+/// Create (or dedupe) one story, attach its links, and report it.  `Err`
+/// stops the run.
+async fn story_task(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    story: &InputStory,
+    resolver: &Resolver,
+    global_template: Option<&Template>,
+    deadline: Option<Instant>,
+    pb: &ProgressBar,
+) -> Result<Done<Story>> {
+    if out_of_time(deadline) {
+        return Ok(Done::Unprocessed);
+    }
+    pb.set_message(story.name.clone());
+
+    // Per-story template overrides global template.
+    let template = story
+        .template
+        .as_ref()
+        .map(|p| load_template(Path::new(p), args.strict_templates))
+        .transpose()?
+        .or_else(|| global_template.cloned());
+
+    let opts = CreateOptions::from(args);
+    let done = match build_and_create_story(client, story, resolver, template.as_ref(), &opts).await
+    {
+        Ok((outcome, created)) => {
+            emit_outcome(
+                &args.output,
+                outcome,
+                "story",
+                &created.name,
+                created.id,
+                created.app_url.as_deref(),
+                pb,
+            );
+            let mut errors = Vec::new();
+            if opts.draft && outcome == Outcome::Created {
+                archive_draft_story(client, &args.output, story, created.id, &mut errors, pb).await;
+            }
+            if outcome == Outcome::Created {
+                attach_links(client, &args.output, story, created.id, &mut errors, pb).await;
+                add_tasks(
+                    client,
+                    &args.output,
+                    story,
+                    created.id,
+                    resolver,
+                    &mut errors,
+                    pb,
+                )
+                .await;
+                add_comments(
+                    client,
+                    &args.output,
+                    story,
+                    created.id,
+                    resolver,
+                    &mut errors,
+                    pb,
+                )
+                .await;
+            }
+            if args.verify && outcome.wrote() {
+                let check = verify::story(client, story, resolver, created.id).await;
+                let item = Item::story(story);
+                report_verify(&args.output, &item, created.id, check, &mut errors, pb);
+            }
+            Done::Ok {
+                outcome,
+                resource: created,
+                errors,
+            }
+        }
+        Err(e) => {
+            abort_on_auth(&e, pb)?;
+            emit_err(&args.output, &Item::story(story), &e.to_string(), pb);
+            Done::Failed(format!("{}: {e}", Item::story(story).subject()))
+        }
+    };
+    pb.inc(1);
+    Ok(done)
+}
+
+// ---------------------------------------------------------------------------
+// Streaming mode (--stream)
+// ---------------------------------------------------------------------------
+
+/// Create stories from a CSV/TSV as rows are read, so memory stays bounded
+/// and the first story is created immediately.  Stories have no in-batch
+/// dependants, which is what makes file-order streaming safe.
+async fn run_stream(args: &CreateArgs, client: &ShortcutClient) -> Result<()> {
+    let started = Instant::now();
+    let files = input::glob::expand(&args.file)?;
+    let [path] = files.as_slice() else {
+        bail!("--stream reads a single file");
+    };
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    let delimiter = match ext.as_str() {
+        "csv" => b',',
+        "tsv" => b'\t',
+        _ => bail!("--stream supports only .csv and .tsv files"),
+    };
+    if !matches!(args.r#type, Some(ResourceType::Story)) {
+        bail!("--stream requires --type story");
+    }
+
+    let stream = input::csv::stream_stories(path, delimiter, &column_options(args)?)?;
+    let mut warnings = stream.warnings;
+    let value_map = args.map_file.as_deref().map(ValueMap::load).transpose()?;
+    let presets = args
+        .presets
+        .as_deref()
+        .map(Presets::load)
+        .transpose()?
+        .unwrap_or_default();
+    let mut seen_misses = HashSet::new();
+
+    let policy = load_policy(args)?;
+    let global_template = args
+        .story_template
+        .as_ref()
+        .map(|p| load_template(p, args.strict_templates))
+        .transpose()?;
+
+    if chatty(args) {
+        println!("Streaming stories from {}", path.display());
+        if !args.dry_run.enabled {
+            for w in &warnings {
+                eprintln!("{} {w}", "⚠".yellow());
+            }
+        }
+        eprint!("Fetching workspace data (members, groups, workflows, iterations)…");
+    }
+
+    let mut resolver = Resolver::new(client, &args.workflows)
+        .await
+        .inspect_err(|_| {
+            if chatty(args) {
+                eprintln!();
+            }
+        })?;
+    if args.project.is_some() || stream.headers.iter().any(|h| h == "project") {
+        resolver.load_projects(client).await?;
+    }
+
+    if chatty(args) {
+        eprintln!("  {}", "done".green());
+    }
+
+    if let Some(path) = &args.team_alias {
+        resolver.load_team_aliases(path)?;
+    }
+    if !args.email_domain_map.is_empty() {
+        resolver.set_email_domain_map(args.email_domain_map.iter().cloned().collect());
+    }
+    if args.stage {
+        stage::check_staging_state(&resolver, &args.staging_state)?;
+    }
+    let self_owner = if args.default_self_owner {
+        Some(client.current_member().await?.mention_name)
+    } else {
+        None
+    };
+
+    // Streamed stories cannot reference epics in the same file, so the
+    // validator works against an empty batch.
+    let batch = InputFile::default();
+    let mut errors: Vec<String> = Vec::new();
+    let mut validator = Validator::new(&batch, &resolver, &policy, &mut errors);
+    // Policy errors would silently drop a rule from every row's checks;
+    // a dry run reports them with the rows' errors.
+    if !args.dry_run.enabled && !errors.is_empty() {
+        bail!("{}", errors.join("\n"));
+    }
+    let mut planned: Vec<PlannedWrite> = Vec::new();
+    let mut results = RunResults::default();
+    let mut echoed = InputFile::default();
+
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} Creating stories {pos} {msg:.dim}")
+            .unwrap(),
+    );
+    if args.quiet {
+        pb.set_draw_target(ProgressDrawTarget::hidden());
+    }
+
+    let deadline = args.max_runtime.map(|s| started + Duration::from_secs(s));
+    for row in stream.rows {
+        // Rows past the deadline are still read, to count them.
+        if !args.dry_run.enabled && out_of_time(deadline) {
+            results.unprocessed += 1;
+            continue;
+        }
+        let mut story = match row {
+            Ok(story) if args.source_rows => story,
+            Ok(story) => InputStory {
+                source_row: None,
+                ..story
+            },
+            Err(e) => {
+                errors.push(e.to_string());
+                results.errors.push(e.to_string());
+                continue;
+            }
+        };
+        if is_skipped(&story.name, story.skip) {
+            results.skipped_rows += 1;
+            continue;
+        }
+        let mut map_errors = Vec::new();
+        if let Some(map) = &value_map {
+            for miss in map.apply_story(&mut story) {
+                if args.strict {
+                    map_errors.push(format!("Story '{}': {miss}", story.name));
+                } else if seen_misses.insert(miss.clone()) {
+                    if chatty(args) && !args.dry_run.enabled {
+                        pb.println(format!("{} {miss}", "⚠".yellow()));
+                    }
+                    warnings.push(miss);
+                }
+            }
+        }
+        let mut preset_errors = Vec::new();
+        presets.expand_story(&mut story, &mut preset_errors);
+        if story.project.is_none() {
+            story.project = args.project.clone();
+        }
+        if let Some(me) = &self_owner
+            && story.owners.is_empty()
+        {
+            story.owners.push(me.clone());
+            results.owner_defaulted += 1;
+        }
+        if args.due_from_iteration {
+            due_from_iteration(&mut story, &HashMap::new(), &resolver);
+        }
+        if args.normalize_names {
+            input::normalize::normalize_story(&mut story, &mut warnings);
+        }
+        if args.stage {
+            stage::stage_story(&mut story, &args.staging_state, &resolver);
+        }
+
+        // `label:` references are looked up the first time they are seen.
+        if let Some(label) = story.epic.as_deref().and_then(epic_label_ref)
+            && !resolver.epic_label_map.contains_key(label)
+        {
+            resolver.load_epic_labels(client, &[label]).await?;
+            // Its policy errors repeat the ones collected above.
+            validator = Validator::new(&batch, &resolver, &policy, &mut Vec::new());
+        }
+
+        pb.set_message(story.name.clone());
+        let mut row_errors = map_errors;
+        row_errors.extend(preset_errors);
+        validator.story(&story, &mut row_errors);
+
+        if args.dry_run.enabled {
+            errors.extend(row_errors);
+            planned.extend(plan::story_writes(&story));
+        } else if !row_errors.is_empty() {
+            for e in row_errors {
+                emit_err(&args.output, &Item::story(&story), &e, &pb);
+                results.errors.push(e);
+            }
+        } else {
+            // Per-story template overrides global template.
+            let template = match &story.template {
+                Some(p) => Some(load_template(Path::new(p), args.strict_templates)?),
+                None => global_template.clone(),
+            };
+            let opts = CreateOptions::from(args);
+            match build_and_create_story(client, &story, &resolver, template.as_ref(), &opts).await
+            {
+                Ok((outcome, created)) => {
+                    echoed.stories.push(echo_story(&story, created.id));
+                    results.count(outcome, |r| &mut r.stories_ok);
+                    if args.draft && outcome == Outcome::Created {
+                        results.drafts += 1;
+                        archive_draft_story(
+                            client,
+                            &args.output,
+                            &story,
+                            created.id,
+                            &mut results.errors,
+                            &pb,
+                        )
+                        .await;
+                    }
+                    emit_outcome(
                         &args.output,
+                        outcome,
                         "story",
                         &created.name,
                         created.id,
                         created.app_url.as_deref(),
                         &pb,
                     );
+                    if outcome == Outcome::Created {
+                        attach_links(
+                            client,
+                            &args.output,
+                            &story,
+                            created.id,
+                            &mut results.errors,
+                            &pb,
+                        )
+                        .await;
+                        add_tasks(
+                            client,
+                            &args.output,
+                            &story,
+                            created.id,
+                            &resolver,
+                            &mut results.errors,
+                            &pb,
+                        )
+                        .await;
+                        add_comments(
+                            client,
+                            &args.output,
+                            &story,
+                            created.id,
+                            &resolver,
+                            &mut results.errors,
+                            &pb,
+                        )
+                        .await;
+                    }
+                    if args.verify && outcome.wrote() {
+                        let check = verify::story(client, &story, &resolver, created.id).await;
+                        report_verify(
+                            &args.output,
+                            &Item::story(&story),
+                            created.id,
+                            check,
+                            &mut results.errors,
+                            &pb,
+                        );
+                    }
                 }
                 Err(e) => {
-                    results.errors.push(format!("Story '{}': {e}", story.name));
-                    emit_err(&args.output, "story", &story.name, &e.to_string(), &pb);
+                    abort_on_auth(&e, &pb)?;
+                    results
+                        .errors
+                        .push(format!("{}: {e}", Item::story(&story).subject()));
+                    emit_err(&args.output, &Item::story(&story), &e.to_string(), &pb);
                 }
             }
-            pb.inc(1);
         }
-        pb.finish_and_clear();
+        pb.inc(1);
+    }
+    pb.finish_and_clear();
+
+    if args.dry_run.enabled {
+        return report_validation(&errors, &warnings, &planned, &args.output, args.quiet);
+    }
+    if let Some(path) = &args.echo_created {
+        echo::write(path, &echoed)?;
+    }
+    if let Some(path) = &args.metrics_file {
+        write_metrics(path, &results, client, started.elapsed())?;
+    }
+    print_summary(&results, client, &args.output, args.quiet)
+}
+
+// ---------------------------------------------------------------------------
+// Epic ordering
+// ---------------------------------------------------------------------------
+
+/// Arrange the created epics of each objective (see [`plan::epic_order`])
+/// by chaining `PUT /epics/{id}` with `after_id`.
+/// `created_ids` is parallel to `epics`; failed creations are skipped.
+async fn order_epics(
+    client: &ShortcutClient,
+    epics: &[InputEpic],
+    created_ids: &[Option<i64>],
+    results: &mut RunResults,
+) {
+    for (obj, group) in plan::epic_order(epics) {
+        let ordered: Vec<(usize, i64)> = group
+            .into_iter()
+            .filter_map(|i| created_ids[i].map(|id| (i, id)))
+            .collect();
+        for pair in ordered.windows(2) {
+            let ((_, prev), (i, id)) = (pair[0], pair[1]);
+            let req = UpdateEpicRequest {
+                after_id: Some(prev),
+                ..Default::default()
+            };
+            if let Err(e) = client.update_epic(id, &req).await {
+                results.errors.push(format!(
+                    "Epic '{}': could not order under objective '{obj}': {e}",
+                    epics[i].name
+                ));
+            }
+        }
+    }
+}
+
+/// Load a description template, checking its `{{env.*}}` placeholders
+/// under `--strict-templates`.
+fn load_template(path: &Path, strict: bool) -> Result<Template> {
+    let template = Template::load(path)?;
+    if strict {
+        template
+            .check_env()
+            .map_err(|e| anyhow::anyhow!("{e} (in '{}')", path.display()))?;
+    }
+    Ok(template)
+}
+
+/// The `--policy` file, if any, with `--story-types` applied.
+fn load_policy(args: &CreateArgs) -> Result<Policy> {
+    let mut policy = args
+        .policy
+        .as_ref()
+        .map(|p| Policy::load(p))
+        .transpose()?
+        .unwrap_or_default();
+    if !args.story_types.is_empty() {
+        policy.story_types = args.story_types.clone();
+    }
+    Ok(policy)
+}
+
+/// The header handling selected by `--name-column` / `--name-is-first`
+/// and `--column-map`.
+fn column_options(args: &CreateArgs) -> Result<ColumnOptions> {
+    let name_column = match &args.name_column {
+        Some(header) => NameColumn::Header(header.clone()),
+        None if args.name_is_first => NameColumn::NameOrFirst,
+        None => NameColumn::Name,
+    };
+    let column_map = args
+        .column_map
+        .as_deref()
+        .map(ColumnMap::load)
+        .transpose()?
+        .unwrap_or_default();
+    Ok(ColumnOptions {
+        name_column,
+        column_map,
+    })
+}
+
+/// Items of each type listed by the `--confirm` preview.
+const PREVIEW_ITEMS: usize = 5;
+
+/// `--confirm`: show what is about to be created (on stderr) and ask.  The
+/// prompt is skipped, and the run goes ahead, with --yes or when stdin is
+/// not a terminal.
+fn confirm(input: &InputFile, yes: bool) -> Result<bool> {
+    eprintln!(
+        "{}",
+        "─── Preview ───────────────────────────────────".dimmed()
+    );
+    preview("Labels", input.labels.iter().map(|l| &l.name));
+    preview("Iterations", input.iterations.iter().map(|i| &i.name));
+    preview("Objectives", input.objectives.iter().map(|o| &o.name));
+    preview("Epics", input.epics.iter().map(|e| &e.name));
+    preview("Stories", input.stories.iter().map(|s| &s.name));
+
+    if yes || !std::io::stdin().is_terminal() {
+        return Ok(true);
+    }
+    let total = input.labels.len()
+        + input.iterations.len()
+        + input.objectives.len()
+        + input.epics.len()
+        + input.stories.len();
+    super::confirm(&format!(
+        "Create {total} resource{}?",
+        if total == 1 { "" } else { "s" }
+    ))
+}
+
+fn preview<'a>(label: &str, names: impl ExactSizeIterator<Item = &'a String>) {
+    let count = names.len();
+    if count == 0 {
+        return;
+    }
+    eprintln!("{label:<11}: {count}");
+    for name in names.take(PREVIEW_ITEMS) {
+        eprintln!("  {} {name}", "•".dimmed());
     }
+    if count > PREVIEW_ITEMS {
+        eprintln!(
+            "  {}",
+            format!("… and {} more", count - PREVIEW_ITEMS).dimmed()
+        );
+    }
+}
+
+/// Whether `--max-runtime` has run out; no new creates are started after.
+fn out_of_time(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|d| Instant::now() >= d)
+}
+
+/// Whether informational text (banners, status lines, warnings) is shown.
+pub(super) fn chatty(args: &CreateArgs) -> bool {
+    matches!(args.output, OutputFormat::Text) && !args.quiet
+}
+
+// ---------------------------------------------------------------------------
+// Summary
+// ---------------------------------------------------------------------------
 
-    // ---- Summary ----
-    if matches!(args.output, OutputFormat::Text) {
+/// Exit code when `--max-runtime` ran out with items left unprocessed.
+const EXIT_RUNTIME: i32 = 4;
+
+/// Print the end-of-run summary; exits [`EXIT_RUNTIME`] when
+/// `--max-runtime` left items unprocessed, otherwise 1 when any resource
+/// failed.
+/// With `quiet`, text output is reduced to the errors, on stderr.
+fn print_summary(
+    results: &RunResults,
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    quiet: bool,
+) -> Result<()> {
+    let retries = client.retry_stats();
+    if quiet && matches!(*output, OutputFormat::Text) {
+        for err in &results.errors {
+            eprintln!("{} {err}", "✗".red());
+        }
+    } else if matches!(*output, OutputFormat::Text) {
         println!(
             "\n{}",
             "─── Summary ───────────────────────────────────".dimmed()
         );
+        if results.labels_ok > 0 {
+            println!(
+                "  Labels created     : {}",
+                results.labels_ok.to_string().green()
+            );
+        }
+        if results.iterations_ok > 0 {
+            println!(
+                "  Iterations created : {}",
+                results.iterations_ok.to_string().green()
+            );
+        }
         println!(
             "  Objectives created : {}",
             results.objectives_ok.to_string().green()
@@ -185,6 +1366,36 @@ pub async fn run(args: CreateArgs, token: Option<String>) -> Result<()> {
             "  Stories created    : {}",
             results.stories_ok.to_string().green()
         );
+        if results.drafts > 0 {
+            println!("  Created as draft   : {}", results.drafts);
+        }
+        if results.skipped_rows > 0 {
+            println!("  Rows skipped       : {}", results.skipped_rows);
+        }
+        if results.owner_defaulted > 0 {
+            println!("  Owner defaulted    : {}", results.owner_defaulted);
+        }
+        if results.unprocessed > 0 {
+            println!(
+                "  Unprocessed        : {} (--max-runtime exceeded)",
+                results.unprocessed.to_string().yellow()
+            );
+        }
+        if results.updated + results.unchanged > 0 {
+            println!(
+                "  Updated            : {}",
+                results.updated.to_string().cyan()
+            );
+            println!("  Skipped (unchanged): {}", results.unchanged);
+        }
+        if results.existing > 0 {
+            println!("  Skipped (exists)   : {}", results.existing);
+        }
+        println!(
+            "  Retries            : {} ({:.1}s backoff)",
+            retries.retried_requests,
+            retries.backoff.as_secs_f64()
+        );
         if !results.errors.is_empty() {
             println!(
                 "  Errors             : {}",
@@ -198,17 +1409,33 @@ pub async fn run(args: CreateArgs, token: Option<String>) -> Result<()> {
         // JSON summary line.
         println!(
             "{}",
-            serde_json::to_string(&json!({
+            json_event(&json!({
                 "event": "summary",
+                "labels_created": results.labels_ok,
+                "iterations_created": results.iterations_ok,
                 "objectives_created": results.objectives_ok,
                 "epics_created": results.epics_ok,
                 "stories_created": results.stories_ok,
+                "updated": results.updated,
+                "skipped": results.unchanged,
+                "existing": results.existing,
+                "drafts": results.drafts,
+                "skipped_rows": results.skipped_rows,
+                "owner_defaulted": results.owner_defaulted,
+                "runtime_exceeded": results.unprocessed > 0,
+                "unprocessed": results.unprocessed,
                 "error_count": results.errors.len(),
                 "errors": results.errors,
-            }))?
+                "retried_requests": retries.retried_requests,
+                "retry_attempts": retries.retry_attempts,
+                "retry_backoff_seconds": retries.backoff.as_secs_f64(),
+            }))
         );
     }
 
+    if results.unprocessed > 0 {
+        std::process::exit(EXIT_RUNTIME);
+    }
     if !results.errors.is_empty() {
         std::process::exit(1);
     }
@@ -216,150 +1443,225 @@ pub async fn run(args: CreateArgs, token: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Write the run results in Prometheus textfile-collector format for
+/// `--metrics-file`.  Written to a sibling temp file and renamed into place
+/// so a scrape never sees a partial file.
+fn write_metrics(
+    path: &Path,
+    results: &RunResults,
+    client: &ShortcutClient,
+    elapsed: Duration,
+) -> Result<()> {
+    let mut out = String::new();
+    let mut metric = |name: &str, kind: &str, help: &str, samples: &[(&str, String)]| {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        for (labels, value) in samples {
+            let _ = writeln!(out, "{name}{labels} {value}");
+        }
+    };
+    metric(
+        "bypass_created_total",
+        "counter",
+        "Resources created in the last run.",
+        &[
+            ("{kind=\"label\"}", results.labels_ok.to_string()),
+            ("{kind=\"iteration\"}", results.iterations_ok.to_string()),
+            ("{kind=\"objective\"}", results.objectives_ok.to_string()),
+            ("{kind=\"epic\"}", results.epics_ok.to_string()),
+            ("{kind=\"story\"}", results.stories_ok.to_string()),
+        ],
+    );
+    metric(
+        "bypass_updated_total",
+        "counter",
+        "Existing resources overwritten by --dedupe-by-hash.",
+        &[("", results.updated.to_string())],
+    );
+    metric(
+        "bypass_skipped_total",
+        "counter",
+        "Existing resources left unchanged by --dedupe-by-hash.",
+        &[("", results.unchanged.to_string())],
+    );
+    metric(
+        "bypass_existing_total",
+        "counter",
+        "Resources skipped by --if-exists skip because the name was taken.",
+        &[("", results.existing.to_string())],
+    );
+    metric(
+        "bypass_errors_total",
+        "counter",
+        "Errors in the last run.",
+        &[("", results.errors.len().to_string())],
+    );
+    metric(
+        "bypass_retried_requests_total",
+        "counter",
+        "API requests that needed at least one retry.",
+        &[("", client.retry_stats().retried_requests.to_string())],
+    );
+    metric(
+        "bypass_duration_seconds",
+        "gauge",
+        "Wall-clock duration of the last run.",
+        &[("", format!("{:.3}", elapsed.as_secs_f64()))],
+    );
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    metric(
+        "bypass_last_run_timestamp_seconds",
+        "gauge",
+        "Unix time the last run finished.",
+        &[("", now.to_string())],
+    );
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    std::fs::write(&tmp, out)
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .map_err(|e| anyhow::anyhow!("Cannot write metrics '{}': {}", path.display(), e))
+}
+
 // ---------------------------------------------------------------------------
-// Dry-run validation
+// --if-empty guard
 // ---------------------------------------------------------------------------
 
-fn dry_run(
-    input: &crate::input::models::InputFile,
-    resolver: &Resolver,
-    global_template: Option<&Template>,
-    output: &OutputFormat,
-) -> Result<()> {
-    let mut errors: Vec<String> = Vec::new();
-
-    // Validate objectives.
-    for obj in &input.objectives {
-        if obj.name.is_empty() {
-            errors.push("Objective: 'name' is required".into());
-        }
-        if let Some(state) = &obj.state {
-            validate_objective_state(state, &obj.name, &mut errors);
-        }
+/// Fetch members, groups, and workflows (plus projects and epic labels when
+/// the input references them) for name resolution.
+async fn fetch_workspace(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    input: &InputFile,
+    epic_labels: &[&str],
+) -> Result<Resolver> {
+    if chatty(args) {
+        eprint!("Fetching workspace data (members, groups, workflows, iterations)…");
     }
 
-    // Build a synthetic objective name set for cross-reference validation.
-    let batch_objectives: std::collections::HashSet<&str> =
-        input.objectives.iter().map(|o| o.name.as_str()).collect();
-
-    // Validate epics.
-    for epic in &input.epics {
-        if epic.name.is_empty() {
-            errors.push("Epic: 'name' is required".into());
-            continue;
-        }
-        if let Some(state) = &epic.state {
-            validate_epic_state(state, &epic.name, &mut errors);
-        }
-        for owner in &epic.owners {
-            if !resolver.member_map.contains_key(owner.trim()) {
-                errors.push(format!(
-                    "Epic '{}': unknown user '{}'. Available: {}",
-                    epic.name,
-                    owner,
-                    list_sample(resolver.available_members())
-                ));
-            }
-        }
-        for team in &epic.teams {
-            if !resolver.group_map.contains_key(team.trim()) {
-                errors.push(format!(
-                    "Epic '{}': unknown team '{}'. Available: {}",
-                    epic.name,
-                    team,
-                    list_sample(resolver.available_groups())
-                ));
+    let mut resolver = Resolver::new(client, &args.workflows)
+        .await
+        .inspect_err(|_| {
+            if chatty(args) {
+                eprintln!();
             }
-        }
-        if let Some(obj) = &epic.objective
-            && obj.parse::<i64>().is_err()
-            && !batch_objectives.contains(obj.as_str())
-            && !resolver.objective_map.contains_key(obj.trim())
-        {
-            errors.push(format!(
-                "Epic '{}': objective '{obj}' not found in current batch \
-                     (use a numeric ID to reference a pre-existing objective)",
-                epic.name
-            ));
-        }
-        // Check per-epic template file exists.
-        if let Some(tmpl_path) = &epic.template
-            && !std::path::Path::new(tmpl_path).exists()
-        {
-            errors.push(format!(
-                "Epic '{}': template file '{tmpl_path}' not found",
-                epic.name
-            ));
-        }
+        })?;
+
+    if input.stories.iter().any(|s| s.project.is_some()) {
+        resolver.load_projects(client).await?;
+    }
+    if !epic_labels.is_empty() {
+        resolver.load_epic_labels(client, epic_labels).await?;
     }
 
-    let batch_epics: std::collections::HashSet<&str> =
-        input.epics.iter().map(|e| e.name.as_str()).collect();
+    if chatty(args) {
+        eprintln!("  {}", "done".green());
+    }
+    Ok(resolver)
+}
 
-    // Validate stories.
-    for story in &input.stories {
-        if story.name.is_empty() {
-            errors.push("Story: 'name' is required".into());
-            continue;
-        }
-        if let Some(t) = &story.story_type
-            && !["bug", "chore", "feature"].contains(&t.as_str())
-        {
-            errors.push(format!(
-                "Story '{}': invalid type '{t}'. Must be 'bug', 'chore', or 'feature'",
-                story.name
-            ));
-        }
-        for owner in &story.owners {
-            if !resolver.member_map.contains_key(owner.trim()) {
-                errors.push(format!(
-                    "Story '{}': unknown user '{}'. Available: {}",
-                    story.name,
-                    owner,
-                    list_sample(resolver.available_members())
-                ));
+/// Report whether the workspace has any resources of type `rt`.
+/// Returns `true` when creation should proceed.
+async fn check_if_empty(
+    client: &ShortcutClient,
+    rt: &ResourceType,
+    output: &OutputFormat,
+    quiet: bool,
+) -> Result<bool> {
+    let (kind, existing) = match rt {
+        ResourceType::Objective => ("objective", client.list_objectives().await?.len()),
+        ResourceType::Epic => ("epic", client.list_epics().await?.len()),
+        ResourceType::Story => ("story", client.list_stories().await?.len()),
+        ResourceType::Label => ("label", client.list_labels().await?.len()),
+    };
+    let proceed = existing == 0;
+
+    match output {
+        OutputFormat::Text if quiet => {}
+        OutputFormat::Text => {
+            if proceed {
+                println!("Workspace has no {kind} resources – proceeding (--if-empty)");
+            } else {
+                println!(
+                    "{} Workspace already has {existing} {kind} resource(s) – \
+                     skipping creation (--if-empty)",
+                    "↷".yellow()
+                );
             }
         }
-        if let Some(team) = &story.team
-            && !resolver.group_map.contains_key(team.trim())
-        {
-            errors.push(format!(
-                "Story '{}': unknown team '{}'. Available: {}",
-                story.name,
-                team,
-                list_sample(resolver.available_groups())
-            ));
-        }
-        if let Some(epic) = &story.epic
-            && epic.parse::<i64>().is_err()
-            && !batch_epics.contains(epic.as_str())
-            && !resolver.epic_map.contains_key(epic.trim())
-        {
-            errors.push(format!(
-                "Story '{}': epic '{epic}' not found in current batch \
-                     (use a numeric ID to reference a pre-existing epic)",
-                story.name
-            ));
-        }
-        if let Some(ws) = &story.workflow_state
-            && !resolver.workflow_state_map.contains_key(ws.trim())
-        {
-            errors.push(format!(
-                "Story '{}': unknown workflow state '{}'. Available: {}",
-                story.name,
-                ws,
-                list_sample(resolver.available_workflow_states())
-            ));
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                json_event(&json!({
+                    "event": "if_empty",
+                    "kind": kind,
+                    "existing": existing,
+                    "proceeded": proceed,
+                }))
+            );
         }
     }
+    Ok(proceed)
+}
+
+// ---------------------------------------------------------------------------
+// Dry-run validation
+// ---------------------------------------------------------------------------
 
-    // Check global template.
-    if let Some(_t) = global_template {
-        // Template was already loaded successfully, nothing to do.
+fn dry_run(
+    mut errors: Vec<String>,
+    input: &InputFile,
+    resolver: &Resolver,
+    policy: &Policy,
+    warnings: &[String],
+    output: &OutputFormat,
+    quiet: bool,
+) -> Result<()> {
+    let validator = Validator::new(input, resolver, policy, &mut errors);
+    validator.all(input, &mut errors);
+    let unresolved = preflight::Report::check(input, resolver);
+    if !unresolved.is_empty() {
+        match output {
+            OutputFormat::Text if quiet => {}
+            OutputFormat::Text => unresolved.print(),
+            OutputFormat::Json => println!("{}", json_event(&unresolved.to_json())),
+        }
     }
+    report_validation(&errors, warnings, &plan::plan(input), output, quiet)
+}
 
+/// Print dry-run validation results and the write plan; exits 1 when there
+/// are errors.  With `quiet`, text output is reduced to the errors, on
+/// stderr.
+fn report_validation(
+    errors: &[String],
+    warnings: &[String],
+    plan: &[PlannedWrite],
+    output: &OutputFormat,
+    quiet: bool,
+) -> Result<()> {
     match output {
+        OutputFormat::Text if quiet => {
+            for e in errors {
+                eprintln!("{} {e}", "✗".red());
+            }
+            if !errors.is_empty() {
+                std::process::exit(1);
+            }
+        }
         OutputFormat::Text => {
+            println!("Plan – {} write(s):", plan.len().to_string().cyan());
+            let width = plan.iter().map(|w| w.endpoint.len()).max().unwrap_or(0);
+            for (i, w) in plan.iter().enumerate() {
+                let endpoint = format!("{:<width$}", w.endpoint);
+                println!("  {:>3}. {}  {}", i + 1, endpoint.dimmed(), w.action);
+            }
+            for w in warnings {
+                println!("{} {w}", "⚠".yellow());
+            }
             if errors.is_empty() {
                 println!(
                     "{} All validations passed – no resources created (dry run).",
@@ -367,7 +1669,7 @@ fn dry_run(
                 );
             } else {
                 println!("{} {} validation error(s):", "✗".red(), errors.len());
-                for e in &errors {
+                for e in errors {
                     println!("  {} {e}", "•".red());
                 }
                 std::process::exit(1);
@@ -376,11 +1678,13 @@ fn dry_run(
         OutputFormat::Json => {
             println!(
                 "{}",
-                serde_json::to_string(&json!({
+                json_event(&json!({
                     "event": "dry_run",
                     "valid": errors.is_empty(),
                     "errors": errors,
-                }))?
+                    "warnings": warnings,
+                    "plan": plan,
+                }))
             );
             if !errors.is_empty() {
                 std::process::exit(1);
@@ -391,33 +1695,410 @@ fn dry_run(
     Ok(())
 }
 
-// ---------------------------------------------------------------------------
-// Resource builders
-// ---------------------------------------------------------------------------
-
-async fn build_and_create_objective(
-    client: &ShortcutClient,
-    input: &InputObjective,
-) -> Result<Objective> {
-    let req = CreateObjectiveRequest {
-        name: input.name.clone(),
-        description: input.description.clone(),
-        state: input.state.clone(),
-    };
-    client.create_objective(&req).await
+// ---------------------------------------------------------------------------
+// Resource builders
+// ---------------------------------------------------------------------------
+
+/// Create a label, unless `--if-exists` finds one of the same name.
+async fn build_and_create_label(
+    client: &ShortcutClient,
+    input: &InputLabel,
+    if_exists: IfExists,
+) -> Result<(Outcome, Label)> {
+    if let Some(existing) = existing_label(client, input, if_exists).await? {
+        return Ok((Outcome::Exists, existing));
+    }
+
+    let req = CreateLabelRequest {
+        name: input.name.trim().to_string(),
+        color: input.color.as_ref().map(|c| c.trim().to_string()),
+        description: input.description.clone(),
+    };
+    Ok((Outcome::Created, client.create_label(&req).await?))
+}
+
+/// Create an iteration, unless `--if-exists` finds one of the same name.
+async fn build_and_create_iteration(
+    client: &ShortcutClient,
+    input: &InputIteration,
+    resolver: &Resolver,
+    if_exists: IfExists,
+) -> Result<(Outcome, Iteration)> {
+    if let Some(existing) = existing_iteration(client, input, if_exists).await? {
+        return Ok((Outcome::Exists, existing));
+    }
+
+    let group_ids = if input.teams.is_empty() {
+        None
+    } else {
+        Some(resolver.resolve_groups(&input.teams)?)
+    };
+    let req = CreateIterationRequest {
+        name: input.name.clone(),
+        start_date: input.start_date.trim().to_string(),
+        end_date: input.end_date.trim().to_string(),
+        description: input.description.clone(),
+        group_ids,
+    };
+    Ok((Outcome::Created, client.create_iteration(&req).await?))
+}
+
+/// Create an objective, unless `--if-exists` finds one of the same name.
+async fn build_and_create_objective(
+    client: &ShortcutClient,
+    input: &InputObjective,
+    resolver: &Resolver,
+    template: Option<&Template>,
+    if_exists: IfExists,
+) -> Result<(Outcome, Objective)> {
+    if let Some(existing) = existing_objective(client, input, if_exists).await? {
+        return Ok((Outcome::Exists, existing));
+    }
+
+    let description = match template {
+        Some(t) => Some(t.render(input)),
+        None => input.description.clone(),
+    };
+
+    let categories = input
+        .categories
+        .iter()
+        .map(|c| {
+            resolver
+                .resolve_category(c)
+                .map(|name| CreateCategoryParams {
+                    name: name.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let req = CreateObjectiveRequest {
+        name: input.name.clone(),
+        description,
+        state: input.state.clone(),
+        categories,
+    };
+    Ok((Outcome::Created, client.create_objective(&req).await?))
+}
+
+/// Parse every `--file` (see [`input::glob::expand`]) as `--format` and
+/// merge them, in order, into one input.
+fn parse_input(args: &CreateArgs) -> Result<InputFile> {
+    let rt = args.r#type.as_ref();
+    let columns = column_options(args)?;
+    let mut merged = InputFile::default();
+    for path in input::glob::expand(&args.file)? {
+        merged.merge(match args.format {
+            InputFormat::Auto => input::parse_file(&path, rt, &columns)?,
+            InputFormat::Yaml => input::parse_file_as(&path, Some("yaml"), rt, &columns)?,
+            InputFormat::Json => input::parse_file_as(&path, Some("json"), rt, &columns)?,
+            InputFormat::Csv => input::parse_file_as(&path, Some("csv"), rt, &columns)?,
+            InputFormat::Markdown => input::parse_file_as(&path, Some("markdown"), rt, &columns)?,
+            InputFormat::Outline => input::parse_file_as(&path, Some("outline"), rt, &columns)?,
+            InputFormat::Github => input::github::parse(&path, &args.github_done_state)?,
+            InputFormat::Jira => input::jira::parse(&path)?,
+            InputFormat::Trello => input::trello::parse(&path)?,
+            InputFormat::Asana => input::asana::parse(&path)?,
+            InputFormat::Pivotal => input::pivotal::parse(&path)?,
+            InputFormat::Azdo => input::azdo::parse(&path)?,
+        });
+    }
+    Ok(merged)
+}
+
+/// Per-resource creation switches taken from [`CreateArgs`].
+struct CreateOptions {
+    link_references: bool,
+    dedupe: bool,
+    draft: bool,
+    if_exists: IfExists,
+    /// `--ac-prefix` for tasks created from acceptance criteria.
+    ac_prefix: String,
+}
+
+impl From<&CreateArgs> for CreateOptions {
+    fn from(args: &CreateArgs) -> Self {
+        Self {
+            link_references: args.link_references,
+            dedupe: args.dedupe_by_hash,
+            draft: args.draft,
+            if_exists: args.if_exists,
+            ac_prefix: args.ac_prefix.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Create an epic, or with `dedupe` look for an earlier import of it by
+/// name and skip or overwrite it depending on its `bypass-hash:` label.
+/// `--if-exists` handles a same-named epic instead.  With `draft`, newly
+/// created epics are labelled; the caller archives them (see
+/// [`archive_draft_epic`]).
+async fn build_and_create_epic(
+    client: &ShortcutClient,
+    input: &InputEpic,
+    resolver: &Resolver,
+    template: Option<&Template>,
+    opts: &CreateOptions,
+) -> Result<(Outcome, Epic)> {
+    let mut req = epic_request(input, resolver, template, opts.link_references)?;
+    if opts.draft {
+        push_label(&mut req.labels, DRAFT_LABEL);
+    }
+
+    let (outcome, epic) = if opts.dedupe {
+        let hash = dedupe::epic_hash_label(input);
+        push_label(&mut req.labels, &hash);
+        let existing = client.search_epics_by_name(&input.name).await?;
+        match existing.into_iter().find(|e| dedupe::is_hashed(&e.labels)) {
+            Some(e) if dedupe::has_hash(&e.labels, &hash) => (Outcome::Unchanged, e),
+            Some(e) => (Outcome::Updated, client.overwrite_epic(e.id, &req).await?),
+            None => (Outcome::Created, client.create_epic(&req).await?),
+        }
+    } else if let Some(e) = existing_epic(client, input, opts.if_exists).await? {
+        (Outcome::Exists, e)
+    } else {
+        (Outcome::Created, client.create_epic(&req).await?)
+    };
+
+    Ok((outcome, epic))
+}
+
+// ---------------------------------------------------------------------------
+// --if-exists
+// ---------------------------------------------------------------------------
+
+/// `--if-exists` for labels, iterations and objectives, which cannot carry
+/// a `bypass-hash:` label: under `--dedupe-by-hash` they are matched by
+/// name instead, as with `skip`, so a re-run reuses them.
+fn unhashed_if_exists(args: &CreateArgs) -> IfExists {
+    if args.dedupe_by_hash {
+        IfExists::Skip
+    } else {
+        args.if_exists
+    }
+}
+
+/// An objective named like `input`, under `--if-exists skip`; with
+/// `error` a match fails the row.  `None` when there is none or the mode
+/// is `create`.
+async fn existing_objective(
+    client: &ShortcutClient,
+    input: &InputObjective,
+    if_exists: IfExists,
+) -> Result<Option<Objective>> {
+    if if_exists == IfExists::Create {
+        return Ok(None);
+    }
+    let found = client.find_objectives_named(&input.name).await?;
+    taken(if_exists, found.into_iter().next(), |o| o.id)
+}
+
+/// As [`existing_objective`], for labels.
+async fn existing_label(
+    client: &ShortcutClient,
+    input: &InputLabel,
+    if_exists: IfExists,
+) -> Result<Option<Label>> {
+    if if_exists == IfExists::Create {
+        return Ok(None);
+    }
+    let found = client.find_labels_named(&input.name).await?;
+    taken(if_exists, found.into_iter().next(), |l| l.id)
+}
+
+/// As [`existing_objective`], for iterations.
+async fn existing_iteration(
+    client: &ShortcutClient,
+    input: &InputIteration,
+    if_exists: IfExists,
+) -> Result<Option<Iteration>> {
+    if if_exists == IfExists::Create {
+        return Ok(None);
+    }
+    let found = client.find_iterations_named(&input.name).await?;
+    taken(if_exists, found.into_iter().next(), |i| i.id)
+}
+
+/// As [`existing_objective`], for epics; names are matched workspace-wide.
+async fn existing_epic(
+    client: &ShortcutClient,
+    input: &InputEpic,
+    if_exists: IfExists,
+) -> Result<Option<Epic>> {
+    if if_exists == IfExists::Create {
+        return Ok(None);
+    }
+    let found = client.find_epics_named(&input.name).await?;
+    taken(if_exists, found.into_iter().next(), |e| e.id)
+}
+
+/// As [`existing_objective`], for stories in epic `epic_id` (`None`: stories
+/// without an epic), so same-named stories elsewhere are not duplicates.
+async fn existing_story(
+    client: &ShortcutClient,
+    input: &InputStory,
+    epic_id: Option<i64>,
+    if_exists: IfExists,
+) -> Result<Option<Story>> {
+    if if_exists == IfExists::Create {
+        return Ok(None);
+    }
+    let found = client.find_stories_named(&input.name).await?;
+    let found = found.into_iter().find(|s| s.epic_id == epic_id);
+    taken(if_exists, found, |s| s.id)
+}
+
+/// `--dry-run` with `--if-exists`: look up every resource by name.  Under
+/// `skip`, matches are reported, dropped from `input`, and registered in
+/// `resolver` as the real run would; under `error` they become errors.
+async fn dry_run_existing(
+    client: &ShortcutClient,
+    args: &CreateArgs,
+    input: &mut InputFile,
+    resolver: &mut Resolver,
+    errors: &mut Vec<String>,
+) -> Result<()> {
+    let skip = args.if_exists == IfExists::Skip;
+    let mut report = |item: &Item, id: i64| {
+        if !skip {
+            errors.push(format!("{}: already exists (#{id})", item.subject()));
+            return;
+        }
+        match args.output {
+            OutputFormat::Text if args.quiet => {}
+            OutputFormat::Text => println!(
+                "  {} {}: {}  (#{id}) would be skipped (exists)",
+                "↷".yellow(),
+                item.kind,
+                item.display_name()
+            ),
+            OutputFormat::Json => {
+                let mut event = json!({
+                    "event": "skipped",
+                    "kind": item.kind,
+                    "id": id,
+                    "name": item.name,
+                    "reason": "exists",
+                    "dry_run": true,
+                });
+                if let Some(row) = item.row {
+                    event["source_row"] = json!(row);
+                }
+                println!("{}", json_event(&event));
+            }
+        }
+    };
+
+    let mut found: HashSet<(&str, usize)> = HashSet::new();
+    for (i, label) in input.labels.iter().enumerate() {
+        if let Some(l) = existing_label(client, label, IfExists::Skip).await? {
+            report(&Item::label(label), l.id);
+            found.insert(("label", i));
+        }
+    }
+    for (i, iteration) in input.iterations.iter().enumerate() {
+        if let Some(it) = existing_iteration(client, iteration, IfExists::Skip).await? {
+            report(&Item::iteration(iteration), it.id);
+            found.insert(("iteration", i));
+            if skip {
+                resolver.register_iteration(iteration.name.trim().to_string(), it.id);
+            }
+        }
+    }
+    for (i, obj) in input.objectives.iter().enumerate() {
+        if let Some(o) = existing_objective(client, obj, IfExists::Skip).await? {
+            report(&Item::objective(obj), o.id);
+            found.insert(("objective", i));
+            if skip {
+                resolver.register_objective(obj.name.clone(), o.id, o.app_url);
+            }
+        }
+    }
+
+    // Existing epics by reference key, to scope the story lookups.
+    let mut epic_ids: HashMap<String, i64> = HashMap::new();
+    for (i, epic) in input.epics.iter().enumerate() {
+        if let Some(e) = existing_epic(client, epic, IfExists::Skip).await? {
+            report(&Item::epic(epic), e.id);
+            found.insert(("epic", i));
+            epic_ids.insert(epic.name.clone(), e.id);
+            if let Some(obj) = &epic.objective {
+                epic_ids.insert(qualified_epic_key(obj, &epic.name), e.id);
+            }
+            if skip {
+                resolver.register_epic(
+                    epic.name.clone(),
+                    epic.objective.as_deref(),
+                    e.id,
+                    e.app_url,
+                );
+                resolver.register_epic_labels(&epic.labels, e.id);
+            }
+        }
+    }
+
+    for (i, story) in input.stories.iter().enumerate() {
+        let epic_id = match &story.epic {
+            None => None,
+            Some(reference) => match resolver
+                .resolve_epic(reference)
+                .ok()
+                .or_else(|| epic_ids.get(&epic_key(reference)).copied())
+            {
+                Some(id) => Some(id),
+                // A new epic: the story cannot exist in it yet.
+                None => continue,
+            },
+        };
+        if let Some(s) = existing_story(client, story, epic_id, IfExists::Skip).await? {
+            report(&Item::story(story), s.id);
+            found.insert(("story", i));
+        }
+    }
+
+    if skip {
+        fn keep<T>(items: &mut Vec<T>, kind: &str, found: &HashSet<(&str, usize)>) {
+            let mut i = 0;
+            items.retain(|_| {
+                i += 1;
+                !found.contains(&(kind, i - 1))
+            });
+        }
+        keep(&mut input.labels, "label", &found);
+        keep(&mut input.iterations, "iteration", &found);
+        keep(&mut input.objectives, "objective", &found);
+        keep(&mut input.epics, "epic", &found);
+        keep(&mut input.stories, "story", &found);
+    }
+    Ok(())
+}
+
+/// Apply `--if-exists error` to a lookup result.
+fn taken<T>(if_exists: IfExists, found: Option<T>, id: impl Fn(&T) -> i64) -> Result<Option<T>> {
+    match found {
+        Some(existing) if if_exists == IfExists::Error => {
+            bail!("already exists (#{})", id(&existing))
+        }
+        found => Ok(found),
+    }
 }
 
-async fn build_and_create_epic(
-    client: &ShortcutClient,
+fn epic_request(
     input: &InputEpic,
     resolver: &Resolver,
     template: Option<&Template>,
-) -> Result<Epic> {
+    link_references: bool,
+) -> Result<CreateEpicRequest> {
     let owner_ids = if input.owners.is_empty() {
         None
     } else {
         Some(resolver.resolve_members(&input.owners)?)
     };
+    let follower_ids = if input.followers.is_empty() {
+        None
+    } else {
+        Some(resolver.resolve_members(&input.followers)?)
+    };
 
     let group_ids = if input.teams.is_empty() {
         None
@@ -431,12 +2112,24 @@ async fn build_and_create_epic(
         .map(|name| Ok::<_, anyhow::Error>(vec![resolver.resolve_objective(name)?]))
         .transpose()?;
 
-    let labels = labels_param(&input.labels);
+    let mut labels = labels_param(&input.labels);
+    if let Some(capacity) = input.capacity {
+        push_label(&mut labels, &format!("{CAPACITY_PREFIX}{capacity}"));
+    }
 
-    let description = match template {
+    let mut description = match template {
         Some(t) => Some(t.render(input)),
         None => input.description.clone(),
     };
+    if link_references {
+        let objective = input.objective.as_deref();
+        let url = objective.and_then(|o| resolver.objective_url_map.get(o.trim()));
+        description = description
+            .map(|d| link_reference(&d, "objective", objective, url.map(String::as_str)));
+    }
+    if !input.links.is_empty() {
+        description = Some(with_links(description.unwrap_or_default(), &input.links));
+    }
 
     let req = CreateEpicRequest {
         name: input.name.clone(),
@@ -444,24 +2137,72 @@ async fn build_and_create_epic(
         state: input.state.clone(),
         objective_ids,
         owner_ids,
+        follower_ids,
         group_ids,
         labels,
-        planned_start_date: input.start_date.clone(),
-        deadline: input.deadline.clone(),
+        planned_start_date: patch(&input.start_date),
+        deadline: patch(&input.deadline),
     };
-    client.create_epic(&req).await
+    Ok(req)
 }
 
+/// Create a story; options as for [`build_and_create_epic`].  For
+/// `--if-exists`, only stories in the same epic (or in none) count.
 async fn build_and_create_story(
     client: &ShortcutClient,
     input: &InputStory,
     resolver: &Resolver,
-) -> Result<Story> {
+    template: Option<&Template>,
+    opts: &CreateOptions,
+) -> Result<(Outcome, Story)> {
+    let mut req = story_request(input, resolver, template, opts)?;
+    if opts.draft {
+        push_label(&mut req.labels, DRAFT_LABEL);
+    }
+
+    let (outcome, story) = if opts.dedupe {
+        let hash = dedupe::story_hash_label(input);
+        push_label(&mut req.labels, &hash);
+        let existing = client.search_stories_by_name(&input.name).await?;
+        match existing.into_iter().find(|s| dedupe::is_hashed(&s.labels)) {
+            Some(s) if dedupe::has_hash(&s.labels, &hash) => (Outcome::Unchanged, s),
+            Some(s) => {
+                // Tasks cannot be replaced through an update.
+                req.tasks.clear();
+                (Outcome::Updated, client.overwrite_story(s.id, &req).await?)
+            }
+            None => (Outcome::Created, client.create_story(&req).await?),
+        }
+    } else if let Some(s) = existing_story(client, input, req.epic_id, opts.if_exists).await? {
+        (Outcome::Exists, s)
+    } else {
+        (Outcome::Created, client.create_story(&req).await?)
+    };
+
+    Ok((outcome, story))
+}
+
+fn story_request(
+    input: &InputStory,
+    resolver: &Resolver,
+    template: Option<&Template>,
+    opts: &CreateOptions,
+) -> Result<CreateStoryRequest> {
     let owner_ids = if input.owners.is_empty() {
         None
     } else {
         Some(resolver.resolve_members(&input.owners)?)
     };
+    let follower_ids = if input.followers.is_empty() {
+        None
+    } else {
+        Some(resolver.resolve_members(&input.followers)?)
+    };
+    let requested_by_id = input
+        .requested_by
+        .as_ref()
+        .map(|r| resolver.resolve_member(r))
+        .transpose()?;
 
     let group_id = input
         .team
@@ -480,28 +2221,344 @@ async fn build_and_create_story(
         None => resolver.default_workflow_state_id,
     };
 
+    let project_id = input
+        .project
+        .as_ref()
+        .map(|p| resolver.resolve_project(p))
+        .transpose()?;
+
+    let iteration_id = input
+        .iteration
+        .as_ref()
+        .map(|i| resolver.resolve_iteration(i))
+        .transpose()?;
+
+    let custom_fields = input
+        .custom_fields
+        .iter()
+        .map(|(field, value)| resolver.resolve_custom_field(field, value))
+        .collect::<Result<_>>()?;
+
     let labels = labels_param(&input.labels);
 
+    let mut description = match template {
+        Some(t) => Some(t.render(input)),
+        None => input.description.clone(),
+    };
+    if opts.link_references {
+        let epic = input.epic.as_deref();
+        let url = epic.and_then(|e| resolver.epic_url_map.get(&epic_key(e)));
+        description =
+            description.map(|d| link_reference(&d, "epic", epic, url.map(String::as_str)));
+    }
+
     let req = CreateStoryRequest {
         name: input.name.clone(),
         story_type: input.story_type.clone(),
-        description: input.description.clone(),
+        description,
         owner_ids,
+        follower_ids,
+        requested_by_id,
         group_id,
         epic_id,
         workflow_state_id,
         labels,
         estimate: input.estimate,
-        deadline: input.due_date.clone(),
+        deadline: patch(&input.due_date),
+        project_id,
+        iteration_id,
+        tasks: acceptance_tasks(input, &opts.ac_prefix),
+        custom_fields,
+    };
+    Ok(req)
+}
+
+/// Append a markdown list of `links` to an epic description; epics cannot
+/// carry linked files.
+fn with_links(mut description: String, links: &[InputLink]) -> String {
+    if !description.is_empty() {
+        description.push_str("\n\n");
+    }
+    description.push_str("**Links**\n");
+    for link in links {
+        let _ = writeln!(description, "- [{}]({})", link.name(), link.url);
+    }
+    description
+}
+
+/// Attach a created story's `links` as linked files.  A failed link is
+/// reported as an error against the story, which stays created.
+async fn attach_links(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    story: &InputStory,
+    id: i64,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    for link in &story.links {
+        let req = CreateLinkedFileRequest {
+            name: link.name().to_string(),
+            url: link.url.clone(),
+            file_type: "url",
+            story_id: id,
+        };
+        if let Err(e) = client.create_linked_file(&req).await {
+            let msg = format!("link '{}': {e}", link.url);
+            let item = Item::story(story);
+            emit_err(output, &item, &msg, pb);
+            errors.push(format!("{}: {msg}", item.subject()));
+        }
+    }
+}
+
+/// Add a created story's `tasks`, after those from `acceptance_criteria`.
+/// A failed task, or one whose owner does not resolve, is reported as an
+/// error against the story, which stays created.
+async fn add_tasks(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    story: &InputStory,
+    id: i64,
+    resolver: &Resolver,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    for task in &story.tasks {
+        let result = async {
+            let owner_ids = task
+                .owner
+                .as_deref()
+                .map(|o| resolver.resolve_member(o))
+                .transpose()?
+                .map(|id| vec![id]);
+            let req = CreateTaskRequest {
+                description: task.name.clone(),
+                owner_ids,
+                complete: task.complete,
+            };
+            client.create_task(id, &req).await
+        };
+        if let Err(e) = result.await {
+            let msg = format!("task '{}': {e}", task.name);
+            let item = Item::story(story);
+            emit_err(output, &item, &msg, pb);
+            errors.push(format!("{}: {msg}", item.subject()));
+        }
+    }
+}
+
+/// Post a created story's `comments`, in order.  A failed comment, or one
+/// whose author does not resolve, is reported as an error against the
+/// story, which stays created.
+async fn add_comments(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    story: &InputStory,
+    id: i64,
+    resolver: &Resolver,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    for (i, comment) in story.comments.iter().enumerate() {
+        let result = async {
+            let author_id = comment
+                .author
+                .as_deref()
+                .map(|a| resolver.resolve_member(a))
+                .transpose()?;
+            let req = CreateCommentRequest {
+                text: comment.text.clone(),
+                author_id,
+            };
+            client.create_comment(id, &req).await
+        };
+        if let Err(e) = result.await {
+            let msg = format!("comment {}: {e}", i + 1);
+            let item = Item::story(story);
+            emit_err(output, &item, &msg, pb);
+            errors.push(format!("{}: {msg}", item.subject()));
+        }
+    }
+}
+
+/// Post a created story's `story_links` once every story of the run
+/// exists.  A target that does not resolve, or a failed link, is reported
+/// as an error against the story.
+async fn link_stories(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    story: &InputStory,
+    id: i64,
+    resolver: &Resolver,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    for (verb, target) in story.story_links.iter() {
+        let result = async {
+            let req = CreateStoryLinkRequest {
+                subject_id: id,
+                object_id: resolver.resolve_story(target)?,
+                verb,
+            };
+            client.create_story_link(&req).await
+        }
+        .await;
+        if let Err(e) = result {
+            let msg = format!("link '{verb} {target}': {e}");
+            let item = Item::story(story);
+            emit_err(output, &item, &msg, pb);
+            errors.push(format!("{}: {msg}", item.subject()));
+        }
+    }
+}
+
+/// Post an epic's `health` as a health update; a failure is reported as an
+/// error for the epic, which stays created.
+async fn set_health(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    epic: &InputEpic,
+    id: i64,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    let Some(status) = epic.health.as_deref().and_then(health_status) else {
+        return;
+    };
+    if let Err(e) = client
+        .create_epic_health(id, &CreateEpicHealthRequest { status })
+        .await
+    {
+        let msg = format!("health '{status}': {e}");
+        let item = Item::epic(epic);
+        emit_err(output, &item, &msg, pb);
+        errors.push(format!("{}: {msg}", item.subject()));
+    }
+}
+
+/// Archive an epic just created under `--draft`.  A failure is reported
+/// against the epic, which exists either way.
+async fn archive_draft_epic(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    epic: &InputEpic,
+    id: i64,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    let archive = UpdateEpicRequest {
+        archived: Some(true),
+        ..Default::default()
+    };
+    if let Err(e) = client.update_epic(id, &archive).await {
+        let msg = format!("archiving draft: {e}");
+        let item = Item::epic(epic);
+        emit_err(output, &item, &msg, pb);
+        errors.push(format!("{}: {msg}", item.subject()));
+    }
+}
+
+/// As [`archive_draft_epic`], for stories.
+async fn archive_draft_story(
+    client: &ShortcutClient,
+    output: &OutputFormat,
+    story: &InputStory,
+    id: i64,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    let archive = UpdateStoryRequest {
+        archived: Some(true),
+        ..Default::default()
+    };
+    if let Err(e) = client.update_story(id, &archive).await {
+        let msg = format!("archiving draft: {e}");
+        let item = Item::story(story);
+        emit_err(output, &item, &msg, pb);
+        errors.push(format!("{}: {msg}", item.subject()));
+    }
+}
+
+/// End date of each iteration declared in the input, by trimmed name.
+fn batch_iteration_ends(iterations: &[InputIteration]) -> HashMap<&str, &str> {
+    iterations
+        .iter()
+        .map(|i| (i.name.trim(), i.end_date.trim()))
+        .collect()
+}
+
+/// `--due-from-iteration`: give `story` its iteration's end date when it
+/// has no `due_date`, preferring an iteration from `batch` (see
+/// [`batch_iteration_ends`]) over an existing one.  Returns whether it did.
+fn due_from_iteration(
+    story: &mut InputStory,
+    batch: &HashMap<&str, &str>,
+    resolver: &Resolver,
+) -> bool {
+    if story.due_date.is_some() {
+        return false;
+    }
+    let Some(iteration) = story.iteration.as_deref().map(str::trim) else {
+        return false;
     };
-    client.create_story(&req).await
+    let end = batch
+        .get(iteration)
+        .copied()
+        .or_else(|| resolver.iteration_end_date(iteration));
+    // The API may give a full timestamp; the date part is what is meant.
+    story.due_date = end.map(|d| d.split('T').next().unwrap_or(d).to_string());
+    story.due_date.is_some()
+}
+
+/// A created story as `--echo-created` writes it: the input values (with
+/// presets already expanded) plus its Shortcut ID.
+fn echo_story(story: &InputStory, id: i64) -> InputStory {
+    InputStory {
+        id: Some(id),
+        preset: Vec::new(),
+        ..story.clone()
+    }
+}
+
+/// One task per non-empty line of the story's `acceptance_criteria`.
+fn acceptance_tasks(input: &InputStory, prefix: &str) -> Vec<CreateTaskParams> {
+    input
+        .acceptance_criteria
+        .iter()
+        .flat_map(|ac| ac.lines())
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| CreateTaskParams {
+            description: format!("{prefix}{line}"),
+        })
+        .collect()
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
-fn labels_param(names: &[String]) -> Option<Vec<CreateLabelParams>> {
+/// An optional input field as a request field: absent leaves it unchanged,
+/// [`CLEAR`] sends an explicit null.
+pub(super) fn patch(value: &Option<String>) -> Patch<String> {
+    match value.as_deref().map(str::trim) {
+        None => Patch::Unchanged,
+        Some(CLEAR) => Patch::Clear,
+        Some(v) => Patch::Set(v.to_string()),
+    }
+}
+
+/// Label prefix recording an epic's `capacity`.
+pub(super) const CAPACITY_PREFIX: &str = "capacity:";
+
+fn push_label(labels: &mut Option<Vec<CreateLabelParams>>, name: &str) {
+    labels.get_or_insert_with(Vec::new).push(CreateLabelParams {
+        name: name.to_string(),
+    });
+}
+
+pub(super) fn labels_param(names: &[String]) -> Option<Vec<CreateLabelParams>> {
     if names.is_empty() {
         None
     } else {
@@ -514,32 +2571,50 @@ fn labels_param(names: &[String]) -> Option<Vec<CreateLabelParams>> {
     }
 }
 
-fn validate_objective_state(state: &str, name: &str, errors: &mut Vec<String>) {
-    if !["in progress", "to do", "done"].contains(&state) {
-        errors.push(format!(
-            "Objective '{name}': invalid state '{state}'. \
-             Must be 'in progress', 'to do', or 'done'"
-        ));
-    }
+/// One progress bar per resource type in the batch, drawn together so
+/// later types show as waiting while earlier ones run.  Lines printed
+/// through any bar appear above all of them.  The bars are cleared when
+/// this is dropped, including on an early return.
+///
+/// Hidden under `--quiet` and when stderr is not a terminal; output then
+/// falls back to plain lines as with a single hidden bar.
+struct Bars {
+    multi: MultiProgress,
+    labels: ProgressBar,
+    iterations: ProgressBar,
+    objectives: ProgressBar,
+    epics: ProgressBar,
+    stories: ProgressBar,
 }
 
-fn validate_epic_state(state: &str, name: &str, errors: &mut Vec<String>) {
-    if !["in progress", "to do", "done"].contains(&state) {
-        errors.push(format!(
-            "Epic '{name}': invalid state '{state}'. \
-             Must be 'in progress', 'to do', or 'done'"
-        ));
+impl Bars {
+    fn new(input: &InputFile, quiet: bool) -> Self {
+        let multi = MultiProgress::new();
+        if quiet {
+            multi.set_draw_target(ProgressDrawTarget::hidden());
+        }
+        let add = |len: usize, label: &str| {
+            if len == 0 {
+                return ProgressBar::hidden();
+            }
+            let pb = multi.add(make_pb(len as u64, label));
+            pb.set_message("waiting");
+            pb
+        };
+        Self {
+            labels: add(input.labels.len(), "labels"),
+            iterations: add(input.iterations.len(), "iterations"),
+            objectives: add(input.objectives.len(), "objectives"),
+            epics: add(input.epics.len(), "epics"),
+            stories: add(input.stories.len(), "stories"),
+            multi,
+        }
     }
 }
 
-fn list_sample(mut items: Vec<&str>) -> String {
-    items.sort_unstable();
-    items.dedup();
-    let preview: Vec<&str> = items.iter().copied().take(5).collect();
-    if items.len() > 5 {
-        format!("{} … (+{})", preview.join(", "), items.len() - 5)
-    } else {
-        preview.join(", ")
+impl Drop for Bars {
+    fn drop(&mut self) {
+        let _ = self.multi.clear();
     }
 }
 
@@ -557,6 +2632,8 @@ fn make_pb(len: u64, label: &str) -> ProgressBar {
     pb
 }
 
+/// Printed through `pb`, so nothing is shown when the bar is hidden
+/// (`--quiet`).
 fn emit_ok(
     output: &OutputFormat,
     kind: &str,
@@ -574,33 +2651,195 @@ fn emit_ok(
             ));
         }
         OutputFormat::Json => {
-            let line = serde_json::to_string(&json!({
+            let line = json_event(&json!({
                 "event": "created",
                 "kind": kind,
                 "id": id,
                 "name": name,
                 "url": url,
-            }))
-            .unwrap_or_default();
-            pb.println(line);
+            }));
+            if pb.is_hidden() {
+                println!("{line}");
+            } else {
+                pb.println(line);
+            }
         }
     }
 }
 
-fn emit_err(output: &OutputFormat, kind: &str, name: &str, error: &str, pb: &ProgressBar) {
+/// Stop the run on an authentication failure: every later request would
+/// fail the same way.
+fn abort_on_auth(e: &anyhow::Error, pb: &ProgressBar) -> Result<()> {
+    if let Some(&BypassError::Auth { status }) = e.downcast_ref::<BypassError>() {
+        pb.finish_and_clear();
+        return Err(BypassError::Auth { status }.into());
+    }
+    Ok(())
+}
+
+/// Record `--verify` mismatches (or a failed read-back) as errors.
+fn report_verify(
+    output: &OutputFormat,
+    item: &Item,
+    id: i64,
+    check: Result<Vec<String>>,
+    errors: &mut Vec<String>,
+    pb: &ProgressBar,
+) {
+    let problems = match check {
+        Ok(mismatches) => mismatches,
+        Err(e) => vec![format!("read-back failed: {e}")],
+    };
+    for problem in problems {
+        let msg = format!("verify #{id}: {problem}");
+        emit_err(output, item, &msg, pb);
+        errors.push(format!("{}: {msg}", item.subject()));
+    }
+}
+
+/// [`emit_ok`] for created resources; `--dedupe-by-hash` updates and skips
+/// are reported as `updated` / `skipped` events.
+fn emit_outcome(
+    output: &OutputFormat,
+    outcome: Outcome,
+    kind: &str,
+    name: &str,
+    id: i64,
+    url: Option<&str>,
+    pb: &ProgressBar,
+) {
+    let (symbol, event) = match outcome {
+        Outcome::Created => return emit_ok(output, kind, name, id, url, pb),
+        Outcome::Updated => ("↻".cyan(), "updated"),
+        Outcome::Unchanged | Outcome::Exists => ("↷".yellow(), "skipped"),
+    };
+    let exists = outcome == Outcome::Exists;
     match output {
         OutputFormat::Text => {
-            pb.println(format!("  {} {kind}: {name}\n    {error}", "✗".red()));
+            let reason = if exists { " (exists)" } else { "" };
+            pb.println(format!(
+                "  {symbol} {kind}: {name}  (#{id}) {event}{reason}"
+            ));
         }
         OutputFormat::Json => {
-            let line = serde_json::to_string(&json!({
-                "event": "error",
+            let mut event = json!({
+                "event": event,
                 "kind": kind,
+                "id": id,
                 "name": name,
+                "url": url,
+            });
+            if exists {
+                event["reason"] = json!("exists");
+            }
+            let line = json_event(&event);
+            if pb.is_hidden() {
+                println!("{line}");
+            } else {
+                pb.println(line);
+            }
+        }
+    }
+}
+
+/// The input resource an error is about.
+struct Item<'a> {
+    kind: &'static str,
+    name: &'a str,
+    /// Source row, when `--source-rows` is given.
+    row: Option<usize>,
+}
+
+impl<'a> Item<'a> {
+    fn label(label: &'a InputLabel) -> Self {
+        Self {
+            kind: "label",
+            name: &label.name,
+            row: label.source_row,
+        }
+    }
+
+    fn iteration(iteration: &'a InputIteration) -> Self {
+        Self {
+            kind: "iteration",
+            name: &iteration.name,
+            row: iteration.source_row,
+        }
+    }
+
+    fn objective(obj: &'a InputObjective) -> Self {
+        Self {
+            kind: "objective",
+            name: &obj.name,
+            row: obj.source_row,
+        }
+    }
+
+    fn epic(epic: &'a InputEpic) -> Self {
+        Self {
+            kind: "epic",
+            name: &epic.name,
+            row: epic.source_row,
+        }
+    }
+
+    fn story(story: &'a InputStory) -> Self {
+        Self {
+            kind: "story",
+            name: &story.name,
+            row: story.source_row,
+        }
+    }
+
+    /// `name`, followed by ` (row N)` when the row is known.
+    fn display_name(&self) -> String {
+        match self.row {
+            Some(row) => format!("{} (row {row})", self.name),
+            None => self.name.to_string(),
+        }
+    }
+
+    /// The prefix of a summary error line, e.g. `Story 'X' (row 42)`.
+    fn subject(&self) -> String {
+        let kind = capitalized(self.kind);
+        match self.row {
+            Some(row) => format!("{kind} '{}' (row {row})", self.name),
+            None => format!("{kind} '{}'", self.name),
+        }
+    }
+}
+
+/// Errors are always shown: a hidden bar drops `println`, so they bypass it
+/// (text to stderr).
+fn emit_err(output: &OutputFormat, item: &Item, error: &str, pb: &ProgressBar) {
+    let kind = item.kind;
+    match output {
+        OutputFormat::Text if pb.is_hidden() => {
+            eprintln!("{} {kind}: {}: {error}", "✗".red(), item.display_name());
+        }
+        OutputFormat::Text => {
+            pb.println(format!(
+                "  {} {kind}: {}\n    {error}",
+                "✗".red(),
+                item.display_name()
+            ));
+        }
+        OutputFormat::Json => {
+            let mut event = json!({
+                "event": "error",
+                "kind": kind,
+                "name": item.name,
                 "error": error,
-            }))
-            .unwrap_or_default();
-            pb.println(line);
+            });
+            if let Some(row) = item.row {
+                event["source_row"] = json!(row);
+            }
+            let line = json_event(&event);
+            if pb.is_hidden() {
+                println!("{line}");
+            } else {
+                pb.println(line);
+            }
         }
     }
 }
@@ -611,8 +2850,109 @@ fn emit_err(output: &OutputFormat, kind: &str, name: &str, error: &str, pb: &Pro
 
 #[derive(Default)]
 struct RunResults {
+    labels_ok: usize,
+    iterations_ok: usize,
     objectives_ok: usize,
     epics_ok: usize,
     stories_ok: usize,
+    /// `--dedupe-by-hash`: resources overwritten / left unchanged.
+    updated: usize,
+    unchanged: usize,
+    /// `--draft`: epics and stories created archived.
+    drafts: usize,
+    /// Input rows left out as skipped or commented out.
+    skipped_rows: usize,
+    /// `--if-exists skip`: resources left alone because the name was taken.
+    existing: usize,
+    /// Items not started because `--max-runtime` ran out.
+    unprocessed: usize,
+    /// `--default-self-owner`: epics and stories given the token's user as
+    /// owner.
+    owner_defaulted: usize,
     errors: Vec<String>,
 }
+
+impl RunResults {
+    /// Tally an outcome; `created` selects the per-kind created counter.
+    fn count(&mut self, outcome: Outcome, created: impl FnOnce(&mut Self) -> &mut usize) {
+        match outcome {
+            Outcome::Created => *created(self) += 1,
+            Outcome::Updated => self.updated += 1,
+            Outcome::Unchanged => self.unchanged += 1,
+            Outcome::Exists => self.existing += 1,
+        }
+    }
+
+    /// Fold in one item of a phase, returning the resource if it exists.
+    fn fold<T>(
+        &mut self,
+        done: Done<T>,
+        created: impl FnOnce(&mut Self) -> &mut usize,
+    ) -> Option<(Outcome, T)> {
+        match done {
+            Done::Unprocessed => {
+                self.unprocessed += 1;
+                None
+            }
+            Done::Failed(error) => {
+                self.errors.push(error);
+                None
+            }
+            Done::Ok {
+                outcome,
+                resource,
+                errors,
+            } => {
+                self.count(outcome, created);
+                self.errors.extend(errors);
+                Some((outcome, resource))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patch_from_input_value() {
+        assert_eq!(patch(&None), Patch::Unchanged);
+        assert_eq!(patch(&Some(" __clear__ ".into())), Patch::Clear);
+        assert_eq!(
+            patch(&Some("2026-12-01".into())),
+            Patch::Set("2026-12-01".into())
+        );
+    }
+
+    #[test]
+    fn due_from_iteration_fills_only_empty_dates() {
+        let fixture: Fixture = serde_json::from_value(serde_json::json!({
+            "iterations": [
+                {"id": 7, "name": "Sprint 12", "end_date": "2024-07-14T00:00:00Z"}
+            ]
+        }))
+        .unwrap();
+        let resolver = Resolver::from_fixture(&fixture, &[], &[]).unwrap();
+        let batch = HashMap::from([("Sprint 13", "2024-07-28")]);
+
+        let mut story = InputStory::named("a", 1);
+        story.iteration = Some(" Sprint 13 ".into());
+        assert!(due_from_iteration(&mut story, &batch, &resolver));
+        assert_eq!(story.due_date.as_deref(), Some("2024-07-28"));
+
+        let mut story = InputStory::named("b", 2);
+        story.iteration = Some("7".into());
+        assert!(due_from_iteration(&mut story, &batch, &resolver));
+        assert_eq!(story.due_date.as_deref(), Some("2024-07-14"));
+
+        story.due_date = Some("2024-07-01".into());
+        assert!(!due_from_iteration(&mut story, &batch, &resolver));
+        assert_eq!(story.due_date.as_deref(), Some("2024-07-01"));
+
+        let mut story = InputStory::named("c", 3);
+        story.iteration = Some("Sprint 99".into());
+        assert!(!due_from_iteration(&mut story, &batch, &resolver));
+        assert_eq!(story.due_date, None);
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::json;
+
+use super::{capitalized, confirm, json_event};
+use crate::api::models::SearchStoriesRequest;
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{DeleteArgs, OutputFormat, ResourceType};
+use crate::config::Config;
+use crate::error::BypassError;
+use crate::input::{self, ColumnOptions};
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Delete the resources selected by `--file`, `--label` and `--epic`.
+/// Everything is looked up and previewed first; deletion only starts once
+/// the whole selection is known.
+pub async fn run(args: DeleteArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+
+    let (targets, gone) = select(&client, &args).await?;
+    preview(&args, &targets, &gone);
+
+    if targets.is_empty() || args.dry_run.enabled {
+        return Ok(());
+    }
+
+    if !args.yes
+        && !confirm(&format!(
+            "Delete {} resource{}? This cannot be undone.",
+            targets.len(),
+            if targets.len() == 1 { "" } else { "s" }
+        ))?
+    {
+        eprintln!("{}", "Aborted – nothing was deleted.".yellow());
+        return Ok(());
+    }
+
+    let mut deleted = Tally::default();
+    let mut errors: Vec<String> = Vec::new();
+    for target in &targets {
+        let result = match target.kind {
+            "objective" => client.delete_objective(target.id).await,
+            "epic" => client.delete_epic(target.id).await,
+            _ => client.delete_story(target.id).await,
+        };
+        match result {
+            Ok(()) => {
+                deleted.count(target.kind);
+                emit(&args.output, target, None);
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(&args.output, target, Some(&msg));
+                errors.push(format!(
+                    "{} '{}' (#{}): {msg}",
+                    capitalized(target.kind),
+                    target.name,
+                    target.id
+                ));
+            }
+        }
+    }
+
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            println!(
+                "  Stories deleted    : {}",
+                deleted.stories.to_string().green()
+            );
+            println!(
+                "  Epics deleted      : {}",
+                deleted.epics.to_string().green()
+            );
+            if deleted.objectives > 0 {
+                println!(
+                    "  Objectives deleted : {}",
+                    deleted.objectives.to_string().green()
+                );
+            }
+            if !errors.is_empty() {
+                println!("  Errors             : {}", errors.len().to_string().red());
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "stories_deleted": deleted.stories,
+                "epics_deleted": deleted.epics,
+                "objectives_deleted": deleted.objectives,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Selection
+// ---------------------------------------------------------------------------
+
+/// One resource to delete.
+struct Target {
+    /// "objective", "epic" or "story".
+    kind: &'static str,
+    id: i64,
+    name: String,
+}
+
+/// Every selected resource, once each: stories first, then epics, then
+/// objectives.  IDs given in `--file` or `--epic` that no longer exist
+/// are returned apart, so a re-run after a partial delete carries on.
+async fn select(
+    client: &ShortcutClient,
+    args: &DeleteArgs,
+) -> Result<(Vec<Target>, Vec<(&'static str, i64)>)> {
+    let mut targets: Vec<Target> = Vec::new();
+    let mut gone: Vec<(&'static str, i64)> = Vec::new();
+
+    if let Some(path) = &args.file {
+        for (kind, id) in listed_ids(path, args.r#type.as_ref())? {
+            // Read first, so a bad ID fails before anything is deleted.
+            let name = match kind {
+                "objective" => client.get_objective(id).await.map(|o| o.name),
+                "epic" => client.get_epic(id).await.map(|e| e.name),
+                _ => client.get_story(id).await.map(|s| s.name),
+            };
+            match name {
+                Ok(name) => targets.push(Target { kind, id, name }),
+                Err(e) if not_found(&e) => gone.push((kind, id)),
+                Err(e) => bail!("{} #{id}: {e}", capitalized(kind)),
+            }
+        }
+    }
+
+    if let Some(label) = &args.label {
+        let stories = client
+            .search_stories(&SearchStoriesRequest {
+                label_name: Some(label.clone()),
+                ..Default::default()
+            })
+            .await?;
+        targets.extend(stories.into_iter().map(|s| Target {
+            kind: "story",
+            id: s.id,
+            name: s.name,
+        }));
+        let epics = client.list_epics().await?;
+        targets.extend(
+            epics
+                .into_iter()
+                .filter(|e| e.labels.iter().any(|l| &l.name == label))
+                .map(|e| Target {
+                    kind: "epic",
+                    id: e.id,
+                    name: e.name,
+                }),
+        );
+    }
+
+    for &id in &args.epics {
+        let epic = match client.get_epic(id).await {
+            Ok(epic) => epic,
+            Err(e) if not_found(&e) => {
+                gone.push(("epic", id));
+                continue;
+            }
+            Err(e) => bail!("Epic #{id}: {e}"),
+        };
+        let stories = client
+            .search_stories(&SearchStoriesRequest {
+                epic_id: Some(id),
+                ..Default::default()
+            })
+            .await?;
+        targets.extend(stories.into_iter().map(|s| Target {
+            kind: "story",
+            id: s.id,
+            name: s.name,
+        }));
+        if !args.keep_epic {
+            targets.push(Target {
+                kind: "epic",
+                id,
+                name: epic.name,
+            });
+        }
+    }
+
+    let mut seen = HashSet::new();
+    targets.retain(|t| seen.insert((t.kind, t.id)));
+    targets.sort_by_key(|t| match t.kind {
+        "story" => 0,
+        "epic" => 1,
+        _ => 2,
+    });
+    Ok((targets, gone))
+}
+
+fn not_found(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<BypassError>(),
+        Some(BypassError::Api { status: 404, .. })
+    )
+}
+
+/// The IDs in `--file`: one per line in a .txt file, or the `id` of every
+/// row of an input file.  A row without an `id` is an error; rows are
+/// never matched by name.
+fn listed_ids(path: &Path, r#type: Option<&ResourceType>) -> Result<Vec<(&'static str, i64)>> {
+    let is_text = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("txt"));
+    if is_text {
+        let kind = match r#type {
+            Some(ResourceType::Objective) => "objective",
+            Some(ResourceType::Epic) => "epic",
+            Some(ResourceType::Story) | None => "story",
+            Some(ResourceType::Label) => bail!("bypass does not delete labels"),
+        };
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read file '{}': {}", path.display(), e))?;
+        let mut ids = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.split_once('#').map_or(line, |(id, _)| id).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let id = line.parse::<i64>().map_err(|_| {
+                anyhow::anyhow!("{}:{}: '{line}' is not an ID", path.display(), i + 1)
+            })?;
+            ids.push((kind, id));
+        }
+        return Ok(ids);
+    }
+
+    let input = input::parse_file(path, r#type, &ColumnOptions::default())?;
+    if !input.labels.is_empty() {
+        bail!(
+            "'{}' lists labels; bypass does not delete labels",
+            path.display()
+        );
+    }
+    let mut ids = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+    let rows = input
+        .objectives
+        .iter()
+        .map(|o| ("objective", o.id, &o.name))
+        .chain(input.epics.iter().map(|e| ("epic", e.id, &e.name)))
+        .chain(input.stories.iter().map(|s| ("story", s.id, &s.name)));
+    for (kind, id, name) in rows {
+        match id {
+            Some(id) => ids.push((kind, id)),
+            None => missing.push(format!("{kind} '{name}'")),
+        }
+    }
+    if !missing.is_empty() {
+        bail!(
+            "'{}' has rows without an id: {}",
+            path.display(),
+            missing.join(", ")
+        );
+    }
+    Ok(ids)
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+#[derive(Default)]
+struct Tally {
+    objectives: usize,
+    epics: usize,
+    stories: usize,
+}
+
+impl Tally {
+    fn count(&mut self, kind: &str) {
+        match kind {
+            "objective" => self.objectives += 1,
+            "epic" => self.epics += 1,
+            _ => self.stories += 1,
+        }
+    }
+}
+
+fn preview(args: &DeleteArgs, targets: &[Target], gone: &[(&'static str, i64)]) {
+    match args.output {
+        OutputFormat::Text => {
+            for (kind, id) in gone {
+                println!(
+                    "  {} {kind} #{id}  {}",
+                    "↷".yellow(),
+                    "not found (already deleted?)".dimmed()
+                );
+            }
+            println!(
+                "{} resource{} to delete",
+                targets.len().to_string().bold(),
+                if targets.len() == 1 { "" } else { "s" }
+            );
+            for t in targets {
+                println!("  • {} '{}'  (#{})", t.kind, t.name, t.id);
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "delete_plan",
+                "dry_run": args.dry_run.enabled,
+                "resources": targets
+                    .iter()
+                    .map(|t| json!({ "kind": t.kind, "id": t.id, "name": t.name }))
+                    .collect::<Vec<_>>(),
+                "not_found": gone
+                    .iter()
+                    .map(|(kind, id)| json!({ "kind": kind, "id": id }))
+                    .collect::<Vec<_>>(),
+            }))
+        ),
+    }
+}
+
+fn emit(output: &OutputFormat, target: &Target, error: Option<&str>) {
+    match (output, error) {
+        (OutputFormat::Text, None) => println!(
+            "  {} {} '{}'  (#{})",
+            "✓".green(),
+            target.kind,
+            target.name,
+            target.id
+        ),
+        (OutputFormat::Text, Some(e)) => println!(
+            "  {} {} '{}'  (#{})\n    {e}",
+            "✗".red(),
+            target.kind,
+            target.name,
+            target.id
+        ),
+        (OutputFormat::Json, None) => println!(
+            "{}",
+            json_event(&json!({
+                "event": "deleted",
+                "kind": target.kind,
+                "id": target.id,
+                "name": target.name,
+            }))
+        ),
+        (OutputFormat::Json, Some(e)) => println!(
+            "{}",
+            json_event(&json!({
+                "event": "error",
+                "kind": target.kind,
+                "id": target.id,
+                "name": target.name,
+                "error": e,
+            }))
+        ),
+    }
+}
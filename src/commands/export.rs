@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use colored::Colorize;
+use futures_util::{StreamExt, TryStreamExt, stream};
+use serde_json::json;
+
+use super::create::CAPACITY_PREFIX;
+use super::json_event;
+use crate::api::models::{Epic, Label, Objective, SearchStoriesRequest, Story};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{ExportArgs, OutputFormat, ResourceType};
+use crate::config::Config;
+use crate::dedupe::HASH_PREFIX;
+use crate::echo;
+use crate::input::models::{
+    InputComment, InputEpic, InputFile, InputLabel, InputObjective, InputStory, InputStoryLinks,
+    InputTask,
+};
+
+/// Epics and stories read at a time for their descriptions.
+const DETAIL_CONCURRENCY: usize = 4;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Read the workspace and write it as an input file that `create` accepts
+/// and `update` can apply back, each resource carrying its `id`.
+pub async fn run(args: ExportArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    if echo::check(&args.file)?.is_some() && args.r#type.is_none() {
+        bail!("CSV/TSV holds one resource type; pass --type or use a .yaml file");
+    }
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+
+    let wants = |kind: ResourceType| {
+        args.r#type
+            .as_ref()
+            .is_none_or(|t| std::mem::discriminant(t) == std::mem::discriminant(&kind))
+    };
+    let keep = |archived: bool| args.include_archived || !archived;
+
+    // Objectives and epics are always listed: epics and stories refer to
+    // them by name.
+    let (objectives, mut epics, members, groups, workflows, iterations, custom_fields) = tokio::try_join!(
+        client.list_objectives(),
+        client.list_epics(),
+        client.list_members(),
+        client.list_groups(),
+        client.list_workflows(),
+        client.list_iterations(),
+        client.list_custom_fields(),
+    )?;
+    let mut stories = if wants(ResourceType::Story) {
+        client
+            .search_stories(&SearchStoriesRequest {
+                archived: (!args.include_archived).then_some(false),
+                ..Default::default()
+            })
+            .await?
+    } else {
+        Vec::new()
+    };
+    stories.retain(|s| keep(s.archived));
+    stories.sort_by_key(|s| s.id);
+
+    if !args.no_descriptions {
+        if wants(ResourceType::Epic) {
+            let ids: Vec<i64> = epics
+                .iter()
+                .filter(|e| keep(e.archived))
+                .map(|e| e.id)
+                .collect();
+            let full: Vec<Epic> = stream::iter(ids.into_iter().map(|id| client.get_epic(id)))
+                .buffered(DETAIL_CONCURRENCY)
+                .try_collect()
+                .await?;
+            for epic in full {
+                if let Some(e) = epics.iter_mut().find(|e| e.id == epic.id) {
+                    *e = epic;
+                }
+            }
+        }
+        let ids: Vec<i64> = stories.iter().map(|s| s.id).collect();
+        stories = stream::iter(ids.into_iter().map(|id| client.get_story(id)))
+            .buffered(DETAIL_CONCURRENCY)
+            .try_collect()
+            .await?;
+    }
+
+    let names = Names {
+        members: members
+            .iter()
+            .map(|m| (m.id.clone(), m.profile.mention_name.clone()))
+            .collect(),
+        groups: groups
+            .iter()
+            .map(|g| (g.id.clone(), g.name.clone()))
+            .collect(),
+        states: state_refs(&workflows),
+        iterations: refs(iterations.iter().map(|i| (i.id, i.name.clone()))),
+        custom_field_values: custom_fields
+            .iter()
+            .flat_map(|cf| {
+                cf.values
+                    .iter()
+                    .map(|v| (v.id.clone(), (cf.name.clone(), v.value.clone())))
+            })
+            .collect(),
+        objectives: refs(objectives.iter().map(|o| (o.id, o.name.clone()))),
+        epics: epic_refs(&epics, &objectives),
+        stories: refs(stories.iter().map(|s| (s.id, s.name.clone()))),
+    };
+
+    let mut file = InputFile::default();
+    if wants(ResourceType::Label) {
+        file.labels = client
+            .list_labels()
+            .await?
+            .iter()
+            .filter(|l| keep(l.archived) && !internal_label(&l.name))
+            .map(export_label)
+            .collect();
+        file.labels.sort_by_key(|l| l.id);
+    }
+    if wants(ResourceType::Objective) {
+        file.objectives = objectives
+            .iter()
+            .filter(|o| keep(o.archived))
+            .map(export_objective)
+            .collect();
+        file.objectives.sort_by_key(|o| o.id);
+    }
+    if wants(ResourceType::Epic) {
+        file.epics = epics
+            .iter()
+            .filter(|e| keep(e.archived))
+            .map(|e| export_epic(e, &names))
+            .collect();
+        file.epics.sort_by_key(|e| e.id);
+    }
+    file.stories = stories.iter().map(|s| export_story(s, &names)).collect();
+    echo::write(&args.file, &file)?;
+
+    match args.output {
+        OutputFormat::Text => println!(
+            "{} Exported {}{} objectives, {} epics and {} stories to {}",
+            "✓".green(),
+            if file.labels.is_empty() {
+                String::new()
+            } else {
+                format!("{} labels, ", file.labels.len())
+            },
+            file.objectives.len(),
+            file.epics.len(),
+            file.stories.len(),
+            args.file.display()
+        ),
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "exported",
+                "file": args.file.display().to_string(),
+                "labels": file.labels.len(),
+                "objectives": file.objectives.len(),
+                "epics": file.epics.len(),
+                "stories": file.stories.len(),
+            }))
+        ),
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// References
+// ---------------------------------------------------------------------------
+
+/// How exported resources refer to members, teams, states, iterations,
+/// custom field values, objectives, epics and stories, keyed by ID.
+struct Names {
+    /// Mention names, which are unique where display names need not be.
+    members: HashMap<String, String>,
+    groups: HashMap<String, String>,
+    states: HashMap<i64, String>,
+    iterations: HashMap<i64, String>,
+    /// Custom field value ID → (field name, value name).
+    custom_field_values: HashMap<String, (String, String)>,
+    objectives: HashMap<i64, String>,
+    epics: HashMap<i64, String>,
+    stories: HashMap<i64, String>,
+}
+
+/// The name of each resource, or its ID as a string where the name is
+/// shared and would resolve to the wrong one.
+fn refs(named: impl Iterator<Item = (i64, String)>) -> HashMap<i64, String> {
+    let named: Vec<(i64, String)> = named.collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (_, name) in &named {
+        *counts.entry(name.to_lowercase()).or_default() += 1;
+    }
+    named
+        .into_iter()
+        .map(|(id, name)| match counts[&name.to_lowercase()] {
+            1 => (id, name),
+            _ => (id, id.to_string()),
+        })
+        .collect()
+}
+
+fn state_refs(workflows: &[crate::api::models::Workflow]) -> HashMap<i64, String> {
+    refs(
+        workflows
+            .iter()
+            .flat_map(|wf| wf.states.iter().map(|s| (s.id, s.name.clone()))),
+    )
+}
+
+/// As [`refs`], but a shared epic name is qualified with its objective
+/// (`Objective / Epic`) when that makes it unique.
+fn epic_refs(epics: &[Epic], objectives: &[Objective]) -> HashMap<i64, String> {
+    let objective_names: HashMap<i64, &str> =
+        objectives.iter().map(|o| (o.id, o.name.as_str())).collect();
+    let qualified = |e: &Epic| match e
+        .objective_ids
+        .first()
+        .and_then(|id| objective_names.get(id))
+    {
+        Some(objective) => format!("{objective} / {}", e.name),
+        None => e.name.clone(),
+    };
+    let mut by_name = refs(epics.iter().map(|e| (e.id, e.name.clone())));
+    let by_qualified = refs(epics.iter().map(|e| (e.id, qualified(e))));
+    for (id, name) in by_name.iter_mut() {
+        if *name == id.to_string() {
+            name.clone_from(&by_qualified[id]);
+        }
+    }
+    by_name
+}
+
+// ---------------------------------------------------------------------------
+// Conversion
+// ---------------------------------------------------------------------------
+
+fn export_label(l: &Label) -> InputLabel {
+    InputLabel {
+        id: Some(l.id),
+        name: l.name.clone(),
+        color: non_empty(&l.color),
+        description: non_empty(&l.description),
+        skip: false,
+        source_row: None,
+    }
+}
+
+fn export_objective(o: &Objective) -> InputObjective {
+    InputObjective {
+        id: Some(o.id),
+        name: o.name.clone(),
+        description: non_empty(&o.description),
+        state: Some(o.state.clone()),
+        template: None,
+        categories: o.categories.iter().map(|c| c.name.clone()).collect(),
+        key_results: Vec::new(),
+        skip: false,
+        source_row: None,
+    }
+}
+
+fn export_epic(e: &Epic, names: &Names) -> InputEpic {
+    let (labels, capacity) = labels(&e.labels);
+    InputEpic {
+        id: Some(e.id),
+        name: e.name.clone(),
+        description: non_empty(&e.description),
+        objective: e
+            .objective_ids
+            .first()
+            .map(|id| reference(&names.objectives, *id)),
+        owners: e
+            .owner_ids
+            .iter()
+            .filter_map(|id| names.members.get(id).cloned())
+            .collect(),
+        followers: e
+            .follower_ids
+            .iter()
+            .filter_map(|id| names.members.get(id).cloned())
+            .collect(),
+        teams: e
+            .group_ids
+            .iter()
+            .filter_map(|id| names.groups.get(id).cloned())
+            .collect(),
+        labels,
+        state: Some(e.state.clone()),
+        start_date: date(&e.planned_start_date),
+        deadline: date(&e.deadline),
+        template: None,
+        position: None,
+        capacity,
+        health: None,
+        links: Vec::new(),
+        preset: Vec::new(),
+        skip: false,
+        source_row: None,
+    }
+}
+
+fn export_story(s: &Story, names: &Names) -> InputStory {
+    InputStory {
+        id: Some(s.id),
+        name: s.name.clone(),
+        story_type: Some(s.story_type.clone()),
+        description: non_empty(&s.description),
+        epic: s.epic_id.map(|id| reference(&names.epics, id)),
+        owners: s
+            .owner_ids
+            .iter()
+            .filter_map(|id| names.members.get(id).cloned())
+            .collect(),
+        followers: s
+            .follower_ids
+            .iter()
+            .filter_map(|id| names.members.get(id).cloned())
+            .collect(),
+        requested_by: s
+            .requested_by_id
+            .as_ref()
+            .and_then(|id| names.members.get(id).cloned()),
+        team: s
+            .group_id
+            .as_ref()
+            .and_then(|id| names.groups.get(id).cloned()),
+        labels: labels(&s.labels).0,
+        estimate: s.estimate,
+        due_date: date(&s.deadline),
+        workflow_state: s.workflow_state_id.map(|id| reference(&names.states, id)),
+        iteration: s.iteration_id.map(|id| reference(&names.iterations, id)),
+        custom_fields: s
+            .custom_fields
+            .iter()
+            .filter_map(|v| names.custom_field_values.get(&v.value_id).cloned())
+            .collect(),
+        tasks: s
+            .tasks
+            .iter()
+            .map(|t| InputTask {
+                name: t.description.clone(),
+                owner: t
+                    .owner_ids
+                    .first()
+                    .and_then(|id| names.members.get(id).cloned()),
+                complete: t.complete,
+            })
+            .collect(),
+        comments: s
+            .comments
+            .iter()
+            .filter(|c| !c.deleted)
+            .filter_map(|c| {
+                Some(InputComment {
+                    text: non_empty(&c.text)?,
+                    author: c
+                        .author_id
+                        .as_ref()
+                        .and_then(|id| names.members.get(id).cloned()),
+                })
+            })
+            .collect(),
+        story_links: story_links(s, names),
+        ..Default::default()
+    }
+}
+
+/// The links a story is the subject of; each also shows on its object.
+fn story_links(s: &Story, names: &Names) -> InputStoryLinks {
+    let mut links = InputStoryLinks::default();
+    for link in s.story_links.iter().filter(|l| l.subject_id == s.id) {
+        let target = reference(&names.stories, link.object_id);
+        match link.verb.as_str() {
+            "blocks" => links.blocks.push(target),
+            "duplicates" => links.duplicates.push(target),
+            _ => links.relates_to.push(target),
+        }
+    }
+    links
+}
+
+/// The name for `id`, or the ID itself for a resource that was not listed.
+fn reference(names: &HashMap<i64, String>, id: i64) -> String {
+    names.get(&id).cloned().unwrap_or_else(|| id.to_string())
+}
+
+/// Label names without the `bypass-hash:` labels `--dedupe` adds, and the
+/// capacity recorded in a `capacity:<n>` label.
+fn labels(labels: &[Label]) -> (Vec<String>, Option<i64>) {
+    let mut capacity = None;
+    let mut names = Vec::new();
+    for label in labels {
+        if label.name.starts_with(HASH_PREFIX) {
+            continue;
+        }
+        match label.name.strip_prefix(CAPACITY_PREFIX).map(str::parse) {
+            Some(Ok(n)) => capacity = Some(n),
+            _ => names.push(label.name.clone()),
+        }
+    }
+    (names, capacity)
+}
+
+/// The labels bypass adds itself (`bypass-hash:`, `capacity:<n>`), which
+/// the resources that carry them recreate.
+fn internal_label(name: &str) -> bool {
+    name.starts_with(HASH_PREFIX) || name.starts_with(CAPACITY_PREFIX)
+}
+
+/// The date part of an API timestamp ("2024-01-15T00:00:00Z").
+fn date(timestamp: &Option<String>) -> Option<String> {
+    timestamp
+        .as_deref()
+        .map(|t| t.split('T').next().unwrap_or(t).to_string())
+}
+
+fn non_empty(text: &Option<String>) -> Option<String> {
+    text.clone().filter(|t| !t.trim().is_empty())
+}
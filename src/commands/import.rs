@@ -0,0 +1,125 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use std::path::Path;
+
+use crate::api::ClientOptions;
+use crate::cli::{ImportCommand, ImportGithubArgs, InputFormat, IssueState};
+use crate::input::github::{self, Issue};
+
+use super::create;
+
+const GITHUB_API: &str = "https://api.github.com";
+
+/// Issues per request; GitHub's maximum.
+const PER_PAGE: usize = 100;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+pub async fn run(cmd: ImportCommand, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    match cmd {
+        ImportCommand::Github(args) => import_github(*args, token, opts).await,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// import github
+// ---------------------------------------------------------------------------
+
+/// Fetch the issues, convert them as `--format github` would, and hand the
+/// result to `create`.
+async fn import_github(
+    args: ImportGithubArgs,
+    token: Option<String>,
+    opts: ClientOptions,
+) -> Result<()> {
+    let create = &args.create;
+    if create.stream
+        || create.r#type.is_some()
+        || !matches!(create.format, InputFormat::Auto)
+        || create.file.iter().any(|f| f != Path::new("-"))
+    {
+        bail!(
+            "`import github` reads issues from GitHub; --file, --format, --type and --stream do not apply"
+        );
+    }
+    let valid_repo = args
+        .repo
+        .split_once('/')
+        .is_some_and(|(owner, name)| !owner.is_empty() && !name.is_empty() && !name.contains('/'));
+    if !valid_repo {
+        bail!("--repo must be OWNER/NAME, got '{}'", args.repo);
+    }
+
+    let issues = fetch_issues(&args).await?;
+    if create::chatty(create) {
+        eprintln!(
+            "{} Fetched {} issue(s) from {}",
+            "↓".cyan(),
+            issues.len(),
+            args.repo
+        );
+    }
+    let input = github::convert(issues, &create.github_done_state);
+    create::run_parsed(args.create, input, token, opts).await
+}
+
+/// Every issue matching `--state` and all of the `--label`s, oldest first,
+/// one page at a time.  Pull requests come back too; the conversion skips
+/// them.
+async fn fetch_issues(args: &ImportGithubArgs) -> Result<Vec<Issue>> {
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("bypass-cli/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let url = format!("{GITHUB_API}/repos/{}/issues", args.repo);
+    let state = match args.state {
+        IssueState::Open => "open",
+        IssueState::Closed => "closed",
+        IssueState::All => "all",
+    };
+    let labels = args.labels.join(",");
+
+    let mut issues = Vec::new();
+    for page in 1.. {
+        let mut req = client
+            .get(&url)
+            .header("Accept", "application/vnd.github+json")
+            .query(&[
+                ("state", state),
+                ("sort", "created"),
+                ("direction", "asc"),
+                ("per_page", &PER_PAGE.to_string()),
+                ("page", &page.to_string()),
+            ]);
+        if !labels.is_empty() {
+            req = req.query(&[("labels", &labels)]);
+        }
+        if let Some(token) = &args.github_token {
+            req = req.bearer_auth(token);
+        }
+        let resp = req.send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            let message = serde_json::from_str::<serde_json::Value>(&body)
+                .ok()
+                .and_then(|v| v["message"].as_str().map(str::to_string))
+                .unwrap_or(body);
+            bail!(
+                "GitHub API error for {} (HTTP {status}): {message}",
+                args.repo
+            );
+        }
+        let batch: Vec<Issue> = resp
+            .json()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to parse GitHub issues for {}: {e}", args.repo))?;
+        let last = batch.len() < PER_PAGE;
+        issues.extend(batch);
+        if last {
+            break;
+        }
+    }
+    Ok(issues)
+}
@@ -0,0 +1,55 @@
+use anyhow::{Result, bail};
+
+use super::bulk::{self, StoryChange};
+use crate::api::models::{SearchStoriesRequest, UpdateStoryRequest};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::MigrateStatesArgs;
+use crate::config::Config;
+use crate::resolver::Resolver;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+pub async fn run(
+    args: MigrateStatesArgs,
+    token: Option<String>,
+    opts: ClientOptions,
+) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+    let resolver = Resolver::new(&client, &[]).await?;
+
+    let from = resolver.resolve_workflow_state(&args.from_state)?;
+    let to = resolver.resolve_workflow_state(&args.to_state)?;
+    if from == to {
+        bail!(
+            "--from-state '{}' and --to-state '{}' are the same workflow state",
+            args.from_state,
+            args.to_state
+        );
+    }
+
+    let change = StoryChange {
+        action: "migrate",
+        prompt: "Move",
+        done: "migrated",
+        relation: "in",
+        field: "state",
+        from: &args.from_state,
+        to: &args.to_state,
+        output: &args.output,
+        dry_run: args.dry_run.enabled,
+        yes: args.yes,
+    };
+    let search = SearchStoriesRequest {
+        workflow_state_id: Some(from),
+        archived: (!args.include_archived).then_some(false),
+        ..Default::default()
+    };
+    bulk::update_stories(&client, &search, &change, |_| UpdateStoryRequest {
+        workflow_state_id: Some(to),
+        ..Default::default()
+    })
+    .await
+}
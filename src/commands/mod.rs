@@ -1,10 +1,115 @@
+mod archive;
+mod associate;
+mod bulk;
+mod config;
 mod create;
+mod delete;
+mod export;
+mod import;
+mod migrate_states;
+mod ping;
+mod promote;
+mod publish;
+mod reassign;
+mod template;
+mod update;
 
+use crate::api::ClientOptions;
 use crate::cli::{Cli, Commands};
-use anyhow::Result;
+use anyhow::{Result, bail};
+use clap::CommandFactory;
+use serde_json::json;
+use std::io::{BufRead, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set from `--pretty-json` before any command runs.
+static PRETTY_JSON: AtomicBool = AtomicBool::new(false);
 
 pub async fn run(cli: Cli) -> Result<()> {
-    match cli.command {
-        Commands::Create(args) => create::run(args, cli.token).await,
+    PRETTY_JSON.store(cli.pretty_json, Ordering::Relaxed);
+    if cli.version {
+        return print_version(cli.json);
+    }
+    let Some(command) = cli.command else {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::MissingSubcommand,
+                "a subcommand is required",
+            )
+            .exit()
+    };
+    let opts = ClientOptions {
+        auth_header: cli.auth_header,
+        headers: cli.header,
+    };
+    match command {
+        Commands::Create(args) => create::run(*args, cli.token, opts).await,
+        Commands::Update(args) => update::run(args, cli.token, opts).await,
+        Commands::Delete(args) => delete::run(args, cli.token, opts).await,
+        Commands::Archive(args) => archive::run(args, cli.token, opts).await,
+        Commands::Export(args) => export::run(args, cli.token, opts).await,
+        Commands::Publish(args) => publish::run(args, cli.token, opts).await,
+        Commands::Promote(args) => promote::run(args, cli.token, opts).await,
+        Commands::Reassign(args) => reassign::run(args, cli.token, opts).await,
+        Commands::MigrateStates(args) => migrate_states::run(args, cli.token, opts).await,
+        Commands::Associate(args) => associate::run(args, cli.token, opts).await,
+        Commands::Ping(args) => ping::run(args, cli.token, opts).await,
+        Commands::Config(cmd) => config::run(cmd, cli.token, opts).await,
+        Commands::Template(cmd) => template::run(cmd),
+        Commands::Import(cmd) => import::run(cmd, cli.token, opts).await,
+    }
+}
+
+/// Serialize one `--output json` event: a single line, or indented with
+/// `--pretty-json`.
+pub fn json_event(event: &serde_json::Value) -> String {
+    if PRETTY_JSON.load(Ordering::Relaxed) {
+        serde_json::to_string_pretty(event).unwrap_or_default()
+    } else {
+        event.to_string()
+    }
+}
+
+/// Ask `prompt` (followed by ` [y/N] `) on stderr and read the answer from
+/// stdin.  Refuses when stdin is not a terminal, so scripts must pass
+/// `--yes`.
+fn confirm(prompt: &str) -> Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        bail!("Refusing to continue without confirmation; pass --yes when stdin is not a terminal");
+    }
+    eprint!("{prompt} [y/N] ");
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// `kind` with its first letter upper-cased, to start a message:
+/// `story` → `Story`.
+fn capitalized(kind: &str) -> String {
+    let mut kind = kind.to_string();
+    if let Some(first) = kind.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    kind
+}
+
+/// `--version` prints the same string clap would; `--version --json` adds
+/// the build metadata embedded by `build.rs`.
+fn print_version(as_json: bool) -> Result<()> {
+    if as_json {
+        println!(
+            "{}",
+            serde_json::to_string(&json!({
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+                "git_commit": env!("BYPASS_GIT_COMMIT"),
+                "build_date": env!("BYPASS_BUILD_DATE"),
+                "target": env!("BYPASS_BUILD_TARGET"),
+            }))?
+        );
+    } else {
+        println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
     }
+    Ok(())
 }
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+
+use super::json_event;
+use crate::api::client::BASE_URL;
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{OutputFormat, PingArgs};
+use crate::config::Config;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Connectivity check.  A token is used when one is configured, but is not
+/// required: an HTTP 401 still proves the API is reachable.
+pub async fn run(args: PingArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let token = Config::load(token).map(|c| c.api_token).unwrap_or_default();
+    let client = ShortcutClient::new(token, &opts)?;
+
+    match (client.ping().await, &args.output) {
+        (Ok(ping), OutputFormat::Text) => {
+            println!(
+                "{} {BASE_URL} reachable – HTTP {} in {} ms \
+                 (first attempt {} ms, {} attempt(s))",
+                "✓".green(),
+                ping.status,
+                ping.total.as_millis(),
+                ping.timing.first_attempt.as_millis(),
+                ping.timing.attempts,
+            );
+        }
+        (Ok(ping), OutputFormat::Json) => {
+            println!(
+                "{}",
+                json_event(&json!({
+                    "event": "ping",
+                    "reachable": true,
+                    "status": ping.status,
+                    "latency_ms": ping.total.as_millis() as u64,
+                    "first_attempt_ms": ping.timing.first_attempt.as_millis() as u64,
+                    "attempts": ping.timing.attempts,
+                }))
+            );
+        }
+        (Err(e), OutputFormat::Text) => {
+            println!("{} {BASE_URL} unreachable: {e}", "✗".red());
+            std::process::exit(1);
+        }
+        (Err(e), OutputFormat::Json) => {
+            println!(
+                "{}",
+                json_event(&json!({
+                    "event": "ping",
+                    "reachable": false,
+                    "error": e.to_string(),
+                }))
+            );
+            std::process::exit(1);
+        }
+    }
+    Ok(())
+}
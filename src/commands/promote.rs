@@ -0,0 +1,180 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+
+use super::json_event;
+use crate::api::models::{CreateLabelParams, SearchStoriesRequest, Story, UpdateStoryRequest};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{OutputFormat, PromoteArgs};
+use crate::config::Config;
+use crate::resolver::Resolver;
+use crate::stage;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Second phase of `create --stage`: move every story in the staging state
+/// to the state named by its `intended:<state>` label and drop the label.
+pub async fn run(args: PromoteArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+    let resolver = Resolver::new(&client, &[]).await?;
+
+    let staging_id = stage::check_staging_state(&resolver, &args.staging_state)?;
+    let stories = client
+        .search_stories(&SearchStoriesRequest {
+            workflow_state_id: Some(staging_id),
+            archived: Some(false),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut promoted = 0usize;
+    let mut skipped = 0usize;
+    let mut errors: Vec<String> = Vec::new();
+
+    for story in &stories {
+        let Some(intended) = story
+            .labels
+            .iter()
+            .find_map(|l| stage::intended_state(&l.name))
+        else {
+            skipped += 1;
+            emit(
+                &args.output,
+                story,
+                None,
+                Some("no intended: label, left in place"),
+            )?;
+            continue;
+        };
+        let state_id = match resolver.resolve_workflow_state(intended) {
+            Ok(id) => id,
+            Err(e) => {
+                let msg = format!("intended state '{intended}': {e}");
+                emit(&args.output, story, Some(intended), Some(&msg))?;
+                errors.push(format!("Story '{}' (#{}): {msg}", story.name, story.id));
+                continue;
+            }
+        };
+
+        if args.dry_run.enabled {
+            promoted += 1;
+            emit(&args.output, story, Some(intended), None)?;
+            continue;
+        }
+
+        let req = UpdateStoryRequest {
+            workflow_state_id: Some(state_id),
+            labels: Some(
+                story
+                    .labels
+                    .iter()
+                    .filter(|l| stage::intended_state(&l.name).is_none())
+                    .map(|l| CreateLabelParams {
+                        name: l.name.clone(),
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+        match client.update_story(story.id, &req).await {
+            Ok(_) => {
+                promoted += 1;
+                emit(&args.output, story, Some(intended), None)?;
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(&args.output, story, Some(intended), Some(&msg))?;
+                errors.push(format!("Story '{}' (#{}): {msg}", story.name, story.id));
+            }
+        }
+    }
+
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            let verb = if args.dry_run.enabled {
+                "to promote"
+            } else {
+                "promoted"
+            };
+            println!("  Stories {verb:<11}: {}", promoted.to_string().green());
+            println!("  Skipped            : {skipped}");
+            if !errors.is_empty() {
+                println!("  Errors             : {}", errors.len().to_string().red());
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "dry_run": args.dry_run.enabled,
+                "stories_promoted": promoted,
+                "stories_skipped": skipped,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Report one story.  `problem` marks it as skipped (no `intended`) or
+/// failed (with `intended`).
+fn emit(
+    output: &OutputFormat,
+    story: &Story,
+    intended: Option<&str>,
+    problem: Option<&str>,
+) -> Result<()> {
+    match output {
+        OutputFormat::Text => match (intended, problem) {
+            (Some(state), None) => {
+                println!(
+                    "  {} {}  (#{}) → {state}",
+                    "✓".green(),
+                    story.name,
+                    story.id
+                )
+            }
+            (None, Some(p)) => {
+                println!("  {} {}  (#{}): {p}", "⚠".yellow(), story.name, story.id)
+            }
+            (_, p) => println!(
+                "  {} {}  (#{})\n    {}",
+                "✗".red(),
+                story.name,
+                story.id,
+                p.unwrap_or_default()
+            ),
+        },
+        OutputFormat::Json => {
+            let event = match (intended, problem) {
+                (Some(_), None) => "promoted",
+                (None, Some(_)) => "skipped",
+                _ => "error",
+            };
+            println!(
+                "{}",
+                json_event(&json!({
+                    "event": event,
+                    "kind": "story",
+                    "id": story.id,
+                    "name": story.name,
+                    "intended_state": intended,
+                    "error": problem,
+                }))
+            );
+        }
+    }
+    Ok(())
+}
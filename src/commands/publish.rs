@@ -0,0 +1,162 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+
+use super::json_event;
+use crate::api::models::{
+    CreateLabelParams, Label, SearchStoriesRequest, UpdateEpicRequest, UpdateStoryRequest,
+};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{OutputFormat, PublishArgs};
+use crate::config::Config;
+
+/// Label marking resources created by `create --draft`.
+pub(super) const DRAFT_LABEL: &str = "bypass-draft";
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Un-archive every archived epic and story carrying the draft label and
+/// drop the label.
+pub async fn run(args: PublishArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+
+    let epics: Vec<_> = client
+        .list_epics()
+        .await?
+        .into_iter()
+        .filter(|e| e.archived && e.labels.iter().any(|l| l.name == DRAFT_LABEL))
+        .collect();
+    let stories = client
+        .search_stories(&SearchStoriesRequest {
+            label_name: Some(DRAFT_LABEL.into()),
+            archived: Some(true),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut published = 0usize;
+    let mut errors: Vec<String> = Vec::new();
+
+    for epic in &epics {
+        let result = if args.dry_run.enabled {
+            Ok(())
+        } else {
+            let req = UpdateEpicRequest {
+                archived: Some(false),
+                labels: Some(without_draft(&epic.labels)),
+                ..Default::default()
+            };
+            client.update_epic(epic.id, &req).await.map(|_| ())
+        };
+        report(
+            &args.output,
+            "epic",
+            &epic.name,
+            epic.id,
+            result,
+            &mut published,
+            &mut errors,
+        )?;
+    }
+
+    for story in &stories {
+        let result = if args.dry_run.enabled {
+            Ok(())
+        } else {
+            let req = UpdateStoryRequest {
+                archived: Some(false),
+                labels: Some(without_draft(&story.labels)),
+                ..Default::default()
+            };
+            client.update_story(story.id, &req).await.map(|_| ())
+        };
+        report(
+            &args.output,
+            "story",
+            &story.name,
+            story.id,
+            result,
+            &mut published,
+            &mut errors,
+        )?;
+    }
+
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            let verb = if args.dry_run.enabled {
+                "to publish"
+            } else {
+                "published"
+            };
+            println!("  Drafts {verb:<11}: {}", published.to_string().green());
+            if !errors.is_empty() {
+                println!("  Errors             : {}", errors.len().to_string().red());
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "dry_run": args.dry_run.enabled,
+                "published": published,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn without_draft(labels: &[Label]) -> Vec<CreateLabelParams> {
+    labels
+        .iter()
+        .filter(|l| l.name != DRAFT_LABEL)
+        .map(|l| CreateLabelParams {
+            name: l.name.clone(),
+        })
+        .collect()
+}
+
+fn report(
+    output: &OutputFormat,
+    kind: &str,
+    name: &str,
+    id: i64,
+    result: Result<()>,
+    published: &mut usize,
+    errors: &mut Vec<String>,
+) -> Result<()> {
+    let error = result.err().map(|e| e.to_string());
+    match &error {
+        None => *published += 1,
+        Some(e) => errors.push(format!("{kind} '{name}' (#{id}): {e}")),
+    }
+    match (output, &error) {
+        (OutputFormat::Text, None) => println!("  {} {kind}: {name}  (#{id})", "✓".green()),
+        (OutputFormat::Text, Some(e)) => {
+            println!("  {} {kind}: {name}  (#{id})\n    {e}", "✗".red())
+        }
+        (OutputFormat::Json, _) => println!(
+            "{}",
+            json_event(&json!({
+                "event": if error.is_none() { "published" } else { "error" },
+                "kind": kind,
+                "id": id,
+                "name": name,
+                "error": error,
+            }))
+        ),
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anyhow::Result;
+
+use super::bulk::{self, StoryChange};
+use crate::api::models::{SearchStoriesRequest, UpdateStoryRequest};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::ReassignArgs;
+use crate::config::Config;
+use crate::resolver::Resolver;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+pub async fn run(args: ReassignArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+    let resolver = Resolver::new(&client, &[]).await?;
+
+    // The departing owner has usually been disabled already.
+    let from = resolver.resolve_member(&args.from_owner).or_else(|e| {
+        resolver
+            .disabled_members
+            .get(args.from_owner.trim())
+            .cloned()
+            .ok_or(e)
+    })?;
+    let to = resolver.resolve_member(&args.to_owner)?;
+
+    let change = StoryChange {
+        action: "reassign",
+        prompt: "Reassign",
+        done: "reassigned",
+        relation: "owned by",
+        field: "owner",
+        from: &args.from_owner,
+        to: &args.to_owner,
+        output: &args.output,
+        dry_run: args.dry_run.enabled,
+        yes: args.yes,
+    };
+    let search = SearchStoriesRequest {
+        owner_id: Some(from.clone()),
+        archived: (!args.include_archived).then_some(false),
+        ..Default::default()
+    };
+    bulk::update_stories(&client, &search, &change, |story| UpdateStoryRequest {
+        owner_ids: Some(replace_owner(&story.owner_ids, &from, &to)),
+        ..Default::default()
+    })
+    .await
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Swap `from` for `to` in an owner list, keeping any co-owners.
+fn replace_owner(owners: &[String], from: &str, to: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(owners.len());
+    for id in owners {
+        let id = if id == from { to } else { id.as_str() };
+        if !out.iter().any(|o| o == id) {
+            out.push(id.to_string());
+        }
+    }
+    out
+}
@@ -0,0 +1,148 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+
+use crate::cli::{ResourceType, TemplateCheckArgs, TemplateCommand};
+use crate::input::models::{InputEpic, InputObjective, InputStory};
+use crate::input::{self, ColumnOptions};
+use crate::template::Template;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+pub fn run(cmd: TemplateCommand) -> Result<()> {
+    match cmd {
+        TemplateCommand::Check(args) => check(args),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// template check
+// ---------------------------------------------------------------------------
+
+fn check(args: TemplateCheckArgs) -> Result<()> {
+    let template = Template::load(&args.file)?;
+    let rendered = match args.r#type {
+        ResourceType::Epic => template.render(&sample_epic(&args)?),
+        ResourceType::Objective => template.render(&sample_objective(&args)?),
+        ResourceType::Story => template.render(&sample_story(&args)?),
+        ResourceType::Label => bail!("Labels have no description templates"),
+    };
+
+    println!(
+        "{}",
+        "─── Rendered ──────────────────────────────────".dimmed()
+    );
+    println!("{}", rendered.trim_end());
+    println!(
+        "{}",
+        "───────────────────────────────────────────────".dimmed()
+    );
+
+    let mut problems = 0;
+    for placeholder in Template::leftovers(&rendered) {
+        problems += 1;
+        let hint = if placeholder.starts_with("{{#") || placeholder.starts_with("{{/") {
+            " (block helpers such as {{#if}} are not supported)"
+        } else {
+            ""
+        };
+        println!(
+            "{} unreplaced placeholder {placeholder}{hint}",
+            "⚠".yellow()
+        );
+    }
+    if let Err(e) = template.check_env() {
+        problems += 1;
+        println!("{} {e}", "⚠".yellow());
+    }
+    if problems == 0 {
+        println!("{} All placeholders replaced", "✓".green());
+    } else if args.strict {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// The first epic of `--sample-input`, or a sample with every field set.
+fn sample_epic(args: &TemplateCheckArgs) -> Result<InputEpic> {
+    if let Some(path) = &args.sample_input {
+        let input = input::parse_file(path, Some(&args.r#type), &ColumnOptions::default())?;
+        return input
+            .epics
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("'{}' contains no epics", path.display()));
+    }
+    Ok(InputEpic {
+        id: None,
+        name: "Sample epic".into(),
+        description: Some("A description of the sample epic.".into()),
+        objective: Some("Sample objective".into()),
+        owners: vec!["alice".into(), "bob".into()],
+        followers: Vec::new(),
+        teams: vec!["Platform".into()],
+        labels: vec!["sample".into()],
+        state: Some("to do".into()),
+        start_date: Some("2024-07-01".into()),
+        deadline: Some("2024-09-30".into()),
+        template: None,
+        position: None,
+        capacity: None,
+        health: None,
+        links: Vec::new(),
+        preset: Vec::new(),
+        skip: false,
+        source_row: None,
+    })
+}
+
+/// As [`sample_epic`], for objectives.
+fn sample_objective(args: &TemplateCheckArgs) -> Result<InputObjective> {
+    if let Some(path) = &args.sample_input {
+        let input = input::parse_file(path, Some(&args.r#type), &ColumnOptions::default())?;
+        return input
+            .objectives
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("'{}' contains no objectives", path.display()));
+    }
+    Ok(InputObjective {
+        id: None,
+        name: "Sample objective".into(),
+        description: Some("A description of the sample objective.".into()),
+        state: Some("to do".into()),
+        template: None,
+        categories: Vec::new(),
+        key_results: Vec::new(),
+        skip: false,
+        source_row: None,
+    })
+}
+
+/// As [`sample_epic`], for stories.
+fn sample_story(args: &TemplateCheckArgs) -> Result<InputStory> {
+    if let Some(path) = &args.sample_input {
+        let input = input::parse_file(path, Some(&args.r#type), &ColumnOptions::default())?;
+        return input
+            .stories
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("'{}' contains no stories", path.display()));
+    }
+    Ok(InputStory {
+        name: "Sample story".into(),
+        story_type: Some("feature".into()),
+        description: Some("A description of the sample story.".into()),
+        epic: Some("Sample epic".into()),
+        owners: vec!["alice".into()],
+        followers: vec!["carol".into()],
+        team: Some("Platform".into()),
+        labels: vec!["sample".into()],
+        estimate: Some(3),
+        due_date: Some("2024-08-15".into()),
+        workflow_state: Some("Ready for Development".into()),
+        iteration: Some("Sprint 12".into()),
+        ..Default::default()
+    })
+}
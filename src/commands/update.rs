@@ -0,0 +1,655 @@
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde_json::json;
+
+use super::create::{labels_param, patch};
+use super::{capitalized, confirm, json_event};
+use crate::api::models::{
+    Epic, Objective, Patch, UpdateEpicRequest, UpdateObjectiveRequest, UpdateStoryRequest,
+};
+use crate::api::{ClientOptions, ShortcutClient};
+use crate::cli::{OutputFormat, UpdateArgs};
+use crate::config::Config;
+use crate::input::models::{InputEpic, InputFile, InputObjective, InputStory};
+use crate::input::{self, ColumnOptions};
+use crate::policy::Policy;
+use crate::resolver::{Resolver, epic_label_ref, fold_name};
+use crate::validate;
+
+// ---------------------------------------------------------------------------
+// Entry point
+// ---------------------------------------------------------------------------
+
+/// Apply the fields set in `--file` to existing objectives, epics and
+/// stories.  Every row is matched and resolved before anything is
+/// written: when one cannot be, nothing is changed.
+pub async fn run(args: UpdateArgs, token: Option<String>, opts: ClientOptions) -> Result<()> {
+    let input = input::parse_file(&args.file, args.r#type.as_ref(), &ColumnOptions::default())?;
+    let total = input.objectives.len() + input.epics.len() + input.stories.len();
+    if total == 0 {
+        eprintln!("{}", "No items found in the input file.".yellow());
+        return Ok(());
+    }
+    if matches!(args.output, OutputFormat::Text) {
+        for w in &input.warnings {
+            eprintln!("{} {w}", "⚠".yellow());
+        }
+        for (section, empty) in [
+            ("labels", input.labels.is_empty()),
+            ("iterations", input.iterations.is_empty()),
+        ] {
+            if !empty {
+                eprintln!(
+                    "{} the {section} section is ignored; update only changes objectives, epics and stories",
+                    "⚠".yellow()
+                );
+            }
+        }
+    }
+
+    let mut errors: Vec<String> = Vec::new();
+    validate::offline(&input, &Policy::default(), &mut errors);
+    if !errors.is_empty() {
+        return fail(&args, &errors);
+    }
+
+    let config = Config::load(token)?;
+    let client = ShortcutClient::new(config.api_token, &opts)?;
+    let mut resolver = Resolver::new(&client, &[]).await?;
+    let workspace = Workspace::load(&client, &input, &mut resolver).await?;
+
+    let mut changes: Vec<Change> = Vec::new();
+    for obj in &input.objectives {
+        match plan_objective(&client, &workspace, obj).await {
+            Ok(change) => changes.push(change),
+            Err(e) => errors.push(format!("Objective '{}': {e}", obj.name)),
+        }
+    }
+    for epic in &input.epics {
+        match plan_epic(&client, &workspace, &resolver, epic).await {
+            Ok(change) => changes.push(change),
+            Err(e) => errors.push(format!("Epic '{}': {e}", epic.name)),
+        }
+    }
+    for story in &input.stories {
+        match plan_story(&client, &resolver, story).await {
+            Ok(change) => changes.push(change),
+            Err(e) => errors.push(format!("Story '{}': {e}", story.name)),
+        }
+    }
+    if !errors.is_empty() {
+        return fail(&args, &errors);
+    }
+
+    let mut tally = Tally::default();
+    for change in changes.iter().filter(|c| c.fields.is_empty()) {
+        tally.unchanged += 1;
+        emit(&args, change, None);
+    }
+    let pending: Vec<&Change> = changes.iter().filter(|c| !c.fields.is_empty()).collect();
+
+    if args.dry_run.enabled {
+        for change in &pending {
+            tally.count(change.kind);
+            emit(&args, change, None);
+        }
+        return summary(&args, &tally, &errors);
+    }
+    if !pending.is_empty()
+        && !args.yes
+        && !confirm(&format!(
+            "Update {} resource{}?",
+            pending.len(),
+            if pending.len() == 1 { "" } else { "s" }
+        ))?
+    {
+        eprintln!("{}", "Aborted – nothing was updated.".yellow());
+        return Ok(());
+    }
+
+    for change in pending {
+        match apply(&client, change).await {
+            Ok(()) => {
+                tally.count(change.kind);
+                emit(&args, change, None);
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                emit(&args, change, Some(&msg));
+                errors.push(format!("{}: {msg}", change.subject()));
+            }
+        }
+    }
+    summary(&args, &tally, &errors)
+}
+
+// ---------------------------------------------------------------------------
+// Matching
+// ---------------------------------------------------------------------------
+
+/// Workspace lists for matching rows by name and resolving their
+/// objective and epic references.  Only fetched when a row needs them.
+#[derive(Default)]
+struct Workspace {
+    objectives: Vec<Objective>,
+    epics: Vec<Epic>,
+}
+
+impl Workspace {
+    async fn load(
+        client: &ShortcutClient,
+        input: &InputFile,
+        resolver: &mut Resolver,
+    ) -> Result<Self> {
+        let by_name = |r: &Option<String>| {
+            r.as_deref()
+                .is_some_and(|r| r.trim().parse::<i64>().is_err())
+        };
+        let epic_labels: Vec<&str> = input
+            .stories
+            .iter()
+            .filter_map(|s| s.epic.as_deref().and_then(epic_label_ref))
+            .collect();
+        let need_epics = input.epics.iter().any(|e| e.id.is_none())
+            || input
+                .stories
+                .iter()
+                .any(|s| by_name(&s.epic) && s.epic.as_deref().and_then(epic_label_ref).is_none());
+        let need_objectives = need_epics
+            || input.objectives.iter().any(|o| o.id.is_none())
+            || input.epics.iter().any(|e| by_name(&e.objective));
+
+        let mut workspace = Self::default();
+        if need_objectives {
+            workspace.objectives = client.list_objectives().await?;
+            for o in &workspace.objectives {
+                resolver.register_objective(o.name.clone(), o.id, o.app_url.clone());
+            }
+        }
+        if need_epics {
+            workspace.epics = client.list_epics().await?;
+            for e in &workspace.epics {
+                let objectives = workspace
+                    .objectives
+                    .iter()
+                    .filter(|o| e.objective_ids.contains(&o.id));
+                resolver.register_epic(e.name.clone(), None, e.id, e.app_url.clone());
+                for o in objectives {
+                    resolver.register_epic(e.name.clone(), Some(&o.name), e.id, e.app_url.clone());
+                }
+            }
+        }
+        if !epic_labels.is_empty() {
+            resolver.load_epic_labels(client, &epic_labels).await?;
+        }
+        if input.stories.iter().any(|s| s.project.is_some()) {
+            resolver.load_projects(client).await?;
+        }
+        Ok(workspace)
+    }
+}
+
+/// The single match for a row looked up by name.
+fn only<T>(mut matches: Vec<T>, id: impl Fn(&T) -> i64) -> Result<T> {
+    match matches.len() {
+        0 => bail!("not found; set `id` if it was renamed"),
+        1 => Ok(matches.remove(0)),
+        _ => bail!(
+            "name matches several ({}); set `id` to pick one",
+            matches
+                .iter()
+                .map(|m| format!("#{}", id(m)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    fold_name(a) == fold_name(b)
+}
+
+// ---------------------------------------------------------------------------
+// Planning
+// ---------------------------------------------------------------------------
+
+/// One resource's update, worked out before anything is written.
+struct Change {
+    kind: &'static str,
+    id: i64,
+    /// The resource's current name.
+    name: String,
+    /// Input fields written by the update, in request order.  Empty when
+    /// the row sets nothing that `update` can change.
+    fields: Vec<&'static str>,
+    /// Input fields set on the row that `update` leaves alone.
+    ignored: Vec<&'static str>,
+    request: Request,
+}
+
+enum Request {
+    Objective(UpdateObjectiveRequest),
+    Epic(UpdateEpicRequest),
+    Story(UpdateStoryRequest),
+}
+
+impl Change {
+    fn subject(&self) -> String {
+        let kind = capitalized(self.kind);
+        format!("{kind} '{}' (#{})", self.name, self.id)
+    }
+}
+
+async fn plan_objective(
+    client: &ShortcutClient,
+    workspace: &Workspace,
+    input: &InputObjective,
+) -> Result<Change> {
+    let (id, name) = match input.id {
+        Some(id) => (id, client.get_objective(id).await?.name),
+        None => {
+            let matches = workspace
+                .objectives
+                .iter()
+                .filter(|o| same_name(&o.name, &input.name))
+                .collect();
+            let found = only(matches, |o| o.id)?;
+            (found.id, found.name.clone())
+        }
+    };
+
+    let mut fields = Vec::new();
+    let request = UpdateObjectiveRequest {
+        name: note(&mut fields, "name", rename(input.id, &input.name, &name)),
+        description: note(&mut fields, "description", input.description.clone()),
+        state: note(&mut fields, "state", input.state.clone()),
+        archived: None,
+    };
+    Ok(Change {
+        kind: "objective",
+        id,
+        name,
+        fields,
+        ignored: ignored(&[
+            ("template", input.template.is_some()),
+            ("categories", !input.categories.is_empty()),
+            ("key_results", !input.key_results.is_empty()),
+        ]),
+        request: Request::Objective(request),
+    })
+}
+
+async fn plan_epic(
+    client: &ShortcutClient,
+    workspace: &Workspace,
+    resolver: &Resolver,
+    input: &InputEpic,
+) -> Result<Change> {
+    let (id, name) = match input.id {
+        Some(id) => (id, client.get_epic(id).await?.name),
+        None => {
+            let matches = workspace
+                .epics
+                .iter()
+                .filter(|e| same_name(&e.name, &input.name))
+                .collect();
+            let found = only(matches, |e| e.id)?;
+            (found.id, found.name.clone())
+        }
+    };
+
+    let objective_ids = input
+        .objective
+        .as_deref()
+        .map(|o| resolver.resolve_objective(o).map(|id| vec![id]))
+        .transpose()?;
+
+    let mut fields = Vec::new();
+    let request = UpdateEpicRequest {
+        name: note(&mut fields, "name", rename(input.id, &input.name, &name)),
+        description: note(&mut fields, "description", input.description.clone()),
+        objective_ids: note(&mut fields, "objective", objective_ids),
+        owner_ids: note(
+            &mut fields,
+            "owners",
+            listed(&input.owners, |o| resolver.resolve_members(o))?,
+        ),
+        follower_ids: note(
+            &mut fields,
+            "followers",
+            listed(&input.followers, |f| resolver.resolve_members(f))?,
+        ),
+        group_ids: note(
+            &mut fields,
+            "teams",
+            listed(&input.teams, |t| resolver.resolve_groups(t))?,
+        ),
+        labels: note(&mut fields, "labels", labels_param(&input.labels)),
+        state: note(&mut fields, "state", input.state.clone()),
+        planned_start_date: note_patch(&mut fields, "start_date", patch(&input.start_date)),
+        deadline: note_patch(&mut fields, "deadline", patch(&input.deadline)),
+        ..Default::default()
+    };
+    Ok(Change {
+        kind: "epic",
+        id,
+        name,
+        fields,
+        ignored: ignored(&[
+            ("template", input.template.is_some()),
+            ("position", input.position.is_some()),
+            ("capacity", input.capacity.is_some()),
+            ("health", input.health.is_some()),
+            ("links", !input.links.is_empty()),
+            ("preset", !input.preset.is_empty()),
+        ]),
+        request: Request::Epic(request),
+    })
+}
+
+async fn plan_story(
+    client: &ShortcutClient,
+    resolver: &Resolver,
+    input: &InputStory,
+) -> Result<Change> {
+    let (id, name) = match input.id {
+        Some(id) => (id, client.get_story(id).await?.name),
+        None => {
+            let found = only(client.find_stories_named(&input.name).await?, |s| s.id)?;
+            (found.id, found.name)
+        }
+    };
+
+    let mut fields = Vec::new();
+    let request = UpdateStoryRequest {
+        name: note(&mut fields, "name", rename(input.id, &input.name, &name)),
+        story_type: note(&mut fields, "type", input.story_type.clone()),
+        description: note(&mut fields, "description", input.description.clone()),
+        epic_id: note(
+            &mut fields,
+            "epic",
+            input
+                .epic
+                .as_deref()
+                .map(|e| resolver.resolve_epic(e))
+                .transpose()?,
+        ),
+        owner_ids: note(
+            &mut fields,
+            "owners",
+            listed(&input.owners, |o| resolver.resolve_members(o))?,
+        ),
+        follower_ids: note(
+            &mut fields,
+            "followers",
+            listed(&input.followers, |f| resolver.resolve_members(f))?,
+        ),
+        requested_by_id: note(
+            &mut fields,
+            "requested_by",
+            input
+                .requested_by
+                .as_deref()
+                .map(|r| resolver.resolve_member(r))
+                .transpose()?,
+        ),
+        group_id: note(
+            &mut fields,
+            "team",
+            input
+                .team
+                .as_deref()
+                .map(|t| resolver.resolve_group(t))
+                .transpose()?,
+        ),
+        labels: note(&mut fields, "labels", labels_param(&input.labels)),
+        estimate: note(&mut fields, "estimate", input.estimate),
+        deadline: note_patch(&mut fields, "due_date", patch(&input.due_date)),
+        workflow_state_id: note(
+            &mut fields,
+            "workflow_state",
+            input
+                .workflow_state
+                .as_deref()
+                .map(|s| resolver.resolve_workflow_state(s))
+                .transpose()?,
+        ),
+        project_id: note(
+            &mut fields,
+            "project",
+            input
+                .project
+                .as_deref()
+                .map(|p| resolver.resolve_project(p))
+                .transpose()?,
+        ),
+        iteration_id: note(
+            &mut fields,
+            "iteration",
+            input
+                .iteration
+                .as_deref()
+                .map(|i| resolver.resolve_iteration(i))
+                .transpose()?,
+        ),
+        archived: None,
+    };
+    Ok(Change {
+        kind: "story",
+        id,
+        name,
+        fields,
+        ignored: ignored(&[
+            ("acceptance_criteria", input.acceptance_criteria.is_some()),
+            ("custom_fields", !input.custom_fields.is_empty()),
+            ("tasks", !input.tasks.is_empty()),
+            ("comments", !input.comments.is_empty()),
+            ("template", input.template.is_some()),
+            ("links", !input.links.is_empty()),
+            ("story_links", !input.story_links.is_empty()),
+            ("preset", !input.preset.is_empty()),
+        ]),
+        request: Request::Story(request),
+    })
+}
+
+/// `value`, recording `field` as written when it is set.
+fn note<T>(fields: &mut Vec<&'static str>, field: &'static str, value: Option<T>) -> Option<T> {
+    if value.is_some() {
+        fields.push(field);
+    }
+    value
+}
+
+/// As [`note`], for a date that may also be cleared.
+fn note_patch(
+    fields: &mut Vec<&'static str>,
+    field: &'static str,
+    value: Patch<String>,
+) -> Patch<String> {
+    if !value.is_unchanged() {
+        fields.push(field);
+    }
+    value
+}
+
+/// A new name, for rows found by ID whose name differs from the current one.
+fn rename(id: Option<i64>, name: &str, current: &str) -> Option<String> {
+    let name = name.trim();
+    (id.is_some() && !name.is_empty() && name != current).then(|| name.to_string())
+}
+
+/// Resolve a list field; an empty list leaves the field unchanged.
+fn listed<T>(names: &[String], resolve: impl FnOnce(&[String]) -> Result<T>) -> Result<Option<T>> {
+    if names.is_empty() {
+        Ok(None)
+    } else {
+        resolve(names).map(Some)
+    }
+}
+
+fn ignored(fields: &[(&'static str, bool)]) -> Vec<&'static str> {
+    fields
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(field, _)| *field)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Writing
+// ---------------------------------------------------------------------------
+
+async fn apply(client: &ShortcutClient, change: &Change) -> Result<()> {
+    match &change.request {
+        Request::Objective(req) => client.update_objective(change.id, req).await.map(|_| ()),
+        Request::Epic(req) => client.update_epic(change.id, req).await.map(|_| ()),
+        Request::Story(req) => client.update_story(change.id, req).await.map(|_| ()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Output
+// ---------------------------------------------------------------------------
+
+#[derive(Default)]
+struct Tally {
+    objectives: usize,
+    epics: usize,
+    stories: usize,
+    /// Rows that set no field `update` can change.
+    unchanged: usize,
+}
+
+impl Tally {
+    fn count(&mut self, kind: &str) {
+        match kind {
+            "objective" => self.objectives += 1,
+            "epic" => self.epics += 1,
+            _ => self.stories += 1,
+        }
+    }
+}
+
+/// One line per resource: the fields written, or why it was left alone.
+fn emit(args: &UpdateArgs, change: &Change, error: Option<&str>) {
+    match args.output {
+        OutputFormat::Text => {
+            let head = format!("{} '{}'  (#{})", change.kind, change.name, change.id);
+            match error {
+                Some(e) => println!("  {} {head}\n    {e}", "✗".red()),
+                None if change.fields.is_empty() => println!(
+                    "  {} {head}  {}",
+                    "↷".yellow(),
+                    "nothing to change".dimmed()
+                ),
+                None => println!(
+                    "  {} {head}  {}",
+                    "✓".green(),
+                    change.fields.join(", ").dimmed()
+                ),
+            }
+            if error.is_none() && !change.ignored.is_empty() {
+                println!(
+                    "    {} not changed by update: {}",
+                    "⚠".yellow(),
+                    change.ignored.join(", ")
+                );
+            }
+        }
+        OutputFormat::Json => {
+            let event = match error {
+                Some(_) => "error",
+                None if change.fields.is_empty() => "skipped",
+                None => "updated",
+            };
+            let mut value = json!({
+                "event": event,
+                "kind": change.kind,
+                "id": change.id,
+                "name": change.name,
+                "fields": change.fields,
+                "ignored": change.ignored,
+                "dry_run": args.dry_run.enabled,
+            });
+            if let Some(e) = error {
+                value["error"] = json!(e);
+            }
+            println!("{}", json_event(&value));
+        }
+    }
+}
+
+/// Report rows that could not be matched or resolved; nothing was written.
+fn fail(args: &UpdateArgs, errors: &[String]) -> Result<()> {
+    match args.output {
+        OutputFormat::Text => {
+            for e in errors {
+                println!("  {} {e}", "✗".red());
+            }
+            if !args.dry_run.enabled {
+                eprintln!("{}", "Nothing was updated.".yellow());
+            }
+        }
+        OutputFormat::Json => {
+            for e in errors {
+                println!("{}", json_event(&json!({ "event": "error", "error": e })));
+            }
+        }
+    }
+    summary(args, &Tally::default(), errors)
+}
+
+fn summary(args: &UpdateArgs, tally: &Tally, errors: &[String]) -> Result<()> {
+    match args.output {
+        OutputFormat::Text => {
+            println!(
+                "\n{}",
+                "─── Summary ───────────────────────────────────".dimmed()
+            );
+            let verb = if args.dry_run.enabled {
+                "to update"
+            } else {
+                "updated"
+            };
+            for (kind, n) in [
+                ("Objectives", tally.objectives),
+                ("Epics", tally.epics),
+                ("Stories", tally.stories),
+            ] {
+                println!(
+                    "  {:<20} : {}",
+                    format!("{kind} {verb}"),
+                    n.to_string().green()
+                );
+            }
+            if tally.unchanged > 0 {
+                println!("  Nothing to change    : {}", tally.unchanged);
+            }
+            if !errors.is_empty() {
+                println!(
+                    "  Errors               : {}",
+                    errors.len().to_string().red()
+                );
+            }
+        }
+        OutputFormat::Json => println!(
+            "{}",
+            json_event(&json!({
+                "event": "summary",
+                "dry_run": args.dry_run.enabled,
+                "objectives_updated": tally.objectives,
+                "epics_updated": tally.epics,
+                "stories_updated": tally.stories,
+                "unchanged": tally.unchanged,
+                "error_count": errors.len(),
+                "errors": errors,
+            }))
+        ),
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
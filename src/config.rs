@@ -1,21 +1,113 @@
 use anyhow::{Result, bail};
+use clap::CommandFactory;
+use clap::parser::ValueSource;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
 use std::path::PathBuf;
 
+use crate::cli::Cli;
+
 pub struct Config {
     pub api_token: String,
+    /// Where `api_token` came from (reported by `config show`).
+    pub token_source: TokenSource,
+}
+
+/// The origin of the API token, in precedence order.
+pub enum TokenSource {
+    Flag,
+    Env,
+    ConfigFile(PathBuf),
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenSource::Flag => f.write_str("--token flag"),
+            TokenSource::Env => f.write_str("SHORTCUT_API_TOKEN environment variable"),
+            TokenSource::ConfigFile(path) => write!(f, "config file {}", path.display()),
+        }
+    }
 }
 
 /// Config file schema (`~/.config/bypass/config.yaml`).
 #[derive(serde::Deserialize)]
 struct ConfigFile {
     api_token: Option<String>,
+    /// Long flag name (without `--`) → value used when the flag is not
+    /// given on the command line.
+    #[serde(default)]
+    defaults: BTreeMap<String, serde_yaml::Value>,
 }
 
+/// Top-level keys understood in the config file.
+const CONFIG_KEYS: &[&str] = &["api_token", "defaults"];
+
+/// Long flags that `defaults:` may set: how bypass connects, reports and
+/// reads input, never what a run acts on.  Flags that pick targets or
+/// skip a safeguard (`--file`, `--label`, `--yes`, `--dry-run`, `--token`,
+/// …) must be given on the command line each time.
+const CONFIGURABLE_FLAGS: &[&str] = &[
+    // Connection
+    "auth-header",
+    "header",
+    "concurrency",
+    // Output
+    "output",
+    "pretty-json",
+    "quiet",
+    "source-rows",
+    "metrics-file",
+    // Workspace conventions
+    "workflows",
+    "story-types",
+    "project",
+    "staging-state",
+    "email-domain-map",
+    "team-alias",
+    "normalize-names",
+    "default-self-owner",
+    "due-from-iteration",
+    "policy",
+    "include-archived",
+    "no-descriptions",
+    // Input
+    "map-file",
+    "presets",
+    "column-map",
+    "name-column",
+    "name-is-first",
+    "github-done-state",
+    "ac-prefix",
+    // Templates
+    "template",
+    "objective-template",
+    "story-template",
+    "strict-templates",
+    "link-references",
+    // Checks
+    "confirm",
+    "verify",
+    "dedupe-by-hash",
+    "dry-run-level",
+    "validate-schema",
+];
+
 impl Config {
     pub fn load(cli_token: Option<String>) -> Result<Self> {
         // Priority: CLI flag > env var (handled by clap) > config file.
         if let Some(t) = cli_token {
-            return Ok(Config { api_token: t });
+            // clap merges the flag and the env var into one value; if it
+            // matches the environment, attribute it there.
+            let token_source = match std::env::var("SHORTCUT_API_TOKEN") {
+                Ok(env) if env == t => TokenSource::Env,
+                _ => TokenSource::Flag,
+            };
+            return Ok(Config {
+                api_token: t,
+                token_source,
+            });
         }
 
         // Fall through to config file.
@@ -27,7 +119,10 @@ impl Config {
                 && let Some(t) = cfg.api_token
                 && !t.is_empty()
             {
-                return Ok(Config { api_token: t });
+                return Ok(Config {
+                    api_token: t,
+                    token_source: TokenSource::ConfigFile(path),
+                });
             }
         }
 
@@ -41,6 +136,149 @@ impl Config {
     }
 }
 
-fn config_file_path() -> Option<PathBuf> {
+/// Strictly parse the config file at `path`.
+///
+/// Unlike [`Config::load`], which quietly falls through on a malformed
+/// file, this reports the parse error (with line/column and field context)
+/// and returns a warning for each unrecognized top-level key.
+pub fn validate_file(path: &std::path::Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read config '{}': {}", path.display(), e))?;
+    let cfg: ConfigFile = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Invalid config '{}': {}", path.display(), e))?;
+    let raw: serde_yaml::Value = serde_yaml::from_str(&content)?;
+    let mut warnings = Vec::new();
+    if let Some(map) = raw.as_mapping() {
+        for key in map.keys() {
+            let key = key.as_str().unwrap_or_default();
+            if !CONFIG_KEYS.contains(&key) {
+                warnings.push(format!("unrecognized key '{key}' will be ignored"));
+            }
+        }
+    }
+    let cli = Cli::command();
+    for key in cfg.defaults.keys() {
+        let known = cli
+            .get_subcommands()
+            .flat_map(|sub| sub.get_arguments())
+            .chain(cli.get_arguments())
+            .any(|arg| arg.get_long() == Some(key.as_str()));
+        if !known {
+            warnings.push(format!(
+                "defaults: '{key}' is not a flag of any command and will be ignored"
+            ));
+        } else if !CONFIGURABLE_FLAGS.contains(&key.as_str()) {
+            warnings.push(format!(
+                "defaults: '--{key}' cannot be set in the config file and will be ignored"
+            ));
+        }
+    }
+    Ok(warnings)
+}
+
+/// The `defaults:` section of the config file; empty when there is no
+/// readable config file.
+pub fn defaults() -> BTreeMap<String, serde_yaml::Value> {
+    config_file_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_yaml::from_str::<ConfigFile>(&content).ok())
+        .map(|cfg| cfg.defaults)
+        .unwrap_or_default()
+}
+
+/// The process arguments with the config file's `defaults:` appended, for
+/// every [configurable](CONFIGURABLE_FLAGS) flag of the invoked subcommand
+/// that was not given explicitly.
+///
+/// Flags given on the command line always win, and so does anything that
+/// conflicts with them (e.g. a default `name-column` with
+/// `--name-is-first`).  Defaults naming flags the subcommand lacks are
+/// skipped, so one section can serve every command.
+pub fn args_with_defaults() -> Vec<OsString> {
+    let mut args: Vec<OsString> = std::env::args_os().collect();
+    let defaults = defaults();
+    if defaults.is_empty() {
+        return args;
+    }
+    let Ok(matches) = Cli::command().try_get_matches_from(&args) else {
+        return args;
+    };
+    let Some((name, given)) = matches.subcommand() else {
+        return args;
+    };
+    let cli = Cli::command();
+    let Some(sub) = cli.find_subcommand(name) else {
+        return args;
+    };
+
+    for (key, value) in &defaults {
+        if !CONFIGURABLE_FLAGS.contains(&key.as_str()) {
+            continue;
+        }
+        let Some(arg) = sub
+            .get_arguments()
+            .chain(cli.get_arguments().filter(|a| a.is_global_set()))
+            .find(|a| a.get_long() == Some(key.as_str()))
+        else {
+            continue;
+        };
+        let id = arg.get_id().as_str();
+        if given.value_source(id) == Some(ValueSource::CommandLine)
+            || matches.value_source(id) == Some(ValueSource::CommandLine)
+        {
+            continue;
+        }
+        let is_switch = !arg.get_action().takes_values();
+        let mut extra: Vec<OsString> = Vec::new();
+        for v in flag_values(value) {
+            match v {
+                Some(v) if !is_switch => extra.extend([format!("--{key}").into(), v.into()]),
+                Some(v) if v == "true" => extra.push(format!("--{key}").into()),
+                Some(_) => {}
+                None => extra.push(format!("--{key}").into()),
+            }
+        }
+        let candidate: Vec<OsString> = args.iter().cloned().chain(extra).collect();
+        match Cli::command().try_get_matches_from(&candidate) {
+            Err(e) if e.kind() == clap::error::ErrorKind::ArgumentConflict => {}
+            _ => args = candidate,
+        }
+    }
+    args
+}
+
+/// The command-line values for one default: each list item, or the scalar.
+/// `None` stands for a bare flag given with no value.
+fn flag_values(value: &serde_yaml::Value) -> Vec<Option<String>> {
+    use serde_yaml::Value;
+    match value {
+        Value::Sequence(items) => items.iter().flat_map(flag_values).collect(),
+        Value::String(s) => vec![Some(s.clone())],
+        Value::Number(n) => vec![Some(n.to_string())],
+        Value::Bool(b) => vec![Some(b.to_string())],
+        Value::Null => vec![None],
+        _ => Vec::new(),
+    }
+}
+
+pub fn config_file_path() -> Option<PathBuf> {
     dirs::config_dir().map(|d| d.join("bypass").join("config.yaml"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configurable_flags_exist() {
+        let cli = Cli::command();
+        for flag in CONFIGURABLE_FLAGS {
+            let known = cli
+                .get_subcommands()
+                .flat_map(|sub| sub.get_arguments())
+                .chain(cli.get_arguments())
+                .any(|arg| arg.get_long() == Some(flag));
+            assert!(known, "'{flag}' is not a flag of any command");
+        }
+    }
+}
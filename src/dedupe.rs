@@ -0,0 +1,113 @@
+use crate::api::models::Label;
+use crate::input::models::{InputEpic, InputStory};
+
+/// Label prefix carrying a resource's content hash for `--dedupe-by-hash`.
+pub const HASH_PREFIX: &str = "bypass-hash:";
+
+/// What `--dedupe-by-hash` (or `--if-exists skip`) did with one resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// No earlier import of this resource was found.
+    Created,
+    /// Found with a different hash; overwritten from the input.
+    Updated,
+    /// Found with the same hash; left alone.
+    Unchanged,
+    /// `--if-exists skip`: a resource of the same name exists; left alone.
+    Exists,
+}
+
+impl Outcome {
+    /// Whether the input was written to Shortcut, so follow-ups such as
+    /// `--verify` apply.
+    pub fn wrote(self) -> bool {
+        matches!(self, Self::Created | Self::Updated)
+    }
+}
+
+/// `bypass-hash:<hex>` label for an epic's salient fields.  Followers
+/// count only when set, so hashes from before followers still match.
+pub fn epic_hash_label(epic: &InputEpic) -> String {
+    let owners = epic.owners.join("\x1e");
+    let teams = epic.teams.join("\x1e");
+    let labels = epic.labels.join("\x1e");
+    let followers = epic.followers.join("\x1e");
+    let mut fields = vec![
+        &epic.name,
+        opt(&epic.description),
+        opt(&epic.objective),
+        &owners,
+        &teams,
+        &labels,
+        opt(&epic.state),
+        opt(&epic.start_date),
+        opt(&epic.deadline),
+        opt(&epic.template),
+    ];
+    if !epic.followers.is_empty() {
+        fields.push(&followers);
+    }
+    hash_label(&fields)
+}
+
+/// `bypass-hash:<hex>` label for a story's salient fields.  The template,
+/// followers and requester count only when set, so hashes from before
+/// those fields still match.
+pub fn story_hash_label(story: &InputStory) -> String {
+    let estimate = story.estimate.map(|e| e.to_string()).unwrap_or_default();
+    let owners = story.owners.join("\x1e");
+    let labels = story.labels.join("\x1e");
+    let followers = story.followers.join("\x1e");
+    let mut fields = vec![
+        &story.name,
+        opt(&story.story_type),
+        opt(&story.description),
+        opt(&story.epic),
+        &owners,
+        opt(&story.team),
+        &labels,
+        &estimate,
+        opt(&story.due_date),
+        opt(&story.workflow_state),
+        opt(&story.project),
+    ];
+    if let Some(template) = &story.template {
+        fields.push(template);
+    }
+    if !story.followers.is_empty() {
+        fields.push(&followers);
+    }
+    if let Some(requester) = &story.requested_by {
+        fields.push(requester);
+    }
+    hash_label(&fields)
+}
+
+/// Whether `labels` carry exactly the hash label `hash`.
+pub fn has_hash(labels: &[Label], hash: &str) -> bool {
+    labels.iter().any(|l| l.name == hash)
+}
+
+/// Whether `labels` carry any hash label, i.e. the resource was imported
+/// with `--dedupe-by-hash`.
+pub fn is_hashed(labels: &[Label]) -> bool {
+    labels.iter().any(|l| l.name.starts_with(HASH_PREFIX))
+}
+
+fn opt(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or_default()
+}
+
+/// 64-bit FNV-1a over the unit-separated fields, truncated to 8 hex digits.
+/// Hand-rolled rather than `std::hash` so hashes are stable across Rust
+/// releases and therefore across runs.
+fn hash_label(fields: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for field in fields {
+        for byte in field.bytes().chain(std::iter::once(0x1f)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    format!("{HASH_PREFIX}{:08x}", hash >> 32)
+}
@@ -0,0 +1,217 @@
+use anyhow::{Result, bail};
+use std::path::Path;
+
+use crate::input::models::{
+    InputEpic, InputFile, InputLabel, InputLink, InputObjective, InputStory,
+};
+
+/// Write resources, each carrying its Shortcut `id`, as an input file: the
+/// ones a run created (`--echo-created`) or a whole workspace (`export`).
+///
+/// The format follows the extension of `path`: YAML (`.yaml`/`.yml`) keeps
+/// the manifest layout; CSV/TSV use the same columns the parsers read
+/// (multi-value cells `;`-separated) and can hold only one resource type.
+pub fn write(path: &Path, created: &InputFile) -> Result<()> {
+    let content = match check(path)? {
+        None => serde_yaml::to_string(created)?,
+        Some(delimiter) => to_tabular(created, delimiter)?,
+    };
+    std::fs::write(path, content)
+        .map_err(|e| anyhow::anyhow!("Cannot write '{}': {}", path.display(), e))
+}
+
+/// Check the extension of `path` up front, before anything is created.
+/// Returns the delimiter for CSV/TSV, `None` for YAML.
+pub fn check(path: &Path) -> Result<Option<u8>> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "yaml" | "yml" => Ok(None),
+        "csv" => Ok(Some(b',')),
+        "tsv" => Ok(Some(b'\t')),
+        other => bail!(
+            "Cannot write '{}': use a .yaml, .csv or .tsv file, not '.{other}'",
+            path.display()
+        ),
+    }
+}
+
+fn to_tabular(created: &InputFile, delimiter: u8) -> Result<String> {
+    if !created.iterations.is_empty() {
+        bail!("Iterations have no CSV/TSV layout; use a .yaml file instead");
+    }
+    let kinds = [
+        !created.labels.is_empty(),
+        !created.objectives.is_empty(),
+        !created.epics.is_empty(),
+        !created.stories.is_empty(),
+    ];
+    if kinds.iter().filter(|k| **k).count() > 1 {
+        bail!("CSV/TSV holds one resource type; use a .yaml file instead");
+    }
+
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(Vec::new());
+    if !created.labels.is_empty() {
+        wtr.write_record(["id", "name", "color", "description"])?;
+        for l in &created.labels {
+            wtr.write_record(label_row(l))?;
+        }
+    } else if !created.objectives.is_empty() {
+        wtr.write_record([
+            "id",
+            "name",
+            "description",
+            "state",
+            "template",
+            "categories",
+        ])?;
+        for o in &created.objectives {
+            wtr.write_record(objective_row(o))?;
+        }
+    } else if !created.epics.is_empty() {
+        wtr.write_record([
+            "id",
+            "name",
+            "description",
+            "objective",
+            "owners",
+            "followers",
+            "teams",
+            "labels",
+            "state",
+            "start_date",
+            "deadline",
+            "template",
+            "position",
+            "capacity",
+            "health",
+            "links",
+        ])?;
+        for e in &created.epics {
+            wtr.write_record(epic_row(e))?;
+        }
+    } else {
+        wtr.write_record([
+            "id",
+            "name",
+            "type",
+            "description",
+            "epic",
+            "owners",
+            "followers",
+            "requested_by",
+            "team",
+            "labels",
+            "estimate",
+            "due_date",
+            "workflow_state",
+            "iteration",
+            "custom_fields",
+            "project",
+            "acceptance_criteria",
+            "tasks",
+            "template",
+            "links",
+        ])?;
+        for s in &created.stories {
+            wtr.write_record(story_row(s))?;
+        }
+    }
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+fn label_row(l: &InputLabel) -> Vec<String> {
+    vec![
+        num(l.id),
+        l.name.clone(),
+        opt(&l.color),
+        opt(&l.description),
+    ]
+}
+
+fn objective_row(o: &InputObjective) -> Vec<String> {
+    vec![
+        num(o.id),
+        o.name.clone(),
+        opt(&o.description),
+        opt(&o.state),
+        opt(&o.template),
+        o.categories.join(";"),
+    ]
+}
+
+fn epic_row(e: &InputEpic) -> Vec<String> {
+    vec![
+        num(e.id),
+        e.name.clone(),
+        opt(&e.description),
+        opt(&e.objective),
+        e.owners.join(";"),
+        e.followers.join(";"),
+        e.teams.join(";"),
+        e.labels.join(";"),
+        opt(&e.state),
+        opt(&e.start_date),
+        opt(&e.deadline),
+        opt(&e.template),
+        num(e.position),
+        num(e.capacity),
+        opt(&e.health),
+        urls(&e.links),
+    ]
+}
+
+fn story_row(s: &InputStory) -> Vec<String> {
+    vec![
+        num(s.id),
+        s.name.clone(),
+        opt(&s.story_type),
+        opt(&s.description),
+        opt(&s.epic),
+        s.owners.join(";"),
+        s.followers.join(";"),
+        opt(&s.requested_by),
+        opt(&s.team),
+        s.labels.join(";"),
+        num(s.estimate),
+        opt(&s.due_date),
+        opt(&s.workflow_state),
+        opt(&s.iteration),
+        s.custom_fields
+            .iter()
+            .map(|(field, value)| format!("{field}={value}"))
+            .collect::<Vec<_>>()
+            .join(";"),
+        opt(&s.project),
+        opt(&s.acceptance_criteria),
+        s.tasks
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect::<Vec<_>>()
+            .join(";"),
+        opt(&s.template),
+        urls(&s.links),
+    ]
+}
+
+fn opt(value: &Option<String>) -> String {
+    value.clone().unwrap_or_default()
+}
+
+/// Link URLs only; titles have no CSV column.
+fn urls(links: &[InputLink]) -> String {
+    links
+        .iter()
+        .map(|l| l.url.as_str())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn num(value: Option<i64>) -> String {
+    value.map(|n| n.to_string()).unwrap_or_default()
+}
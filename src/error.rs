@@ -6,12 +6,27 @@ pub enum BypassError {
     #[error("Shortcut API error (HTTP {status}): {message}")]
     Api { status: u16, message: String },
 
-    #[error("Name not found – no {resource_type} named '{name}' in this workspace")]
-    NameNotFound { resource_type: String, name: String },
+    #[error("Authentication failed (HTTP {status}) – check your API token")]
+    Auth { status: u16 },
+
+    /// `hint` is appended as is, e.g. ` — did you mean 'Jane Smith'?`.
+    #[error("Name not found – no {resource_type} named '{name}' in this workspace{hint}")]
+    NameNotFound {
+        resource_type: String,
+        name: String,
+        hint: String,
+    },
+
+    #[error("{resource_type} '{name}' exists but is {status}")]
+    Inactive {
+        resource_type: String,
+        name: String,
+        status: String,
+    },
 
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
-    #[error("Unsupported file format '{0}': use .yaml, .csv, or .xlsx")]
+    #[error("Unsupported file format '{0}': use .yaml, .json, .csv, .tsv, or .xlsx")]
     UnsupportedFormat(String),
 }
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::input::models::InputFile;
+use crate::resolver::{epic_key, qualified_epic_key};
+
+/// Render the in-batch references of `input` as a Graphviz DOT digraph.
+///
+/// Edges point from parent to child (objective → epic → story).  References
+/// that do not match a resource in the batch become dangling nodes: numeric
+/// IDs (pre-existing Shortcut resources) are drawn dashed grey, unknown
+/// names dashed red.
+///
+/// Render with e.g. `dot -Tsvg graph.dot -o graph.svg`.
+pub fn to_dot(input: &InputFile) -> String {
+    let mut out = String::from("digraph bypass {\n    rankdir=LR;\n    node [shape=box];\n\n");
+
+    let mut objectives: HashMap<&str, String> = HashMap::new();
+    for (i, obj) in input.objectives.iter().enumerate() {
+        let id = format!("o{i}");
+        node(&mut out, &id, &obj.name, "shape=ellipse");
+        objectives.insert(obj.name.as_str(), id);
+    }
+
+    let mut epics: HashMap<String, String> = HashMap::new();
+    for (i, epic) in input.epics.iter().enumerate() {
+        let id = format!("e{i}");
+        node(&mut out, &id, &epic.name, "");
+        if let Some(obj) = &epic.objective {
+            epics.insert(qualified_epic_key(obj, &epic.name), id.clone());
+        }
+        epics.insert(epic.name.clone(), id);
+    }
+
+    for (i, story) in input.stories.iter().enumerate() {
+        node(&mut out, &format!("s{i}"), &story.name, "shape=note");
+    }
+    out.push('\n');
+
+    let mut dangling: HashMap<String, String> = HashMap::new();
+
+    for (i, epic) in input.epics.iter().enumerate() {
+        if let Some(obj) = &epic.objective {
+            let parent = match objectives.get(obj.trim()) {
+                Some(id) => id.clone(),
+                None => dangling_node(&mut out, &mut dangling, "objective", obj),
+            };
+            let _ = writeln!(out, "    {parent} -> e{i};");
+        }
+    }
+
+    for (i, story) in input.stories.iter().enumerate() {
+        if let Some(epic) = &story.epic {
+            let parent = match epics.get(&epic_key(epic)) {
+                Some(id) => id.clone(),
+                None => dangling_node(&mut out, &mut dangling, "epic", epic),
+            };
+            let _ = writeln!(out, "    {parent} -> s{i};");
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn node(out: &mut String, id: &str, label: &str, attrs: &str) {
+    let sep = if attrs.is_empty() { "" } else { ", " };
+    let _ = writeln!(out, "    {id} [label=\"{}\"{sep}{attrs}];", escape(label));
+}
+
+/// Emit (once) a node for a reference that is not part of the batch and
+/// return its DOT identifier.
+fn dangling_node(
+    out: &mut String,
+    seen: &mut HashMap<String, String>,
+    kind: &str,
+    reference: &str,
+) -> String {
+    let key = format!("{kind}:{}", reference.trim());
+    if let Some(id) = seen.get(&key) {
+        return id.clone();
+    }
+    let id = format!("x{}", seen.len());
+    if reference.trim().parse::<i64>().is_ok() {
+        node(
+            out,
+            &id,
+            &format!("{kind} #{}", reference.trim()),
+            "style=dashed, color=grey",
+        );
+    } else {
+        node(
+            out,
+            &id,
+            &format!("{kind}: {reference} (unresolved)"),
+            "style=dashed, color=red, fontcolor=red",
+        );
+    }
+    seen.insert(key, id.clone());
+    id
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,95 @@
+use anyhow::{Result, bail};
+use std::path::Path;
+
+use super::csv::Columns;
+use super::models::{InputEpic, InputFile, InputStory, InputTask};
+
+/// Parse an Asana project CSV export (`--format asana`, or `--from
+/// asana`).
+///
+/// - each section (`Section/Column`) → an epic, in order of appearance
+/// - each task (`Name`) → a story in its section's epic; `Notes` →
+///   `description`
+/// - `Assignee Email`, or `Assignee` when there is no email → `owners`
+/// - `Due Date` → `due_date`
+/// - `Tags` (comma-separated) → `labels`
+/// - a subtask (`Parent task` set) → a task on the story of that name,
+///   owned by its assignee and complete when `Completed At` is set
+///
+/// `Task Name` and `Section` are read as spellings of `Name` and
+/// `Section/Column`.  A subtask whose parent is not in the export becomes
+/// a story of its own, with a warning.  `source_row` is the spreadsheet
+/// row.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let mut reader = super::csv::open(path, b',')?;
+    let headers = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV header parse error: {}", e))?;
+    let columns = Columns::new(headers);
+    if !columns.has("name") && !columns.has("task name") {
+        bail!(
+            "'{}' has no Name column; is it an Asana CSV export?",
+            path.display()
+        );
+    }
+
+    let mut input = InputFile::default();
+    // Row, parent name, completed, and the subtask as a story.
+    let mut subtasks: Vec<(usize, String, bool, InputStory)> = Vec::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 2;
+        let record = record.map_err(|e| anyhow::anyhow!("CSV row {row} parse error: {e}"))?;
+        let Some(name) = columns.first(&record, &["name", "task name"]) else {
+            continue;
+        };
+        let section = columns.first(&record, &["section/column", "section"]);
+        if let Some(section) = &section
+            && !input.epics.iter().any(|e| &e.name == section)
+        {
+            input.epics.push(InputEpic::named(section, row));
+        }
+        let owner = columns.first(&record, &["assignee email", "assignee"]);
+        let story = InputStory {
+            description: columns.first(&record, &["notes"]),
+            epic: section,
+            owners: owner.into_iter().collect(),
+            labels: columns
+                .first(&record, &["tags"])
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect(),
+            due_date: columns.first(&record, &["due date"]),
+            ..InputStory::named(&name, row)
+        };
+        match columns.first(&record, &["parent task"]) {
+            Some(parent) => {
+                let complete = columns.first(&record, &["completed at"]).is_some();
+                subtasks.push((row, parent, complete, story));
+            }
+            None => input.stories.push(story),
+        }
+    }
+
+    // Subtasks are listed after, or before, their parent; attach them once
+    // every story is known.
+    for (row, parent, complete, subtask) in subtasks {
+        match input.stories.iter_mut().find(|s| s.name == parent) {
+            Some(story) => story.tasks.push(InputTask {
+                name: subtask.name,
+                owner: subtask.owners.into_iter().next(),
+                complete,
+            }),
+            None => {
+                input.warnings.push(format!(
+                    "Row {row}: parent task '{parent}' is not in the export; '{}' becomes a story",
+                    subtask.name
+                ));
+                input.stories.push(subtask);
+            }
+        }
+    }
+    Ok(input)
+}
@@ -0,0 +1,340 @@
+use anyhow::{Result, bail};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use super::csv::Columns;
+use super::models::{InputEpic, InputFile, InputObjective, InputStory};
+
+/// Azure DevOps work item type → Shortcut story type.  Features and epics
+/// are not listed: they become epics and objectives.
+const WORK_ITEM_TYPES: &[(&str, &str)] = &[
+    ("user story", "feature"),
+    ("product backlog item", "feature"),
+    ("requirement", "feature"),
+    ("bug", "bug"),
+    ("issue", "chore"),
+    ("impediment", "chore"),
+    ("task", "chore"),
+];
+
+/// Azure DevOps state (Agile, Scrum, Basic and CMMI processes) → workflow
+/// state, named as in Shortcut's default workflow.  Other states are kept
+/// as written; translate them with the `workflow_state` section of
+/// `--map-file`.
+const STATES: &[(&str, &str)] = &[
+    ("new", "To Do"),
+    ("proposed", "To Do"),
+    ("approved", "To Do"),
+    ("to do", "To Do"),
+    ("active", "In Progress"),
+    ("committed", "In Progress"),
+    ("doing", "In Progress"),
+    ("resolved", "In Review"),
+    ("closed", "Done"),
+    ("done", "Done"),
+];
+
+enum WorkItem {
+    Objective(InputObjective),
+    Epic(InputEpic),
+    Story(InputStory),
+}
+
+impl WorkItem {
+    fn name(&self) -> &str {
+        match self {
+            WorkItem::Objective(o) => &o.name,
+            WorkItem::Epic(e) => &e.name,
+            WorkItem::Story(s) => &s.name,
+        }
+    }
+}
+
+/// One row, with what is needed to find its parent.
+struct Row {
+    row: usize,
+    id: Option<String>,
+    parent_id: Option<String>,
+    /// `n` for a tree query's `Title n` column; 0 in a flat export.
+    level: usize,
+    item: WorkItem,
+}
+
+/// Parse an Azure DevOps Boards CSV export (`--format azdo`, or `--from
+/// azdo`).
+///
+/// - `Work Item Type: Feature` rows become epics and `Epic` rows
+///   objectives; every other row becomes a story
+/// - `Title` → `name`, `Description` → `description`
+/// - `Work Item Type` → `type` (see [`WORK_ITEM_TYPES`]); an unknown type is
+///   left to the workspace default, with a warning
+/// - `State` → `workflow_state` (see [`STATES`]); `Removed` rows are
+///   skipped
+/// - `Assigned To` → `owners`: the email in `Name <email>`, the value as
+///   given otherwise
+/// - the last segment of `Area Path`, below the project → `team` (`teams`
+///   on an epic)
+/// - `Tags` (semicolon-separated) → `labels`
+/// - `Story Points`, `Effort` or `Size` → `estimate`, rounded
+///
+/// A story's epic is the nearest Feature above it, and a feature's
+/// objective the nearest Epic above it: by the `Parent` column (a work
+/// item ID) in a flat export, or by the `Title 1`, `Title 2`, … columns of
+/// a tree query.  Parent IDs that form a cycle are an error.  `source_row`
+/// is the spreadsheet row.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let mut reader = super::csv::open(path, b',')?;
+    let headers = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV header parse error: {}", e))?;
+    let columns = Columns::new(headers);
+    // Tree queries split the title over one column per level.
+    let title_levels: Vec<String> = (1..)
+        .map(|n| format!("title {n}"))
+        .take_while(|c| columns.has(c))
+        .collect();
+    if !columns.has("work item type") || (!columns.has("title") && title_levels.is_empty()) {
+        bail!(
+            "'{}' needs Work Item Type and Title columns; is it an Azure DevOps CSV export?",
+            path.display()
+        );
+    }
+
+    let mut input = InputFile::default();
+    let mut rows: Vec<Row> = Vec::new();
+    let mut unknown_types: BTreeSet<String> = BTreeSet::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 2;
+        let record = record.map_err(|e| anyhow::anyhow!("CSV row {row} parse error: {e}"))?;
+        let title = columns.first(&record, &["title"]).map(|t| (0, t));
+        let Some((level, title)) = title.or_else(|| {
+            title_levels
+                .iter()
+                .enumerate()
+                .find_map(|(n, c)| columns.first(&record, &[c]).map(|t| (n + 1, t)))
+        }) else {
+            continue;
+        };
+        let state = columns.first(&record, &["state"]);
+        if state
+            .as_deref()
+            .is_some_and(|s| s.eq_ignore_ascii_case("removed"))
+        {
+            input.skipped += 1;
+            continue;
+        }
+        let work_item_type = columns
+            .first(&record, &["work item type"])
+            .unwrap_or_default();
+        let description = columns.first(&record, &["description"]);
+        let owners: Vec<String> = columns
+            .first(&record, &["assigned to"])
+            .map(|a| owner(&a))
+            .into_iter()
+            .collect();
+        let team = columns
+            .first(&record, &["area path"])
+            // The root area is the project itself, not a team.
+            .and_then(|p| p.split_once('\\').map(|(_, rest)| rest.to_string()))
+            .and_then(|p| p.rsplit('\\').next().map(|t| t.trim().to_string()))
+            .filter(|t| !t.is_empty());
+        let labels: Vec<String> = columns
+            .first(&record, &["tags"])
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let item = if work_item_type.eq_ignore_ascii_case("epic") {
+            WorkItem::Objective(InputObjective {
+                description,
+                ..InputObjective::named(&title, row)
+            })
+        } else if work_item_type.eq_ignore_ascii_case("feature") {
+            WorkItem::Epic(InputEpic {
+                description,
+                owners,
+                teams: team.into_iter().collect(),
+                labels,
+                ..InputEpic::named(&title, row)
+            })
+        } else {
+            let story_type = match WORK_ITEM_TYPES
+                .iter()
+                .find(|(azdo, _)| azdo.eq_ignore_ascii_case(&work_item_type))
+            {
+                Some((_, shortcut)) => Some(shortcut.to_string()),
+                None => {
+                    if !work_item_type.is_empty() {
+                        unknown_types.insert(work_item_type);
+                    }
+                    None
+                }
+            };
+            let estimate = match columns.first(&record, &["story points", "effort", "size"]) {
+                Some(points) => match points.parse::<f64>() {
+                    Ok(p) => Some(p.round() as i64),
+                    Err(_) => bail!("Row {row}: estimate '{points}' is not a number"),
+                },
+                None => None,
+            };
+            let workflow_state = state.map(|s| {
+                STATES
+                    .iter()
+                    .find(|(azdo, _)| azdo.eq_ignore_ascii_case(&s))
+                    .map_or(s, |(_, shortcut)| shortcut.to_string())
+            });
+            WorkItem::Story(InputStory {
+                story_type,
+                description,
+                owners,
+                team,
+                labels,
+                estimate,
+                workflow_state,
+                ..InputStory::named(&title, row)
+            })
+        };
+        rows.push(Row {
+            row,
+            id: columns.first(&record, &["id"]),
+            parent_id: columns.first(&record, &["parent"]),
+            level,
+            item,
+        });
+    }
+
+    // Each row's parent: the nearest shallower row above it in a tree
+    // query, the row with its Parent ID otherwise.
+    let by_id: HashMap<&str, usize> = rows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| r.id.as_deref().map(|id| (id, i)))
+        .collect();
+    let mut parents: Vec<Option<usize>> = Vec::with_capacity(rows.len());
+    let mut open: Vec<(usize, usize)> = Vec::new();
+    for (i, r) in rows.iter().enumerate() {
+        let mut parent = r.parent_id.as_deref().and_then(|id| by_id.get(id).copied());
+        if r.level > 0 {
+            while open.last().is_some_and(|&(level, _)| level >= r.level) {
+                open.pop();
+            }
+            parent = parent.or(open.last().map(|&(_, p)| p));
+            open.push((r.level, i));
+        }
+        parents.push(parent);
+    }
+    // Parent IDs that lead back to a row would send the walk up round in
+    // circles.
+    let mut checked = vec![false; rows.len()];
+    for start in 0..rows.len() {
+        let mut path: Vec<usize> = Vec::new();
+        let mut i = start;
+        while !checked[i] {
+            if path.contains(&i) {
+                bail!(
+                    "Row {}: Parent '{}' leads back to this work item",
+                    rows[i].row,
+                    rows[i].parent_id.as_deref().unwrap_or_default()
+                );
+            }
+            path.push(i);
+            match parents[i] {
+                Some(p) => i = p,
+                None => break,
+            }
+        }
+        for i in path {
+            checked[i] = true;
+        }
+    }
+    let nearest = |mut i: usize, wanted: fn(&WorkItem) -> bool| -> Option<String> {
+        while let Some(p) = parents[i] {
+            if wanted(&rows[p].item) {
+                return Some(rows[p].item.name().to_string());
+            }
+            i = p;
+        }
+        None
+    };
+    let ancestors: Vec<Option<String>> = (0..rows.len())
+        .map(|i| match rows[i].item {
+            WorkItem::Objective(_) => None,
+            WorkItem::Epic(_) => nearest(i, |w| matches!(w, WorkItem::Objective(_))),
+            WorkItem::Story(_) => nearest(i, |w| matches!(w, WorkItem::Epic(_))),
+        })
+        .collect();
+
+    for (r, ancestor) in rows.into_iter().zip(ancestors) {
+        match r.item {
+            WorkItem::Objective(o) => input.objectives.push(o),
+            WorkItem::Epic(e) => input.epics.push(InputEpic {
+                objective: ancestor,
+                ..e
+            }),
+            WorkItem::Story(s) => input.stories.push(InputStory {
+                epic: ancestor,
+                ..s
+            }),
+        }
+    }
+    for t in unknown_types {
+        input.warnings.push(format!(
+            "Azure DevOps work item type '{t}' has no Shortcut story type; those stories get the workspace default"
+        ));
+    }
+    Ok(input)
+}
+
+/// `Jane Doe <jane@corp.com>` → `jane@corp.com`; anything else as given.
+fn owner(assigned_to: &str) -> String {
+    match assigned_to
+        .rsplit_once('<')
+        .and_then(|(_, rest)| rest.strip_suffix('>'))
+    {
+        Some(email) if email.contains('@') => email.trim().to_string(),
+        _ => assigned_to.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn parse_csv(content: &str) -> Result<InputFile> {
+        let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        parse(file.path())
+    }
+
+    #[test]
+    fn parent_cycles_are_row_errors() {
+        let err = parse_csv("ID,Work Item Type,Title,Parent\n1,Bug,Crash,1\n")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Row 2: Parent '1' leads back to this work item");
+
+        let err = parse_csv(
+            "ID,Work Item Type,Title,Parent\n\
+             1,Feature,Checkout,2\n\
+             2,User Story,Pay by card,1\n",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("leads back"), "{err}");
+    }
+
+    #[test]
+    fn stories_take_the_nearest_feature_by_parent() {
+        let input = parse_csv(
+            "ID,Work Item Type,Title,Parent\n\
+             1,Feature,Checkout,\n\
+             2,User Story,Pay by card,1\n",
+        )
+        .unwrap();
+        assert_eq!(input.stories[0].epic.as_deref(), Some("Checkout"));
+    }
+}
@@ -1,49 +1,199 @@
 use anyhow::Result;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 
-use super::models::{InputEpic, InputFile, InputObjective, InputStory};
+use super::models::{
+    InputEpic, InputFile, InputLabel, InputLink, InputObjective, InputStory, InputTask,
+    split_custom_fields,
+};
+use super::{ColumnOptions, check_headers, is_stdin, rename_headers, skip_flag};
 use crate::cli::ResourceType;
 
 /// Parse a CSV file for the given resource type.
 /// Multi-value fields (owners, teams, labels) use semicolons (`;`) as the
 /// separator within a cell, since commas are the CSV delimiter.
-pub fn parse(path: &Path, resource_type: &ResourceType) -> Result<InputFile> {
-    match resource_type {
-        ResourceType::Objective => Ok(InputFile {
-            objectives: parse_typed(path, row_to_objective)?,
+///
+/// `delimiter` is `b','` for CSV and `b'\t'` for TSV (e.g. a Google Sheets
+/// export); the column layout is identical for both.
+pub fn parse(
+    path: &Path,
+    resource_type: &ResourceType,
+    delimiter: u8,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    let reader = open(path, delimiter)?;
+    parse_reader(reader, &path.display().to_string(), resource_type, columns)
+}
+
+/// [`parse`] for an already-open reader; `name` identifies the source in
+/// messages.
+pub fn parse_reader(
+    mut reader: csv::Reader<Box<dyn Read>>,
+    name: &str,
+    resource_type: &ResourceType,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    // Validate the header row before touching any data rows.
+    let source = format!("CSV '{name}'");
+    let headers = read_headers(&mut reader, columns, &source)?;
+    let warnings = check_headers(&headers, resource_type, &source)?;
+
+    let input = match resource_type {
+        ResourceType::Objective => InputFile {
+            objectives: read_rows(&mut reader, row_to_objective)?,
             ..Default::default()
-        }),
-        ResourceType::Epic => Ok(InputFile {
-            epics: parse_typed(path, row_to_epic)?,
+        },
+        ResourceType::Epic => InputFile {
+            epics: read_rows(&mut reader, row_to_epic)?,
             ..Default::default()
-        }),
-        ResourceType::Story => Ok(InputFile {
-            stories: parse_typed(path, row_to_story)?,
+        },
+        ResourceType::Story => InputFile {
+            stories: read_rows(&mut reader, row_to_story)?,
             ..Default::default()
-        }),
-    }
+        },
+        ResourceType::Label => InputFile {
+            labels: read_rows(&mut reader, row_to_label)?,
+            ..Default::default()
+        },
+    };
+    Ok(InputFile { warnings, ..input })
+}
+
+/// A lazily-read story CSV/TSV (`--stream`): the header row has been
+/// validated, but data rows are only parsed as `rows` is advanced.
+pub struct StoryStream {
+    pub headers: Vec<String>,
+    pub warnings: Vec<String>,
+    pub rows: Box<dyn Iterator<Item = Result<InputStory>>>,
+}
+
+/// Open a story CSV/TSV for streaming, one row at a time.
+pub fn stream_stories(path: &Path, delimiter: u8, columns: &ColumnOptions) -> Result<StoryStream> {
+    let mut reader = open(path, delimiter)?;
+
+    let source = format!("CSV '{}'", path.display());
+    let headers = read_headers(&mut reader, columns, &source)?;
+    let warnings = check_headers(&headers, &ResourceType::Story, &source)?;
+
+    let rows = reader
+        .into_deserialize::<StoryRow>()
+        .enumerate()
+        .map(|(i, result)| {
+            result
+                .map(|r| row_to_story(r, i + 2))
+                .map_err(|e| anyhow::anyhow!("CSV row {} parse error: {}", i + 2, e))
+        });
+
+    Ok(StoryStream {
+        headers,
+        warnings,
+        rows: Box::new(rows),
+    })
 }
 
 // ---------------------------------------------------------------------------
 // Generic CSV reader
 // ---------------------------------------------------------------------------
 
-fn parse_typed<R, T, F>(path: &Path, convert: F) -> Result<Vec<T>>
+/// A CSV reader over `path`, or over standard input for `-`.
+pub fn open(path: &Path, delimiter: u8) -> Result<csv::Reader<Box<dyn Read>>> {
+    let source: Box<dyn Read> = if is_stdin(path) {
+        Box::new(std::io::stdin())
+    } else {
+        Box::new(
+            File::open(path)
+                .map_err(|e| anyhow::anyhow!("Failed to open CSV '{}': {}", path.display(), e))?,
+        )
+    };
+    Ok(reader(source, delimiter))
+}
+
+/// A CSV reader over `source`.
+pub fn reader(source: Box<dyn Read>, delimiter: u8) -> csv::Reader<Box<dyn Read>> {
+    csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(source)
+}
+
+/// Read the header row, renaming columns (see [`rename_headers`]) in the
+/// reader as well.
+fn read_headers(
+    reader: &mut csv::Reader<Box<dyn Read>>,
+    columns: &ColumnOptions,
+    source: &str,
+) -> Result<Vec<String>> {
+    let mut headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV header parse error: {}", e))?
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+    rename_headers(&mut headers, columns, source)?;
+    reader.set_headers(csv::StringRecord::from(headers.clone()));
+    Ok(headers)
+}
+
+fn read_rows<R, T, F>(reader: &mut csv::Reader<Box<dyn Read>>, convert: F) -> Result<Vec<T>>
 where
     R: for<'de> Deserialize<'de>,
-    F: Fn(R) -> T,
+    F: Fn(R, usize) -> T,
 {
-    let mut reader = csv::Reader::from_path(path)
-        .map_err(|e| anyhow::anyhow!("Failed to open CSV '{}': {}", path.display(), e))?;
     let mut items = Vec::new();
     for (i, result) in reader.deserialize::<R>().enumerate() {
         let row = result.map_err(|e| anyhow::anyhow!("CSV row {} parse error: {}", i + 2, e))?;
-        items.push(convert(row));
+        items.push(convert(row, i + 2));
     }
     Ok(items)
 }
 
+/// The header of a CSV export from another tool (see `--from`), looked up
+/// by lowercase name.  Jira repeats a column once per value for
+/// multi-value fields (`Labels`, `Labels`, …), so a name may map to
+/// several positions.
+pub struct Columns(HashMap<String, Vec<usize>>);
+
+impl Columns {
+    pub fn new(headers: &csv::StringRecord) -> Self {
+        let mut map: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, h) in headers.iter().enumerate() {
+            map.entry(h.trim().to_lowercase()).or_default().push(i);
+        }
+        Self(map)
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// The first non-empty value among the columns called any of `names`.
+    pub fn first(&self, row: &csv::StringRecord, names: &[&str]) -> Option<String> {
+        names
+            .iter()
+            .filter_map(|n| self.0.get(*n))
+            .flatten()
+            .filter_map(|&i| row.get(i))
+            .map(str::trim)
+            .find(|v| !v.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Every non-empty value in the columns called `name`.
+    pub fn all(&self, row: &csv::StringRecord, name: &str) -> Vec<String> {
+        self.0
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter_map(|&i| row.get(i))
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
 /// Split a semicolon-delimited field value into individual trimmed strings.
 fn split_semi(value: &str) -> Vec<String> {
     value
@@ -58,25 +208,74 @@ fn opt_str(s: String) -> Option<String> {
     if t.is_empty() { None } else { Some(t) }
 }
 
+// ---------------------------------------------------------------------------
+// Labels
+// ---------------------------------------------------------------------------
+
+/// CSV columns: name, color, description, skip, enabled, id
+#[derive(Deserialize)]
+struct LabelRow {
+    name: String,
+    #[serde(default)]
+    color: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    skip: String,
+    #[serde(default)]
+    enabled: String,
+    #[serde(default)]
+    id: String,
+}
+
+fn row_to_label(r: LabelRow, row: usize) -> InputLabel {
+    InputLabel {
+        id: r.id.trim().parse::<i64>().ok(),
+        name: r.name.trim().to_string(),
+        color: opt_str(r.color),
+        description: opt_str(r.description),
+        skip: skip_flag(&r.skip, &r.enabled),
+        source_row: Some(row),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Objectives
 // ---------------------------------------------------------------------------
 
-/// CSV columns: name, description, state
+/// CSV columns: name, description, state, template, categories, skip, enabled, id
+/// `categories` is semicolon-separated.
 #[derive(Deserialize)]
 struct ObjRow {
+    #[serde(alias = "title")]
     name: String,
     #[serde(default)]
     description: String,
     #[serde(default)]
     state: String,
+    #[serde(default)]
+    template: String,
+    #[serde(default, alias = "category")]
+    categories: String,
+    #[serde(default)]
+    skip: String,
+    #[serde(default)]
+    enabled: String,
+    #[serde(default)]
+    id: String,
 }
 
-fn row_to_objective(r: ObjRow) -> InputObjective {
+fn row_to_objective(r: ObjRow, row: usize) -> InputObjective {
     InputObjective {
+        id: r.id.trim().parse::<i64>().ok(),
         name: r.name.trim().to_string(),
         description: opt_str(r.description),
         state: opt_str(r.state),
+        template: opt_str(r.template),
+        categories: split_semi(&r.categories),
+        key_results: Vec::new(),
+        skip: skip_flag(&r.skip, &r.enabled),
+        source_row: Some(row),
     }
 }
 
@@ -84,21 +283,26 @@ fn row_to_objective(r: ObjRow) -> InputObjective {
 // Epics
 // ---------------------------------------------------------------------------
 
-/// CSV columns: name, description, objective, owners, teams, labels, state,
-///              start_date, deadline, template
-/// Multi-value columns (owners, teams, labels) are semicolon-separated.
+/// CSV columns: name, description, objective, owners, followers, teams, labels,
+///              state, start_date, deadline, template, position, capacity, health,
+///              links, preset, skip, enabled, id
+/// Multi-value columns (owners, followers, teams, labels, links, preset) are
+/// semicolon-separated.
 #[derive(Deserialize)]
 struct EpicRow {
+    #[serde(alias = "title")]
     name: String,
     #[serde(default)]
     description: String,
-    #[serde(default)]
+    #[serde(default, alias = "milestone")]
     objective: String,
-    #[serde(default)]
+    #[serde(default, alias = "owner", alias = "assignee", alias = "assignees")]
     owners: String,
-    #[serde(default)]
+    #[serde(default, alias = "follower")]
+    followers: String,
+    #[serde(default, alias = "groups")]
     teams: String,
-    #[serde(default)]
+    #[serde(default, alias = "tags")]
     labels: String,
     #[serde(default)]
     state: String,
@@ -108,20 +312,45 @@ struct EpicRow {
     deadline: String,
     #[serde(default)]
     template: String,
+    #[serde(default)]
+    position: String,
+    #[serde(default)]
+    capacity: String,
+    #[serde(default)]
+    health: String,
+    #[serde(default)]
+    links: String,
+    #[serde(default)]
+    preset: String,
+    #[serde(default)]
+    skip: String,
+    #[serde(default)]
+    enabled: String,
+    #[serde(default)]
+    id: String,
 }
 
-fn row_to_epic(r: EpicRow) -> InputEpic {
+fn row_to_epic(r: EpicRow, row: usize) -> InputEpic {
     InputEpic {
+        id: r.id.trim().parse::<i64>().ok(),
         name: r.name.trim().to_string(),
         description: opt_str(r.description),
         objective: opt_str(r.objective),
         owners: split_semi(&r.owners),
+        followers: split_semi(&r.followers),
         teams: split_semi(&r.teams),
         labels: split_semi(&r.labels),
         state: opt_str(r.state),
         start_date: opt_str(r.start_date),
         deadline: opt_str(r.deadline),
         template: opt_str(r.template),
+        position: r.position.trim().parse::<i64>().ok(),
+        capacity: r.capacity.trim().parse::<i64>().ok(),
+        health: opt_str(r.health),
+        links: InputLink::split(&r.links),
+        preset: split_semi(&r.preset),
+        skip: skip_flag(&r.skip, &r.enabled),
+        source_row: Some(row),
     }
 }
 
@@ -129,44 +358,124 @@ fn row_to_epic(r: EpicRow) -> InputEpic {
 // Stories
 // ---------------------------------------------------------------------------
 
-/// CSV columns: name, type, description, epic, owners, team, labels,
-///              estimate, due_date, workflow_state
-/// Multi-value columns (owners, labels) are semicolon-separated.
+/// CSV columns: name, type, description, epic, owners, followers,
+///              requested_by, team, labels, estimate, due_date, workflow_state,
+///              iteration, custom_fields, project, acceptance_criteria, tasks,
+///              template, links, preset, skip, enabled, id
+/// Multi-value columns (owners, followers, labels, custom_fields, tasks, links,
+/// preset) are semicolon-separated; custom fields as `Field=Value`.
 #[derive(Deserialize)]
 struct StoryRow {
+    #[serde(alias = "title")]
     name: String,
-    #[serde(rename = "type", default)]
+    #[serde(rename = "type", alias = "story_type", default)]
     story_type: String,
     #[serde(default)]
     description: String,
     #[serde(default)]
     epic: String,
-    #[serde(default)]
+    #[serde(default, alias = "owner", alias = "assignee", alias = "assignees")]
     owners: String,
+    #[serde(default, alias = "follower")]
+    followers: String,
     #[serde(default)]
+    requested_by: String,
+    #[serde(default, alias = "group")]
     team: String,
-    #[serde(default)]
+    #[serde(default, alias = "tags")]
     labels: String,
     /// Stored as a string so an empty cell becomes None after parsing.
-    #[serde(default)]
+    #[serde(default, alias = "points")]
     estimate: String,
     #[serde(default)]
     due_date: String,
     #[serde(default)]
     workflow_state: String,
+    #[serde(default)]
+    iteration: String,
+    #[serde(default)]
+    custom_fields: String,
+    #[serde(default)]
+    project: String,
+    #[serde(default)]
+    acceptance_criteria: String,
+    #[serde(default)]
+    tasks: String,
+    #[serde(default)]
+    template: String,
+    #[serde(default)]
+    links: String,
+    #[serde(default)]
+    preset: String,
+    #[serde(default)]
+    skip: String,
+    #[serde(default)]
+    enabled: String,
+    #[serde(default)]
+    id: String,
 }
 
-fn row_to_story(r: StoryRow) -> InputStory {
+fn row_to_story(r: StoryRow, row: usize) -> InputStory {
     InputStory {
+        id: r.id.trim().parse::<i64>().ok(),
         name: r.name.trim().to_string(),
         story_type: opt_str(r.story_type),
         description: opt_str(r.description),
         epic: opt_str(r.epic),
         owners: split_semi(&r.owners),
+        followers: split_semi(&r.followers),
+        requested_by: opt_str(r.requested_by),
         team: opt_str(r.team),
         labels: split_semi(&r.labels),
         estimate: r.estimate.trim().parse::<i64>().ok(),
         due_date: opt_str(r.due_date),
         workflow_state: opt_str(r.workflow_state),
+        iteration: opt_str(r.iteration),
+        custom_fields: split_custom_fields(&r.custom_fields),
+        project: opt_str(r.project),
+        acceptance_criteria: opt_str(r.acceptance_criteria),
+        tasks: InputTask::split(&r.tasks),
+        comments: Vec::new(),
+        template: opt_str(r.template),
+        links: InputLink::split(&r.links),
+        story_links: Default::default(),
+        preset: split_semi(&r.preset),
+        skip: skip_flag(&r.skip, &r.enabled),
+        source_row: Some(row),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn parses_tab_delimited_stories() {
+        let mut file = tempfile::Builder::new().suffix(".tsv").tempfile().unwrap();
+        write!(
+            file,
+            "name\towners\tlabels\testimate\n\
+             Pay invoices, online\tada;grace\tbilling\t3\n\
+             Refunds\t\t\t\n"
+        )
+        .unwrap();
+
+        let input = parse(
+            file.path(),
+            &ResourceType::Story,
+            b'\t',
+            &ColumnOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(input.stories.len(), 2);
+        let story = &input.stories[0];
+        assert_eq!(story.name, "Pay invoices, online");
+        assert_eq!(story.owners, ["ada", "grace"]);
+        assert_eq!(story.labels, ["billing"]);
+        assert_eq!(story.estimate, Some(3));
+        assert_eq!(story.source_row, Some(2));
+        assert_eq!(input.stories[1].name, "Refunds");
+        assert!(input.warnings.is_empty());
     }
 }
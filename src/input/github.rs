@@ -0,0 +1,131 @@
+use anyhow::Result;
+use serde::Deserialize;
+use std::path::Path;
+
+use super::models::{InputEpic, InputFile, InputLink, InputStory};
+
+/// One issue from the GitHub REST API (`GET /repos/{owner}/{repo}/issues`).
+/// Only the fields that map onto a story are read.
+#[derive(Deserialize)]
+pub struct Issue {
+    number: i64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<IssueLabel>,
+    #[serde(default)]
+    assignees: Vec<User>,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    milestone: Option<Milestone>,
+    /// Present when the "issue" is a pull request.
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Milestone {
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    due_on: Option<String>,
+}
+
+/// Labels are objects in API responses but plain names in some exports.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IssueLabel {
+    Name(String),
+    Object { name: String },
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+/// Parse a GitHub issues JSON export (an array of issues) into stories
+/// (`--format github`); see [`convert`].
+pub fn parse(path: &Path, done_state: &str) -> Result<InputFile> {
+    let content = super::read_to_string(path)?;
+    let issues: Vec<Issue> = serde_json::from_str(&content).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to parse GitHub issues JSON '{}': {}",
+            path.display(),
+            e
+        )
+    })?;
+    Ok(convert(issues, done_state))
+}
+
+/// Turn GitHub issues into stories, and their milestones into epics.
+///
+/// - `title` → `name`, `body` → `description`
+/// - `labels` → `labels`
+/// - `assignees` → `owners`, by login (translate logins to Shortcut names
+///   with the `owners` section of `--map-file`)
+/// - `state: closed` → `workflow_state` = `done_state`; open issues get the
+///   workflow's default state
+/// - `html_url` → a linked file titled `GitHub #<number>`
+/// - `milestone` → `epic`, with one epic per milestone (its `description`
+///   and `due_on` as the epic's description and deadline)
+///
+/// Pull requests in the export are left out and counted as skipped.  An
+/// issue's `source_row` is its 1-based position in the array, as is an
+/// epic's for the first issue in its milestone.
+pub fn convert(issues: Vec<Issue>, done_state: &str) -> InputFile {
+    let mut input = InputFile::default();
+    for (i, mut issue) in issues.into_iter().enumerate() {
+        if issue.pull_request.is_some() {
+            input.skipped += 1;
+            continue;
+        }
+        let epic = issue.milestone.take().map(|m| {
+            let name = m.title.trim().to_string();
+            if !input.epics.iter().any(|e| e.name == name) {
+                input.epics.push(InputEpic {
+                    description: m.description.filter(|d| !d.trim().is_empty()),
+                    deadline: m.due_on,
+                    ..InputEpic::named(&name, i + 1)
+                });
+            }
+            name
+        });
+        input.stories.push(InputStory {
+            epic,
+            source_row: Some(i + 1),
+            ..issue_to_story(issue, done_state)
+        });
+    }
+    input
+}
+
+fn issue_to_story(issue: Issue, done_state: &str) -> InputStory {
+    InputStory {
+        name: issue.title.trim().to_string(),
+        description: issue.body.filter(|b| !b.trim().is_empty()),
+        owners: issue.assignees.into_iter().map(|u| u.login).collect(),
+        labels: issue
+            .labels
+            .into_iter()
+            .map(|l| match l {
+                IssueLabel::Name(name) | IssueLabel::Object { name } => name,
+            })
+            .collect(),
+        workflow_state: (issue.state == "closed").then(|| done_state.to_string()),
+        links: issue
+            .html_url
+            .map(|url| InputLink {
+                url,
+                title: Some(format!("GitHub #{}", issue.number)),
+            })
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,119 @@
+use anyhow::{Result, bail};
+use glob::MatchOptions;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Expand `--file` values into the files to read, in order.
+///
+/// A value containing `*` or `?` is a glob pattern: `*` matches any run
+/// of characters within one path component, `?` any single character and
+/// `[...]` any character in the brackets.
+/// Each pattern's matches are sorted, and a pattern that matches nothing
+/// is an error.  Hidden files only match a component that itself starts
+/// with `.`.  Anything else, including `-`, is passed through.  A file
+/// listed more than once is read once.
+pub fn expand(values: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for value in values {
+        if !is_pattern(value) {
+            paths.push(value.clone());
+            continue;
+        }
+        let mut matches = expand_pattern(value)?;
+        if matches.is_empty() {
+            bail!("No files match '{}'", value.display());
+        }
+        matches.sort();
+        paths.extend(matches);
+    }
+    let mut seen = HashSet::new();
+    paths.retain(|p| seen.insert(p.clone()));
+    Ok(paths)
+}
+
+fn is_pattern(path: &Path) -> bool {
+    path.to_str().is_some_and(|s| s.contains(['*', '?']))
+}
+
+/// The files matching `pattern`, in the order `glob` yields them.
+fn expand_pattern(pattern: &Path) -> Result<Vec<PathBuf>> {
+    let Some(text) = pattern.to_str() else {
+        bail!("Pattern '{}' is not valid UTF-8", pattern.display());
+    };
+    // `require_literal_leading_dot` would also hide `.draft.csv` from
+    // `.*.csv`; see `hidden_allowed` instead.
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+    let entries = glob::glob_with(text, options)
+        .map_err(|e| anyhow::anyhow!("Invalid pattern '{text}': {e}"))?;
+    let mut matches = Vec::new();
+    for entry in entries {
+        let path = entry?;
+        if path.is_file() && hidden_allowed(pattern, &path) {
+            matches.push(path);
+        }
+    }
+    Ok(matches)
+}
+
+/// Whether every hidden component of `path` was matched by a pattern
+/// component that itself starts with `.`.
+fn hidden_allowed(pattern: &Path, path: &Path) -> bool {
+    pattern.components().zip(path.components()).all(|(p, c)| {
+        !c.as_os_str().to_string_lossy().starts_with('.')
+            || p.as_os_str().to_string_lossy().starts_with('.')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A directory holding `names` as empty files.
+    fn dir_with(names: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for name in names {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        dir
+    }
+
+    fn names(paths: &[PathBuf]) -> Vec<String> {
+        paths
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn star_and_question_mark_match_sorted() {
+        let dir = dir_with(&["b.csv", "a.csv", "ab.csv", "notes.txt"]);
+        let star = expand(&[dir.path().join("*.csv")]).unwrap();
+        assert_eq!(names(&star), ["a.csv", "ab.csv", "b.csv"]);
+        let single = expand(&[dir.path().join("?.csv")]).unwrap();
+        assert_eq!(names(&single), ["a.csv", "b.csv"]);
+    }
+
+    #[test]
+    fn hidden_files_need_a_leading_dot() {
+        let dir = dir_with(&[".draft.csv", "plan.csv"]);
+        let visible = expand(&[dir.path().join("*.csv")]).unwrap();
+        assert_eq!(names(&visible), ["plan.csv"]);
+        let hidden = expand(&[dir.path().join(".*.csv")]).unwrap();
+        assert_eq!(names(&hidden), [".draft.csv"]);
+    }
+
+    #[test]
+    fn no_match_is_an_error() {
+        let dir = dir_with(&["plan.csv"]);
+        let pattern = dir.path().join("*.yaml");
+        let err = expand(std::slice::from_ref(&pattern)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("No files match '{}'", pattern.display())
+        );
+    }
+}
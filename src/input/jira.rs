@@ -0,0 +1,127 @@
+use anyhow::{Result, bail};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use super::csv::Columns;
+use super::models::{InputEpic, InputFile, InputStory};
+
+/// Jira issue type (lowercase) → Shortcut story type.
+const ISSUE_TYPES: &[(&str, &str)] = &[
+    ("story", "feature"),
+    ("new feature", "feature"),
+    ("improvement", "feature"),
+    ("feature", "feature"),
+    ("bug", "bug"),
+    ("defect", "bug"),
+    ("task", "chore"),
+    ("sub-task", "chore"),
+    ("subtask", "chore"),
+    ("chore", "chore"),
+];
+
+/// Parse a Jira CSV export (`--format jira`, or `--from jira`).
+///
+/// - `Issue Type: Epic` rows become epics, named by `Epic Name` when set
+///   and `Summary` otherwise; every other row becomes a story
+/// - `Summary` → `name`, `Description` → `description`
+/// - `Issue Type` → `type` (see [`ISSUE_TYPES`]); an unknown type is left
+///   to the workspace default, with a warning
+/// - `Epic Link` → `epic`: the name of the epic with that issue key when it
+///   is in the same export, the value as given otherwise
+/// - `Assignee` → `owners`, as Jira wrote it (translate names with the
+///   `owners` section of `--map-file`)
+/// - every `Labels` column → `labels`
+/// - `Story Points` → `estimate`, rounded to a whole number
+///
+/// The `Custom field (…)` spellings of `Epic Link`, `Epic Name` and
+/// `Story Points` are read as well.  Rows without a summary are ignored;
+/// other columns are ignored too.  `source_row` is the spreadsheet row.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let mut reader = super::csv::open(path, b',')?;
+    let headers = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV header parse error: {}", e))?;
+    let columns = Columns::new(headers);
+    if !columns.has("summary") {
+        bail!(
+            "'{}' has no Summary column; is it a Jira CSV export?",
+            path.display()
+        );
+    }
+
+    let mut input = InputFile::default();
+    let mut epic_names: HashMap<String, String> = HashMap::new();
+    let mut unknown_types: BTreeSet<String> = BTreeSet::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 2;
+        let record = record.map_err(|e| anyhow::anyhow!("CSV row {row} parse error: {e}"))?;
+        let Some(summary) = columns.first(&record, &["summary"]) else {
+            continue;
+        };
+        let issue_type = columns.first(&record, &["issue type"]).unwrap_or_default();
+        let description = columns.first(&record, &["description"]);
+        let owners: Vec<String> = columns.first(&record, &["assignee"]).into_iter().collect();
+        let labels = columns.all(&record, "labels");
+
+        if issue_type.eq_ignore_ascii_case("epic") {
+            let name = columns
+                .first(&record, &["epic name", "custom field (epic name)"])
+                .unwrap_or(summary);
+            if let Some(key) = columns.first(&record, &["issue key"]) {
+                epic_names.insert(key, name.clone());
+            }
+            input.epics.push(InputEpic {
+                description,
+                owners,
+                labels,
+                ..InputEpic::named(&name, row)
+            });
+            continue;
+        }
+
+        let story_type = match ISSUE_TYPES
+            .iter()
+            .find(|(jira, _)| jira.eq_ignore_ascii_case(&issue_type))
+        {
+            Some((_, shortcut)) => Some(shortcut.to_string()),
+            None => {
+                if !issue_type.is_empty() {
+                    unknown_types.insert(issue_type);
+                }
+                None
+            }
+        };
+        let estimate =
+            match columns.first(&record, &["story points", "custom field (story points)"]) {
+                Some(points) => match points.parse::<f64>() {
+                    Ok(p) => Some(p.round() as i64),
+                    Err(_) => bail!("Row {row}: Story Points '{points}' is not a number"),
+                },
+                None => None,
+            };
+        input.stories.push(InputStory {
+            story_type,
+            description,
+            epic: columns.first(&record, &["epic link", "custom field (epic link)"]),
+            owners,
+            labels,
+            estimate,
+            ..InputStory::named(&summary, row)
+        });
+    }
+
+    // Epic links hold issue keys; point them at the epics' names.
+    for story in &mut input.stories {
+        if let Some(epic) = &mut story.epic
+            && let Some(name) = epic_names.get(epic.as_str())
+        {
+            *epic = name.clone();
+        }
+    }
+    for t in unknown_types {
+        input.warnings.push(format!(
+            "Jira issue type '{t}' has no Shortcut story type; those stories get the workspace default"
+        ));
+    }
+    Ok(input)
+}
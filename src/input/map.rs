@@ -0,0 +1,245 @@
+use anyhow::{Result, bail};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use super::models::{InputEpic, InputFile, InputStory};
+use super::{canonical_column, known_columns};
+use crate::cli::ResourceType;
+
+/// Fields whose values a map file may translate.
+const MAPPABLE_FIELDS: &[&str] = &[
+    "objective",
+    "epic",
+    "owners",
+    "teams",
+    "team",
+    "labels",
+    "state",
+    "workflow_state",
+    "project",
+    "type",
+];
+
+/// Per-field value translations loaded from `--map-file`, applied right
+/// after parsing so that source-system values (e.g. Jira user IDs) become
+/// Shortcut names before resolution.
+///
+/// ```yaml
+/// owners:
+///   JIRA123: John Smith
+///   JIRA456: Jane Doe
+/// workflow_state:
+///   Open: Backlog
+/// ```
+#[derive(Debug, Default)]
+pub struct ValueMap {
+    fields: HashMap<String, HashMap<String, String>>,
+}
+
+impl ValueMap {
+    /// Load a YAML (or JSON) map file.  Unknown field names are an error.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read map file '{}': {}", path.display(), e))?;
+        let fields: HashMap<String, HashMap<String, String>> = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Failed to parse map file '{}': {}", path.display(), e))?;
+        let mut unknown: Vec<&str> = fields
+            .keys()
+            .map(String::as_str)
+            .filter(|f| !MAPPABLE_FIELDS.contains(f))
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            bail!(
+                "Map file '{}' has unknown field(s): {}. Mappable: {}",
+                path.display(),
+                unknown.join(", "),
+                MAPPABLE_FIELDS.join(", ")
+            );
+        }
+        Ok(Self { fields })
+    }
+
+    /// Translate every mapped field in `input`.  Returns one message per
+    /// distinct source value that has no mapping (the value is kept).
+    pub fn apply(&self, input: &mut InputFile) -> Vec<String> {
+        let mut misses = BTreeSet::new();
+        for obj in &mut input.objectives {
+            self.map_opt("state", &mut obj.state, &mut misses);
+        }
+        for epic in &mut input.epics {
+            self.apply_epic(epic, &mut misses);
+        }
+        for story in &mut input.stories {
+            self.apply_story_into(story, &mut misses);
+        }
+        format_misses(misses)
+    }
+
+    /// [`ValueMap::apply`] for a single story (used when streaming rows).
+    pub fn apply_story(&self, story: &mut InputStory) -> Vec<String> {
+        let mut misses = BTreeSet::new();
+        self.apply_story_into(story, &mut misses);
+        format_misses(misses)
+    }
+
+    fn apply_epic(&self, epic: &mut InputEpic, misses: &mut BTreeSet<(&'static str, String)>) {
+        self.map_opt("objective", &mut epic.objective, misses);
+        self.map_all("owners", &mut epic.owners, misses);
+        self.map_all("teams", &mut epic.teams, misses);
+        self.map_all("labels", &mut epic.labels, misses);
+        self.map_opt("state", &mut epic.state, misses);
+    }
+
+    fn apply_story_into(
+        &self,
+        story: &mut InputStory,
+        misses: &mut BTreeSet<(&'static str, String)>,
+    ) {
+        self.map_opt("type", &mut story.story_type, misses);
+        self.map_opt("epic", &mut story.epic, misses);
+        self.map_all("owners", &mut story.owners, misses);
+        self.map_opt("team", &mut story.team, misses);
+        self.map_all("labels", &mut story.labels, misses);
+        self.map_opt("workflow_state", &mut story.workflow_state, misses);
+        self.map_opt("project", &mut story.project, misses);
+    }
+
+    fn map_all(
+        &self,
+        field: &'static str,
+        values: &mut [String],
+        misses: &mut BTreeSet<(&'static str, String)>,
+    ) {
+        for value in values {
+            self.map(field, value, misses);
+        }
+    }
+
+    fn map_opt(
+        &self,
+        field: &'static str,
+        value: &mut Option<String>,
+        misses: &mut BTreeSet<(&'static str, String)>,
+    ) {
+        if let Some(value) = value {
+            self.map(field, value, misses);
+        }
+    }
+
+    fn map(
+        &self,
+        field: &'static str,
+        value: &mut String,
+        misses: &mut BTreeSet<(&'static str, String)>,
+    ) {
+        let Some(table) = self.fields.get(field) else {
+            return;
+        };
+        match table.get(value.trim()) {
+            Some(mapped) => *value = mapped.clone(),
+            None => {
+                misses.insert((field, value.clone()));
+            }
+        }
+    }
+}
+
+fn format_misses(misses: BTreeSet<(&'static str, String)>) -> Vec<String> {
+    misses
+        .into_iter()
+        .map(|(field, value)| format!("Map file: no '{field}' mapping for '{value}'"))
+        .collect()
+}
+
+/// Spreadsheet headers → field names, loaded from `--column-map` and
+/// applied to CSV/TSV/XLSX header rows before they are checked, for
+/// exports whose headers are not bypass's (e.g. in another language).
+///
+/// ```yaml
+/// Titel: name
+/// Verantwortlich: owners
+/// Schätzung: estimate
+/// ```
+#[derive(Debug, Default)]
+pub struct ColumnMap {
+    /// Lowercase header → field.
+    columns: HashMap<String, String>,
+}
+
+impl ColumnMap {
+    /// Load a YAML (or JSON) column map.  A field that no resource type
+    /// has is an error.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read column map '{}': {}", path.display(), e))?;
+        let columns: HashMap<String, String> = serde_yaml::from_str(&content).map_err(|e| {
+            anyhow::anyhow!("Failed to parse column map '{}': {}", path.display(), e)
+        })?;
+        let columns: HashMap<String, String> = columns
+            .into_iter()
+            .map(|(header, field)| (header, field.trim().to_lowercase()))
+            .collect();
+
+        let fields: BTreeSet<&str> = [
+            ResourceType::Objective,
+            ResourceType::Epic,
+            ResourceType::Story,
+            ResourceType::Label,
+        ]
+        .iter()
+        .flat_map(known_columns)
+        .copied()
+        .collect();
+        let mut unknown: Vec<String> = columns
+            .iter()
+            .filter(|(_, field)| !fields.contains(canonical_column(field)))
+            .map(|(header, field)| format!("'{field}' (for '{header}')"))
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            bail!(
+                "Column map '{}' has unknown field(s): {}. Fields: {}",
+                path.display(),
+                unknown.join(", "),
+                fields.into_iter().collect::<Vec<_>>().join(", ")
+            );
+        }
+        let columns = columns
+            .into_iter()
+            .map(|(header, field)| (header.trim().to_lowercase(), field))
+            .collect();
+        Ok(Self { columns })
+    }
+
+    /// Rename every mapped header in `headers` (matched case-insensitively)
+    /// to its field.  Two columns ending up as the same field is an error,
+    /// as each row would then have two values for it.
+    pub fn apply(&self, headers: &mut [String], source: &str) -> Result<()> {
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+        let original = headers.to_vec();
+        let mut renamed = Vec::new();
+        for (i, header) in headers.iter_mut().enumerate() {
+            if let Some(field) = self.columns.get(&header.trim().to_lowercase()) {
+                *header = field.clone();
+                renamed.push(i);
+            }
+        }
+        for i in renamed {
+            let field = canonical_column(&headers[i]);
+            if let Some(other) = (0..headers.len())
+                .find(|&j| j != i && canonical_column(&headers[j].trim().to_lowercase()) == field)
+            {
+                bail!(
+                    "{source}: column '{}' maps to '{}', which the '{}' column already provides",
+                    original[i],
+                    headers[i],
+                    original[other]
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+use anyhow::{Result, bail};
+use std::path::Path;
+
+use super::models::{InputEpic, InputFile, InputObjective, InputStory, InputTask};
+use crate::resolver::qualified_epic_key;
+
+/// Workflow state for checked items, named as in Shortcut's default
+/// workflow.
+const DONE_STATE: &str = "Done";
+
+/// Parse a markdown checklist (`.md`, or `--format markdown`).
+///
+/// - each `#` heading → an epic; the text between it and its first item
+///   → the epic's `description`
+/// - each checklist item (`- [ ]`, `* [x]`, `1. [ ]`, …) → a story in the
+///   epic above it, or in no epic before the first heading; a checked item
+///   gets the `Done` workflow state
+/// - a checklist item indented under a story → a task on it, complete when
+///   checked
+/// - other text indented under a story → its `description`
+///
+/// Deeper headings only end an epic's description, and plain text
+/// elsewhere (including fenced code blocks) is ignored.  `source_row` is
+/// the line number.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let content = super::read_to_string(path)?;
+    let mut input = InputFile::default();
+    // Indentation of the current story's item, while lines may add to it.
+    let mut story_indent: Option<usize> = None;
+    let mut describing_epic = false;
+    let mut in_fence = false;
+    let mut blank = false;
+    for (i, line) in content.lines().enumerate() {
+        let row = i + 1;
+        let text = line.trim_start();
+        if text.starts_with("```") || text.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if text.is_empty() {
+            blank = true;
+            continue;
+        }
+        let indent = indentation(line);
+        let after_blank = std::mem::take(&mut blank);
+
+        if let Some((level, heading)) = heading(line) {
+            story_indent = None;
+            describing_epic = level == 1;
+            if level == 1 {
+                input.epics.push(InputEpic::named(heading, row));
+            }
+            continue;
+        }
+
+        if let Some((checked, name)) = checklist_item(text) {
+            describing_epic = false;
+            if let Some(parent) = story_indent
+                && indent > parent
+                && let Some(story) = input.stories.last_mut()
+            {
+                story.tasks.push(InputTask {
+                    name: name.to_string(),
+                    owner: None,
+                    complete: checked,
+                });
+                continue;
+            }
+            story_indent = Some(indent);
+            input.stories.push(story(
+                name,
+                input.epics.last().map(|e| e.name.clone()),
+                checked,
+                row,
+            ));
+            continue;
+        }
+
+        let description = match story_indent {
+            Some(parent) if indent > parent => input.stories.last_mut().map(|s| &mut s.description),
+            _ if describing_epic => input.epics.last_mut().map(|e| &mut e.description),
+            _ => None,
+        };
+        story_indent = story_indent.filter(|&parent| indent > parent);
+        if let Some(description) = description {
+            append(description, text, after_blank);
+        }
+    }
+    Ok(input)
+}
+
+/// What the lines after a heading or item describe, in an outline.
+enum Current {
+    Nothing,
+    Objective,
+    Epic,
+    /// A `###` story (`None`) or a list item story at this indentation.
+    Story(Option<usize>),
+}
+
+/// Parse a markdown outline (`--format outline`) into objectives, epics
+/// and stories.
+///
+/// - `#` heading → an objective
+/// - `##` heading → an epic under the objective above it
+/// - `###` heading → a story in the epic above it
+/// - a list item under an epic (`- item`, or a checklist item) → a story in
+///   that epic, or in no epic before the first `##`; a checked item gets
+///   the `Done` workflow state
+/// - a list item indented under a list item story, or a checklist item
+///   under a `###` story → a task on it, complete when checked
+/// - `owner:` (or `owners:`, comma-separated) and `estimate:` lines → the
+///   story's owners and estimate; `owner:` also applies to an epic
+/// - other text below a heading or indented under a list item story → its
+///   `description`, as are plain list items under an objective or a `###`
+///   story
+///
+/// Metadata an item cannot have (an estimate on an epic, an owner on an
+/// objective) is an error naming the line.  `source_row` is the line
+/// number.
+pub fn parse_outline(path: &Path) -> Result<InputFile> {
+    let content = super::read_to_string(path)?;
+    let mut input = InputFile::default();
+    let mut current = Current::Nothing;
+    let mut objective: Option<String> = None;
+    let mut epic: Option<String> = None;
+    let mut in_fence = false;
+    let mut blank = false;
+    for (i, line) in content.lines().enumerate() {
+        let row = i + 1;
+        let text = line.trim_start();
+        if text.starts_with("```") || text.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if text.is_empty() {
+            blank = true;
+            continue;
+        }
+        let indent = indentation(line);
+        let after_blank = std::mem::take(&mut blank);
+
+        match heading(line) {
+            Some((1, name)) => {
+                input.objectives.push(InputObjective::named(name, row));
+                objective = Some(name.to_string());
+                epic = None;
+                current = Current::Objective;
+                continue;
+            }
+            Some((2, name)) => {
+                input.epics.push(InputEpic {
+                    objective: objective.clone(),
+                    ..InputEpic::named(name, row)
+                });
+                // Qualified, as two objectives may have epics of the same name.
+                epic = Some(match &objective {
+                    Some(obj) => qualified_epic_key(obj, name),
+                    None => name.to_string(),
+                });
+                current = Current::Epic;
+                continue;
+            }
+            Some((3, name)) => {
+                input.stories.push(story(name, epic.clone(), false, row));
+                current = Current::Story(None);
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some((checked, name)) = list_item(text) {
+            match current {
+                Current::Story(Some(parent)) if indent > parent => {}
+                Current::Story(None) if checked.is_some() => {}
+                Current::Story(None) => {
+                    if let Some(story) = input.stories.last_mut() {
+                        append(&mut story.description, text, after_blank);
+                    }
+                    continue;
+                }
+                Current::Objective => {
+                    if let Some(objective) = input.objectives.last_mut() {
+                        append(&mut objective.description, text, after_blank);
+                    }
+                    continue;
+                }
+                _ => {
+                    input
+                        .stories
+                        .push(story(name, epic.clone(), checked.unwrap_or(false), row));
+                    current = Current::Story(Some(indent));
+                    continue;
+                }
+            }
+            if let Some(story) = input.stories.last_mut() {
+                story.tasks.push(InputTask {
+                    name: name.to_string(),
+                    owner: None,
+                    complete: checked.unwrap_or(false),
+                });
+            }
+            continue;
+        }
+
+        if let Current::Story(Some(parent)) = current
+            && indent <= parent
+        {
+            // Back out of a list item story to the epic it is in.
+            current = match epic {
+                Some(_) => Current::Epic,
+                None => Current::Nothing,
+            };
+        }
+        if let Some((key, value)) = metadata(text) {
+            match (&current, key) {
+                (Current::Story(_), Key::Owner) => {
+                    if let Some(story) = input.stories.last_mut() {
+                        story.owners.extend(split_owners(value));
+                    }
+                }
+                (Current::Story(_), Key::Estimate) => {
+                    let estimate = match value.parse::<f64>() {
+                        Ok(e) => e.round() as i64,
+                        Err(_) => bail!("Line {row}: estimate '{value}' is not a number"),
+                    };
+                    if let Some(story) = input.stories.last_mut() {
+                        story.estimate = Some(estimate);
+                    }
+                }
+                (Current::Epic, Key::Owner) => {
+                    if let Some(epic) = input.epics.last_mut() {
+                        epic.owners.extend(split_owners(value));
+                    }
+                }
+                (Current::Epic, Key::Estimate) => {
+                    bail!("Line {row}: epics have no estimate; put it on their stories")
+                }
+                (Current::Objective, _) => {
+                    bail!("Line {row}: objectives have no owner or estimate")
+                }
+                (Current::Nothing, _) => {
+                    bail!("Line {row}: '{text}' is not under an epic or story")
+                }
+            }
+            continue;
+        }
+        let description = match current {
+            Current::Nothing => None,
+            Current::Objective => input.objectives.last_mut().map(|o| &mut o.description),
+            Current::Epic => input.epics.last_mut().map(|e| &mut e.description),
+            Current::Story(_) => input.stories.last_mut().map(|s| &mut s.description),
+        };
+        if let Some(description) = description {
+            append(description, text, after_blank);
+        }
+    }
+    Ok(input)
+}
+
+enum Key {
+    Owner,
+    Estimate,
+}
+
+/// An `owner:`, `owners:` or `estimate:` line.
+fn metadata(text: &str) -> Option<(Key, &str)> {
+    let (key, value) = text.split_once(':')?;
+    let key = match key.trim().to_lowercase().as_str() {
+        "owner" | "owners" => Key::Owner,
+        "estimate" => Key::Estimate,
+        _ => return None,
+    };
+    Some((key, value.trim()))
+}
+
+fn split_owners(value: &str) -> impl Iterator<Item = String> + '_ {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|o| !o.is_empty())
+        .map(str::to_string)
+}
+
+fn story(name: &str, epic: Option<String>, checked: bool, row: usize) -> InputStory {
+    InputStory {
+        epic,
+        workflow_state: checked.then(|| DONE_STATE.to_string()),
+        ..InputStory::named(name, row)
+    }
+}
+
+/// Add a line to a description, keeping paragraph breaks.
+fn append(description: &mut Option<String>, line: &str, after_blank: bool) {
+    match description {
+        Some(d) => {
+            d.push_str(if after_blank { "\n\n" } else { "\n" });
+            d.push_str(line.trim_end());
+        }
+        None => *description = Some(line.trim_end().to_string()),
+    }
+}
+
+/// Leading whitespace, with a tab as four spaces.
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+/// An ATX heading's level and text: `## Title ##` → `(2, "Title")`.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    if indentation(line) > 3 {
+        return None;
+    }
+    let text = line.trim();
+    let level = text.chars().take_while(|&c| c == '#').count();
+    let rest = &text[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim_end();
+    (!title.is_empty()).then_some((level, title))
+}
+
+/// A list item with a checkbox: whether it is checked, and its text.
+fn checklist_item(text: &str) -> Option<(bool, &str)> {
+    match list_item(text)? {
+        (Some(checked), name) => Some((checked, name)),
+        (None, _) => None,
+    }
+}
+
+/// A list item: whether it is checked (`None` without a checkbox), and its
+/// text.
+fn list_item(text: &str) -> Option<(Option<bool>, &str)> {
+    let rest = match text.strip_prefix(['-', '*', '+']) {
+        Some(rest) => rest,
+        None => {
+            let digits = text.chars().take_while(char::is_ascii_digit).count();
+            text[digits..]
+                .strip_prefix(['.', ')'])
+                .filter(|_| digits > 0)?
+        }
+    };
+    let rest = rest.strip_prefix([' ', '\t'])?.trim_start();
+    let (checked, name) = match rest.get(..3) {
+        Some("[ ]") => (Some(false), &rest[3..]),
+        Some("[x]" | "[X]") => (Some(true), &rest[3..]),
+        _ => (None, rest),
+    };
+    let name = name.trim();
+    (!name.is_empty()).then_some((checked, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn outline_stories_name_their_epic_under_its_objective() {
+        let mut file = tempfile::Builder::new().suffix(".md").tempfile().unwrap();
+        write!(
+            file,
+            "# Billing\n\
+             ## Backend\n\
+             - Charge cards\n\
+             # Search\n\
+             ## Backend\n\
+             ### Index invoices\n"
+        )
+        .unwrap();
+
+        let input = parse_outline(file.path()).unwrap();
+        assert_eq!(input.epics.len(), 2);
+        assert_eq!(input.epics[1].objective.as_deref(), Some("Search"));
+        let epics: Vec<_> = input.stories.iter().map(|s| s.epic.as_deref()).collect();
+        assert_eq!(epics, [Some("Billing / Backend"), Some("Search / Backend")]);
+    }
+}
@@ -1,38 +1,412 @@
+pub mod asana;
+pub mod azdo;
 pub mod csv;
+pub mod github;
+pub mod glob;
+pub mod jira;
+pub mod map;
+pub mod markdown;
 pub mod models;
+pub mod normalize;
+pub mod pivotal;
+pub mod trello;
 pub mod xlsx;
 pub mod yaml;
+pub mod zip;
 
 use anyhow::{Result, bail};
+use std::io::Read;
 use std::path::Path;
 
 use crate::cli::ResourceType;
-use models::InputFile;
+use map::ColumnMap;
+use models::{InputFile, is_skipped};
 
 /// Detect the file format from the extension and parse the file.
 ///
 /// YAML – type inferred from top-level keys; `resource_type` is ignored.
+/// JSON – the YAML layout, written as JSON.
 /// CSV  – `resource_type` is required.
+/// TSV  – as CSV, tab-delimited.
 /// XLSX – `resource_type` optional; auto-detected from sheet names otherwise.
-pub fn parse_file(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputFile> {
-    let ext = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| e.to_lowercase())
-        .unwrap_or_default();
+/// ZIP  – every data file inside, merged (see [`zip::parse`]).
+/// MD   – a markdown checklist (see [`markdown::parse`]).
+///
+/// `columns` applies to the tabular formats only.
+pub fn parse_file(
+    path: &Path,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    parse_file_as(path, None, resource_type, columns)
+}
+
+/// As [`parse_file`], but a `format` ("yaml", "json", "csv", "markdown" or
+/// "outline") overrides the extension.  A `path` of `-` reads standard
+/// input, which has no extension and so needs a `format`.
+pub fn parse_file_as(
+    path: &Path,
+    format: Option<&str>,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    let is_zip = format.is_none()
+        && path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("zip"));
+    let mut input = if is_zip {
+        zip::parse(path, resource_type, columns)?
+    } else {
+        parse_member(path, format, resource_type, columns)?
+    };
+    remove_skipped(&mut input);
+    Ok(input)
+}
+
+/// Whether `path` is `-`, which stands for standard input.
+pub fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Read a text input file, or standard input for `-`.
+fn read_to_string(path: &Path) -> Result<String> {
+    if is_stdin(path) {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| anyhow::anyhow!("Cannot read standard input: {e}"))?;
+        return Ok(content);
+    }
+    std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read file '{}': {}", path.display(), e))
+}
+
+/// [`parse_file_as`] for a single, non-archive file.
+fn parse_member(
+    path: &Path,
+    format: Option<&str>,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    if format.is_none() && is_stdin(path) {
+        bail!("Standard input has no file extension; pass --format yaml, json, csv or markdown");
+    }
+    let ext = match format {
+        Some(format) => format.to_string(),
+        None => path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .unwrap_or_default(),
+    };
 
     match ext.as_str() {
         "yaml" | "yml" => yaml::parse(path),
-        "csv" => {
+        "csv" | "tsv" => {
             let rt = resource_type.ok_or_else(|| {
                 anyhow::anyhow!(
-                    "--type is required for CSV files.\n  \
-                     Use: --type objective | epic | story"
+                    "--type is required for {} files.\n  \
+                     Use: --type objective | epic | story",
+                    ext.to_uppercase()
                 )
             })?;
-            csv::parse(path, rt)
+            let delimiter = if ext == "tsv" { b'\t' } else { b',' };
+            csv::parse(path, rt, delimiter, columns)
+        }
+        "xlsx" | "xls" => xlsx::parse(path, resource_type, columns),
+        "json" => yaml::parse_json(path),
+        "md" | "markdown" => markdown::parse(path),
+        "outline" => markdown::parse_outline(path),
+        other => {
+            bail!(
+                "Unsupported file extension '.{other}'.  Use .yaml, .json, .csv, .tsv, .xlsx, .md, or .zip"
+            )
         }
-        "xlsx" | "xls" => xlsx::parse(path, resource_type),
-        other => bail!("Unsupported file extension '.{other}'.  Use .yaml, .csv, or .xlsx"),
+    }
+}
+
+/// Drop skipped and commented-out resources (see [`models::is_skipped`]),
+/// counting them in `input.skipped`.
+fn remove_skipped(input: &mut InputFile) {
+    let count = |input: &InputFile| {
+        input.labels.len()
+            + input.iterations.len()
+            + input.objectives.len()
+            + input.epics.len()
+            + input.stories.len()
+    };
+    let before = count(input);
+    input.labels.retain(|l| !is_skipped(&l.name, l.skip));
+    input.iterations.retain(|i| !is_skipped(&i.name, i.skip));
+    input.objectives.retain(|o| !is_skipped(&o.name, o.skip));
+    input.epics.retain(|e| !is_skipped(&e.name, e.skip));
+    input.stories.retain(|s| !is_skipped(&s.name, s.skip));
+    input.skipped = before - count(input);
+}
+
+/// Interpret the tabular `skip` / `enabled` cells: a row is skipped when
+/// `skip` is truthy or `enabled` is falsy.  Blank cells mean "not set".
+pub fn skip_flag(skip: &str, enabled: &str) -> bool {
+    let truthy = |v: &str| {
+        matches!(
+            v.trim().to_lowercase().as_str(),
+            "true" | "yes" | "y" | "1" | "x"
+        )
+    };
+    let falsy = |v: &str| matches!(v.trim().to_lowercase().as_str(), "false" | "no" | "n" | "0");
+    truthy(skip) || falsy(enabled)
+}
+
+// ---------------------------------------------------------------------------
+// Column validation (CSV/TSV/XLSX)
+// ---------------------------------------------------------------------------
+
+/// Which column of a CSV/TSV/XLSX header row holds resource names.
+#[derive(Debug, Clone, Default)]
+pub enum NameColumn {
+    /// The `name` column (or an alias such as `title`).
+    #[default]
+    Name,
+    /// The column with this header, matched case-insensitively
+    /// (`--name-column`).
+    Header(String),
+    /// `name` if present, otherwise the first column (`--name-is-first`).
+    NameOrFirst,
+}
+
+/// How the header row of a CSV/TSV/XLSX file maps onto fields.
+#[derive(Debug, Default)]
+pub struct ColumnOptions {
+    pub name_column: NameColumn,
+    /// Header renames from `--column-map`.
+    pub column_map: ColumnMap,
+}
+
+/// Rename `headers` to the field names the row parsers look for: first by
+/// the column map, then the designated name column to `name`.  `source`
+/// names the file or sheet in messages.
+pub fn rename_headers(headers: &mut [String], columns: &ColumnOptions, source: &str) -> Result<()> {
+    columns.column_map.apply(headers, source)?;
+    designate_name_column(headers, &columns.name_column, source)
+}
+
+/// Rename the designated name column of `headers` to `name`, so that the
+/// row parsers find it.  `source` names the file or sheet in messages.
+fn designate_name_column(
+    headers: &mut [String],
+    name_column: &NameColumn,
+    source: &str,
+) -> Result<()> {
+    let existing = headers.iter().position(|h| canonical_column(h) == "name");
+    let idx = match name_column {
+        NameColumn::Name => return Ok(()),
+        NameColumn::NameOrFirst => match existing {
+            Some(_) => return Ok(()),
+            None if headers.is_empty() => bail!("{source} has no header row"),
+            None => 0,
+        },
+        NameColumn::Header(wanted) => {
+            let Some(idx) = headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(wanted.trim()))
+            else {
+                bail!(
+                    "{source} has no column '{wanted}' (--name-column).  Found: {}",
+                    headers.join(", ")
+                );
+            };
+            if let Some(other) = existing
+                && other != idx
+            {
+                bail!(
+                    "{source}: --name-column '{wanted}' conflicts with the '{}' column; \
+                     remove or rename one of them",
+                    headers[other]
+                );
+            }
+            idx
+        }
+    };
+    headers[idx] = "name".to_string();
+    Ok(())
+}
+
+/// Columns that must be present for every resource type.
+const REQUIRED_COLUMNS: &[&str] = &["name"];
+
+/// Accepted synonyms for field / column names → canonical name.  Must match
+/// the `#[serde(alias)]` attributes on the input models and CSV row structs.
+const COLUMN_ALIASES: &[(&str, &str)] = &[
+    ("title", "name"),
+    ("milestone", "objective"),
+    ("owner", "owners"),
+    ("assignee", "owners"),
+    ("assignees", "owners"),
+    ("follower", "followers"),
+    ("category", "categories"),
+    ("groups", "teams"),
+    ("group", "team"),
+    ("tags", "labels"),
+    ("story_type", "type"),
+    ("points", "estimate"),
+];
+
+/// Map a column alias to its canonical name; other names pass through.
+pub fn canonical_column(header: &str) -> &str {
+    COLUMN_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == header)
+        .map_or(header, |(_, canonical)| canonical)
+}
+
+/// Every column the tabular parsers understand, per resource type.
+fn known_columns(resource_type: &ResourceType) -> &'static [&'static str] {
+    match resource_type {
+        ResourceType::Objective => &[
+            "name",
+            "description",
+            "state",
+            "template",
+            "categories",
+            "skip",
+            "enabled",
+            "id",
+        ],
+        ResourceType::Epic => &[
+            "name",
+            "description",
+            "objective",
+            "owners",
+            "followers",
+            "teams",
+            "labels",
+            "state",
+            "start_date",
+            "deadline",
+            "template",
+            "position",
+            "capacity",
+            "health",
+            "links",
+            "preset",
+            "skip",
+            "enabled",
+            "id",
+        ],
+        ResourceType::Story => &[
+            "name",
+            "type",
+            "description",
+            "epic",
+            "owners",
+            "followers",
+            "requested_by",
+            "team",
+            "labels",
+            "estimate",
+            "due_date",
+            "workflow_state",
+            "iteration",
+            "custom_fields",
+            "project",
+            "acceptance_criteria",
+            "tasks",
+            "template",
+            "links",
+            "preset",
+            "skip",
+            "enabled",
+            "id",
+        ],
+        ResourceType::Label => &["name", "color", "description", "skip", "enabled", "id"],
+    }
+}
+
+/// Validate a header row before any data rows are read.
+///
+/// Fails when a required column is missing; returns a warning for each
+/// unrecognized column (likely a typo) since those are silently ignored.
+/// `source` names the file or sheet in messages.
+pub fn check_headers(
+    headers: &[String],
+    resource_type: &ResourceType,
+    source: &str,
+) -> Result<Vec<String>> {
+    let known = known_columns(resource_type);
+
+    let missing: Vec<&str> = REQUIRED_COLUMNS
+        .iter()
+        .copied()
+        .filter(|req| !headers.iter().any(|h| canonical_column(h) == *req))
+        .collect();
+    if !missing.is_empty() {
+        bail!(
+            "{source} is missing required column(s): {}.  Found: {}\n  \
+             Use --name-column <HEADER>, --name-is-first or --column-map to choose the name column",
+            missing.join(", "),
+            headers.join(", ")
+        );
+    }
+
+    Ok(headers
+        .iter()
+        .filter(|h| !h.is_empty() && !known.contains(&canonical_column(h)))
+        .map(|h| {
+            let lowered = h.trim().to_lowercase().replace(' ', "_");
+            let canonical = canonical_column(&lowered);
+            match known.iter().find(|k| **k == canonical) {
+                Some(k) => format!(
+                    "{source}: unrecognized column '{h}' will be ignored (did you mean '{k}'?)"
+                ),
+                None => format!("{source}: unrecognized column '{h}' will be ignored"),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn check_headers_rejects_missing_name() {
+        let err = check_headers(
+            &headers(&["description", "owners"]),
+            &ResourceType::Story,
+            "CSV 'x.csv'",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("CSV 'x.csv' is missing required column(s): name"));
+        assert!(err.contains("Found: description, owners"));
+    }
+
+    #[test]
+    fn check_headers_accepts_name_alias() {
+        let warnings =
+            check_headers(&headers(&["title", "owner"]), &ResourceType::Story, "CSV").unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn check_headers_warns_on_unknown_column() {
+        let warnings = check_headers(
+            &headers(&["name", "colour", "Due Date"]),
+            &ResourceType::Story,
+            "CSV 'x.csv'",
+        )
+        .unwrap();
+        assert_eq!(
+            warnings,
+            vec![
+                "CSV 'x.csv': unrecognized column 'colour' will be ignored".to_string(),
+                "CSV 'x.csv': unrecognized column 'Due Date' will be ignored (did you mean 'due_date'?)"
+                    .to_string(),
+            ]
+        );
     }
 }
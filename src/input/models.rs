@@ -1,86 +1,679 @@
-use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
 
 /// Top-level structure for a YAML manifest.  All sections are optional so a
 /// file may contain only epics, only stories, etc.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Serialize, Default)]
 pub struct InputFile {
-    #[serde(default)]
+    /// Labels to create before anything else, with their colors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<InputLabel>,
+    /// Iterations to create after labels, before objectives (YAML only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub iterations: Vec<InputIteration>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub objectives: Vec<InputObjective>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub epics: Vec<InputEpic>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub stories: Vec<InputStory>,
+    /// Non-fatal parse diagnostics (e.g. unrecognized columns).
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+    /// Resources dropped as skipped or commented out (see [`is_skipped`]).
+    #[serde(skip)]
+    pub skipped: usize,
+}
+
+impl InputFile {
+    /// Append `other`'s resources, warnings and skip count to this input.
+    pub fn merge(&mut self, other: InputFile) {
+        self.labels.extend(other.labels);
+        self.iterations.extend(other.iterations);
+        self.objectives.extend(other.objectives);
+        self.epics.extend(other.epics);
+        self.stories.extend(other.stories);
+        self.warnings.extend(other.warnings);
+        self.skipped += other.skipped;
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Objectives
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct InputObjective {
+    /// Shortcut ID.  Ignored by `create`; set by `--echo-created` and used
+    /// by `update` to find the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(alias = "title")]
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// "in progress" | "to do" | "done"
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
+    /// Path to a per-objective markdown template file.
+    /// If absent, the global --objective-template flag is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Category names – may be a YAML list or a comma-separated string.
+    /// Each must already exist in the workspace.
+    #[serde(
+        default,
+        alias = "category",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub categories: Vec<String>,
+    /// Key results, added after the objective is created (YAML only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_results: Vec<InputKeyResult>,
+    /// Leave this resource out of the run (also: a name starting with `#`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
+    /// Where the resource came from: the CSV/TSV/XLSX row number (the
+    /// header is row 1), or its 1-based position in a YAML list.
+    #[serde(skip)]
+    pub source_row: Option<usize>,
+}
+
+impl InputObjective {
+    /// An objective with nothing but a name, as the importers make from an
+    /// Azure DevOps Epic or an outline's `#` heading.
+    pub fn named(name: &str, source_row: usize) -> Self {
+        Self {
+            id: None,
+            name: name.to_string(),
+            description: None,
+            state: None,
+            template: None,
+            categories: Vec::new(),
+            key_results: Vec::new(),
+            skip: false,
+            source_row: Some(source_row),
+        }
+    }
+}
+
+/// A key result on an objective: a bare name or
+/// `{name, type, start, target}`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(from = "KeyResultSpec")]
+pub struct InputKeyResult {
+    pub name: String,
+    /// One of [`KEY_RESULT_TYPES`]; "numeric" when absent.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Starting value (numeric and percent key results).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<f64>,
+    /// Target value (numeric and percent key results).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+}
+
+/// Key result `type` values, as the API expects them.
+pub const KEY_RESULT_TYPES: &[&str] = &["numeric", "percent", "boolean"];
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyResultSpec {
+    Name(String),
+    Full {
+        name: String,
+        #[serde(rename = "type")]
+        kind: Option<String>,
+        #[serde(alias = "starting")]
+        start: Option<f64>,
+        target: Option<f64>,
+    },
+}
+
+impl From<KeyResultSpec> for InputKeyResult {
+    fn from(spec: KeyResultSpec) -> Self {
+        let (name, kind, start, target) = match spec {
+            KeyResultSpec::Name(name) => (name, None, None, None),
+            KeyResultSpec::Full {
+                name,
+                kind,
+                start,
+                target,
+            } => (name, kind, start, target),
+        };
+        Self {
+            name: name.trim().to_string(),
+            kind: kind
+                .map(|k| k.trim().to_lowercase())
+                .filter(|k| !k.is_empty()),
+            start,
+            target,
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Epics
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct InputEpic {
+    /// Shortcut ID.  Ignored by `create`; set by `--echo-created` and used
+    /// by `update` to find the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(alias = "title")]
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Objective name (resolved to ID) or a numeric ID string.
+    #[serde(alias = "milestone", skip_serializing_if = "Option::is_none")]
     pub objective: Option<String>,
     /// Owner names – may be a YAML list or a comma-separated string.
-    #[serde(default, deserialize_with = "de_string_or_list")]
+    #[serde(
+        default,
+        alias = "owner",
+        alias = "assignee",
+        alias = "assignees",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub owners: Vec<String>,
+    /// Follower names – may be a YAML list or a comma-separated string.
+    #[serde(
+        default,
+        alias = "follower",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub followers: Vec<String>,
     /// Team names – may be a YAML list or a comma-separated string.
-    #[serde(default, deserialize_with = "de_string_or_list")]
+    #[serde(
+        default,
+        alias = "groups",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub teams: Vec<String>,
     /// Label names – may be a YAML list or a comma-separated string.
-    #[serde(default, deserialize_with = "de_string_or_list")]
+    #[serde(
+        default,
+        alias = "tags",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub labels: Vec<String>,
     /// "in progress" | "to do" | "done"
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
     /// ISO 8601 date, e.g. "2024-01-15".
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date: Option<String>,
     /// ISO 8601 date.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deadline: Option<String>,
     /// Path to a per-epic markdown template file.
     /// If absent, the global --template flag is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub template: Option<String>,
+    /// Order among the epics of the same objective (lowest first).
+    /// Defaults to file order within the objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    /// Planned capacity in story points.  Informational: stored as a
+    /// `capacity:<n>` label and checked against the batch's stories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<i64>,
+    /// "on track" | "at risk" | "off track" | "no health" (hyphens and case
+    /// are ignored).  Set with a health update after the epic is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// External documents, listed under the description (epics have no
+    /// linked files in Shortcut).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<InputLink>,
+    /// Names of `--presets` bundles merged into owners, teams and labels.
+    #[serde(
+        default,
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub preset: Vec<String>,
+    /// Leave this resource out of the run (also: a name starting with `#`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
+    /// Where the resource came from: the CSV/TSV/XLSX row number (the
+    /// header is row 1), or its 1-based position in a YAML list.
+    #[serde(skip)]
+    pub source_row: Option<usize>,
+}
+
+impl InputEpic {
+    /// An epic with nothing but a name, as the `--from` importers make
+    /// from a Trello list, an Asana section or a Pivotal epic.
+    pub fn named(name: &str, source_row: usize) -> Self {
+        Self {
+            id: None,
+            name: name.to_string(),
+            description: None,
+            objective: None,
+            owners: Vec::new(),
+            followers: Vec::new(),
+            teams: Vec::new(),
+            labels: Vec::new(),
+            state: None,
+            start_date: None,
+            deadline: None,
+            template: None,
+            position: None,
+            capacity: None,
+            health: None,
+            links: Vec::new(),
+            preset: Vec::new(),
+            skip: false,
+            source_row: Some(source_row),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Stories
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct InputStory {
+    /// Shortcut ID.  Ignored by `create`; set by `--echo-created` and used
+    /// by `update` to find the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(alias = "title")]
     pub name: String,
     /// "feature" (default) | "bug" | "chore"
-    #[serde(rename = "type")]
+    #[serde(
+        rename = "type",
+        alias = "story_type",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub story_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    /// Epic name (resolved to ID) or a numeric ID string.
+    /// Epic name (resolved to ID), a numeric ID string, or `label:<name>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub epic: Option<String>,
     /// Owner names – may be a YAML list or a comma-separated string.
-    #[serde(default, deserialize_with = "de_string_or_list")]
+    #[serde(
+        default,
+        alias = "owner",
+        alias = "assignee",
+        alias = "assignees",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub owners: Vec<String>,
+    /// Follower names – may be a YAML list or a comma-separated string.
+    #[serde(
+        default,
+        alias = "follower",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub followers: Vec<String>,
+    /// Member who asked for the story; defaults to the token's owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_by: Option<String>,
     /// Single team/group name.
+    #[serde(alias = "group", skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
     /// Label names – may be a YAML list or a comma-separated string.
-    #[serde(default, deserialize_with = "de_string_or_list")]
+    #[serde(
+        default,
+        alias = "tags",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
     pub labels: Vec<String>,
     /// Story point estimate.
+    #[serde(alias = "points", skip_serializing_if = "Option::is_none")]
     pub estimate: Option<i64>,
     /// ISO 8601 date.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date: Option<String>,
-    /// Workflow state name (e.g. "Backlog", "In Progress").
+    /// Workflow state name (e.g. "Backlog", "In Progress") or a numeric ID string.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub workflow_state: Option<String>,
+    /// Iteration name (resolved to ID) or a numeric ID string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<String>,
+    /// Custom field name → value name, e.g. `Priority: High`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_fields: BTreeMap<String, String>,
+    /// Project name or numeric ID string (legacy workspaces only).
+    /// Falls back to the --project flag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// One criterion per line; each non-empty line becomes a task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acceptance_criteria: Option<String>,
+    /// Checklist tasks, added after the story (and any acceptance-criteria
+    /// tasks) is created.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<InputTask>,
+    /// Comments posted after the story is created, in order (YAML only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<InputComment>,
+    /// Path to a per-story markdown template file.
+    /// If absent, the global --story-template flag is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// External documents, attached as linked files after creation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<InputLink>,
+    /// Relationships to other stories, posted once every story in the run
+    /// has been created (YAML only).
+    #[serde(default, skip_serializing_if = "InputStoryLinks::is_empty")]
+    pub story_links: InputStoryLinks,
+    /// Names of `--presets` bundles merged into owners, team and labels.
+    #[serde(
+        default,
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub preset: Vec<String>,
+    /// Leave this resource out of the run (also: a name starting with `#`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
+    /// Where the resource came from: the CSV/TSV/XLSX row number (the
+    /// header is row 1), or its 1-based position in a YAML list.
+    #[serde(skip)]
+    pub source_row: Option<usize>,
+}
+
+impl InputStory {
+    /// A story with nothing but a name, for the importers to fill in.
+    pub fn named(name: &str, source_row: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            source_row: Some(source_row),
+            ..Default::default()
+        }
+    }
+}
+
+/// Custom field values from a `;`-separated CSV/TSV/XLSX cell of
+/// `Field=Value` pairs.  A pair without `=` keeps an empty value, which
+/// validation reports.
+pub fn split_custom_fields(cell: &str) -> BTreeMap<String, String> {
+    cell.split(';')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (field, value) = p.split_once('=').unwrap_or((p, ""));
+            (field.trim().to_string(), value.trim().to_string())
+        })
+        .collect()
+}
+
+/// A link to an external document: a bare URL or `{url, title}`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(from = "LinkSpec")]
+pub struct InputLink {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl InputLink {
+    /// Links from a `;`-separated CSV/TSV/XLSX cell of bare URLs.
+    pub fn split(cell: &str) -> Vec<Self> {
+        cell.split(';')
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .map(|u| Self {
+                url: u.to_string(),
+                title: None,
+            })
+            .collect()
+    }
+
+    /// The title, falling back to the URL.
+    pub fn name(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.url)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LinkSpec {
+    Url(String),
+    Full { url: String, title: Option<String> },
+}
+
+impl From<LinkSpec> for InputLink {
+    fn from(spec: LinkSpec) -> Self {
+        let (url, title) = match spec {
+            LinkSpec::Url(url) => (url, None),
+            LinkSpec::Full { url, title } => (url, title),
+        };
+        Self {
+            url: url.trim().to_string(),
+            title: title
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+        }
+    }
+}
+
+/// Stories this story `blocks`, `duplicates` or `relates_to`, each a list
+/// of names of stories in the same file or numeric ID strings.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct InputStoryLinks {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<String>,
+    #[serde(default, alias = "relates to", skip_serializing_if = "Vec::is_empty")]
+    pub relates_to: Vec<String>,
+}
+
+impl InputStoryLinks {
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty() && self.duplicates.is_empty() && self.relates_to.is_empty()
+    }
+
+    /// Every link as `(verb, target)`, with the verb as the API spells it.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.blocks
+            .iter()
+            .map(|t| ("blocks", t.as_str()))
+            .chain(self.duplicates.iter().map(|t| ("duplicates", t.as_str())))
+            .chain(self.relates_to.iter().map(|t| ("relates to", t.as_str())))
+    }
+}
+
+/// A checklist task on a story: a bare description or
+/// `{name, owner, complete}`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(from = "TaskSpec")]
+pub struct InputTask {
+    pub name: String,
+    /// Member name, email or mention name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub complete: bool,
+}
+
+impl InputTask {
+    /// Open, unowned tasks from a `;`-separated CSV/TSV/XLSX cell.
+    pub fn split(cell: &str) -> Vec<Self> {
+        cell.split(';')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| Self {
+                name: t.to_string(),
+                owner: None,
+                complete: false,
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TaskSpec {
+    Name(String),
+    Full {
+        #[serde(alias = "description")]
+        name: String,
+        owner: Option<String>,
+        #[serde(default)]
+        complete: bool,
+    },
+}
+
+impl From<TaskSpec> for InputTask {
+    fn from(spec: TaskSpec) -> Self {
+        let (name, owner, complete) = match spec {
+            TaskSpec::Name(name) => (name, None, false),
+            TaskSpec::Full {
+                name,
+                owner,
+                complete,
+            } => (name, owner, complete),
+        };
+        Self {
+            name: name.trim().to_string(),
+            owner: owner
+                .map(|o| o.trim().to_string())
+                .filter(|o| !o.is_empty()),
+            complete,
+        }
+    }
+}
+
+/// A comment on a story: its text, or `{text, author}`.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(from = "CommentSpec")]
+pub struct InputComment {
+    pub text: String,
+    /// Member name, email or mention name; defaults to the token's owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CommentSpec {
+    Text(String),
+    Full {
+        text: String,
+        author: Option<String>,
+    },
+}
+
+impl From<CommentSpec> for InputComment {
+    fn from(spec: CommentSpec) -> Self {
+        let (text, author) = match spec {
+            CommentSpec::Text(text) => (text, None),
+            CommentSpec::Full { text, author } => (text, author),
+        };
+        Self {
+            text: text.trim().to_string(),
+            author: author
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty()),
+        }
+    }
+}
+
+/// Field value meaning "clear this field" rather than "leave it unchanged"
+/// (which is what leaving the field out means).  Supported by the date
+/// fields: `start_date`, `deadline` and `due_date`.
+pub const CLEAR: &str = "__clear__";
+
+/// Epic `health` values, as written in input and as the API expects them.
+pub const HEALTH_VALUES: &[(&str, &str)] = &[
+    ("on track", "On Track"),
+    ("at risk", "At Risk"),
+    ("off track", "Off Track"),
+    ("no health", "No Health"),
+];
+
+/// The API status for an epic `health` value such as `at-risk` or
+/// `On Track`; `None` when it is not one of [`HEALTH_VALUES`].
+pub fn health_status(value: &str) -> Option<&'static str> {
+    let key = value.trim().to_lowercase().replace(['-', '_'], " ");
+    HEALTH_VALUES
+        .iter()
+        .find(|(input, _)| *input == key)
+        .map(|&(_, status)| status)
+}
+
+/// Whether a resource is left out of the run: `skip` is set or the name is
+/// commented out with a leading `#`.
+pub fn is_skipped(name: &str, skip: bool) -> bool {
+    skip || name.trim_start().starts_with('#')
+}
+
+// ---------------------------------------------------------------------------
+// Iterations
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InputIteration {
+    /// Shortcut ID.  Ignored by `create`; set by `--echo-created`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ISO 8601 date; required.
+    #[serde(default)]
+    pub start_date: String,
+    /// ISO 8601 date; required, and not before `start_date`.
+    #[serde(default)]
+    pub end_date: String,
+    /// Team names – may be a YAML list or a comma-separated string.
+    #[serde(
+        default,
+        alias = "groups",
+        deserialize_with = "de_string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub teams: Vec<String>,
+    /// Leave this resource out of the run (also: a name starting with `#`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
+    /// Its 1-based position in the YAML list.
+    #[serde(skip)]
+    pub source_row: Option<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// Labels
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InputLabel {
+    /// Shortcut ID.  Ignored by `create`; set by `--echo-created`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    /// Hex color, e.g. "#3b82f6".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Leave this resource out of the run (also: a name starting with `#`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip: bool,
+    /// Where the resource came from: the CSV/TSV/XLSX row number (the
+    /// header is row 1), or its 1-based position in a YAML list.
+    #[serde(skip)]
+    pub source_row: Option<usize>,
 }
 
 // ---------------------------------------------------------------------------
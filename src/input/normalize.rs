@@ -0,0 +1,84 @@
+use super::models::{InputFile, InputStory};
+
+/// Trim and collapse internal whitespace in resource names and in the
+/// name references (objective, epic, iteration, owners, followers,
+/// requested_by, teams) that point at them.
+///
+/// Returns one note per value that changed so the caller can tell the user
+/// which source cells to clean up.
+pub fn normalize_names(input: &mut InputFile) -> Vec<String> {
+    let mut notes = Vec::new();
+
+    for iteration in &mut input.iterations {
+        normalize(&mut iteration.name, "Iteration name", &mut notes);
+    }
+
+    for obj in &mut input.objectives {
+        normalize(&mut obj.name, "Objective name", &mut notes);
+    }
+
+    for epic in &mut input.epics {
+        normalize(&mut epic.name, "Epic name", &mut notes);
+        if let Some(obj) = &mut epic.objective {
+            normalize(obj, &format!("Epic '{}' objective", epic.name), &mut notes);
+        }
+        for owner in &mut epic.owners {
+            normalize(owner, &format!("Epic '{}' owner", epic.name), &mut notes);
+        }
+        for follower in &mut epic.followers {
+            normalize(
+                follower,
+                &format!("Epic '{}' follower", epic.name),
+                &mut notes,
+            );
+        }
+        for team in &mut epic.teams {
+            normalize(team, &format!("Epic '{}' team", epic.name), &mut notes);
+        }
+    }
+
+    for story in &mut input.stories {
+        normalize_story(story, &mut notes);
+    }
+
+    notes
+}
+
+/// [`normalize_names`] for a single story (used when streaming rows).
+pub fn normalize_story(story: &mut InputStory, notes: &mut Vec<String>) {
+    normalize(&mut story.name, "Story name", notes);
+    if let Some(epic) = &mut story.epic {
+        normalize(epic, &format!("Story '{}' epic", story.name), notes);
+    }
+    for owner in &mut story.owners {
+        normalize(owner, &format!("Story '{}' owner", story.name), notes);
+    }
+    for follower in &mut story.followers {
+        normalize(follower, &format!("Story '{}' follower", story.name), notes);
+    }
+    if let Some(requester) = &mut story.requested_by {
+        normalize(
+            requester,
+            &format!("Story '{}' requester", story.name),
+            notes,
+        );
+    }
+    if let Some(team) = &mut story.team {
+        normalize(team, &format!("Story '{}' team", story.name), notes);
+    }
+    if let Some(iteration) = &mut story.iteration {
+        normalize(
+            iteration,
+            &format!("Story '{}' iteration", story.name),
+            notes,
+        );
+    }
+}
+
+fn normalize(value: &mut String, what: &str, notes: &mut Vec<String>) {
+    let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed != *value {
+        notes.push(format!("{what} '{value}' normalized to '{collapsed}'"));
+        *value = collapsed;
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::{Result, bail};
+use std::collections::HashSet;
+use std::path::Path;
+
+use super::csv::Columns;
+use super::models::{InputEpic, InputFile, InputLink, InputStory};
+
+/// Pivotal Tracker `Current State` → workflow state, named as in
+/// Shortcut's default workflow.  Workspaces with other state names
+/// translate these with the `workflow_state` section of `--map-file`.
+const STATES: &[(&str, &str)] = &[
+    ("unscheduled", "Backlog"),
+    ("unstarted", "To Do"),
+    ("planned", "To Do"),
+    ("started", "In Progress"),
+    ("rejected", "In Progress"),
+    ("finished", "In Review"),
+    ("delivered", "In Review"),
+    ("accepted", "Done"),
+];
+
+/// Pivotal Tracker story type → Shortcut story type.  Shortcut has no
+/// releases; they become chores.
+const STORY_TYPES: &[(&str, &str)] = &[
+    ("feature", "feature"),
+    ("bug", "bug"),
+    ("chore", "chore"),
+    ("release", "chore"),
+];
+
+/// Parse a Pivotal Tracker CSV export (`--format pivotal`, or `--from
+/// pivotal`).
+///
+/// - `Title` → `name`, `Description` → `description`
+/// - `Type` (or `Story Type`) → `type` (see [`STORY_TYPES`])
+/// - `Current State` → `workflow_state` (see [`STATES`])
+/// - `Estimate` → `estimate`
+/// - every `Owned By` column → `owners`; `Requested By` → `requested_by`
+/// - `Labels` (comma-separated) → `labels`
+/// - `Epic` → `epic`, with one epic per distinct value, in order of
+///   appearance
+/// - `URL` → a linked file titled `Pivotal #<Id>`
+///
+/// An unknown type or state is an error naming the row.  Other columns
+/// are ignored.  `source_row` is the spreadsheet row.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let mut reader = super::csv::open(path, b',')?;
+    let headers = reader
+        .headers()
+        .map_err(|e| anyhow::anyhow!("CSV header parse error: {}", e))?;
+    let columns = Columns::new(headers);
+    if !columns.has("title") {
+        bail!(
+            "'{}' has no Title column; is it a Pivotal Tracker CSV export?",
+            path.display()
+        );
+    }
+
+    let mut input = InputFile::default();
+    let mut epics: HashSet<String> = HashSet::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 2;
+        let record = record.map_err(|e| anyhow::anyhow!("CSV row {row} parse error: {e}"))?;
+        let Some(title) = columns.first(&record, &["title"]) else {
+            continue;
+        };
+        let story_type = match columns.first(&record, &["type", "story type"]) {
+            Some(t) => match lookup(STORY_TYPES, &t) {
+                Some(shortcut) => Some(shortcut.to_string()),
+                None => bail!("Row {row}: unknown Pivotal story type '{t}'"),
+            },
+            None => None,
+        };
+        let workflow_state = match columns.first(&record, &["current state"]) {
+            Some(s) => match lookup(STATES, &s) {
+                Some(shortcut) => Some(shortcut.to_string()),
+                None => bail!("Row {row}: unknown Pivotal state '{s}'"),
+            },
+            None => None,
+        };
+        let estimate = match columns.first(&record, &["estimate"]) {
+            Some(points) => match points.parse::<f64>() {
+                Ok(p) if p >= 0.0 => Some(p.round() as i64),
+                // Pivotal writes -1 for an unestimated story.
+                Ok(_) => None,
+                Err(_) => bail!("Row {row}: Estimate '{points}' is not a number"),
+            },
+            None => None,
+        };
+        let epic = columns.first(&record, &["epic"]);
+        if let Some(epic) = &epic
+            && epics.insert(epic.clone())
+        {
+            input.epics.push(InputEpic::named(epic, row));
+        }
+        let link = columns.first(&record, &["url"]).map(|url| InputLink {
+            url,
+            title: Some(match columns.first(&record, &["id"]) {
+                Some(id) => format!("Pivotal #{id}"),
+                None => "Pivotal story".into(),
+            }),
+        });
+
+        input.stories.push(InputStory {
+            story_type,
+            description: columns.first(&record, &["description"]),
+            epic,
+            owners: columns.all(&record, "owned by"),
+            requested_by: columns.first(&record, &["requested by"]),
+            labels: columns
+                .first(&record, &["labels"])
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string)
+                .collect(),
+            estimate,
+            workflow_state,
+            links: link.into_iter().collect(),
+            ..InputStory::named(&title, row)
+        });
+    }
+    Ok(input)
+}
+
+fn lookup(table: &[(&str, &'static str)], value: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(pivotal, _)| pivotal.eq_ignore_ascii_case(value.trim()))
+        .map(|&(_, shortcut)| shortcut)
+}
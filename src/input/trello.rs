@@ -0,0 +1,140 @@
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::models::{InputEpic, InputFile, InputLink, InputStory};
+
+/// A Trello board as exported from *Menu → Print, export and share →
+/// Export as JSON*.  Only the fields that map onto epics and stories are
+/// read.
+#[derive(Deserialize)]
+struct Board {
+    #[serde(default)]
+    lists: Vec<List>,
+    #[serde(default)]
+    cards: Vec<Card>,
+    #[serde(default)]
+    members: Vec<Member>,
+}
+
+#[derive(Deserialize)]
+struct List {
+    id: String,
+    name: String,
+    #[serde(default)]
+    closed: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Card {
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    labels: Vec<Label>,
+    #[serde(default)]
+    id_members: Vec<String>,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    short_url: Option<String>,
+    #[serde(default)]
+    closed: bool,
+}
+
+/// Trello labels may be unnamed, in which case only the color shows.
+#[derive(Deserialize)]
+struct Label {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Member {
+    id: String,
+    username: String,
+}
+
+/// Parse a Trello board JSON export into epics and stories
+/// (`--format trello`, or `--from trello`).
+///
+/// - each list → an epic of the same name
+/// - each card → a story in its list's epic: `name` → `name`, `desc` →
+///   `description`
+/// - card labels → `labels`, by name (an unnamed label by its color)
+/// - card members → `owners`, by username (translate usernames to
+///   Shortcut names with the `owners` section of `--map-file`)
+/// - `due` → `due_date`
+/// - `shortUrl` → a linked file titled `Trello card`
+///
+/// Archived lists and cards (and the cards of archived lists) are left out
+/// and counted as skipped.  An epic's `source_row` is its list's 1-based
+/// position in `lists`, a story's its card's in `cards`.
+pub fn parse(path: &Path) -> Result<InputFile> {
+    let content = super::read_to_string(path)?;
+    let board: Board = serde_json::from_str(&content).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to parse Trello board JSON '{}': {}",
+            path.display(),
+            e
+        )
+    })?;
+
+    let usernames: HashMap<&str, &str> = board
+        .members
+        .iter()
+        .map(|m| (m.id.as_str(), m.username.as_str()))
+        .collect();
+    let mut lists: HashMap<&str, &str> = HashMap::new();
+    let mut input = InputFile::default();
+    for (i, list) in board.lists.iter().enumerate() {
+        if list.closed {
+            input.skipped += 1;
+            continue;
+        }
+        lists.insert(&list.id, &list.name);
+        input.epics.push(InputEpic::named(list.name.trim(), i + 1));
+    }
+
+    for (i, card) in board.cards.into_iter().enumerate() {
+        let list = lists.get(card.id_list.as_str());
+        if card.closed || list.is_none() {
+            input.skipped += 1;
+            continue;
+        }
+        input.stories.push(InputStory {
+            description: Some(card.desc).filter(|d| !d.trim().is_empty()),
+            epic: list.map(|l| l.trim().to_string()),
+            owners: card
+                .id_members
+                .iter()
+                .filter_map(|id| usernames.get(id.as_str()))
+                .map(|u| u.to_string())
+                .collect(),
+            labels: card
+                .labels
+                .into_iter()
+                .filter_map(|l| match l.name.trim() {
+                    "" => l.color,
+                    name => Some(name.to_string()),
+                })
+                .collect(),
+            due_date: card.due,
+            links: card
+                .short_url
+                .map(|url| InputLink {
+                    url,
+                    title: Some("Trello card".into()),
+                })
+                .into_iter()
+                .collect(),
+            ..InputStory::named(card.name.trim(), i + 1)
+        });
+    }
+    Ok(input)
+}
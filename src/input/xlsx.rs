@@ -1,20 +1,45 @@
 use anyhow::{Result, anyhow, bail};
 use calamine::{DataType, Range, Reader, Xlsx, open_workbook};
 use std::collections::HashMap;
+use std::io::{Read, Seek};
 use std::path::Path;
 
-use super::models::{InputEpic, InputFile, InputObjective, InputStory};
+use super::models::{
+    InputEpic, InputFile, InputLabel, InputLink, InputObjective, InputStory, InputTask,
+    split_custom_fields,
+};
+use super::{ColumnOptions, canonical_column, check_headers, rename_headers, skip_flag};
 use crate::cli::ResourceType;
 
 /// Parse an Excel (.xlsx) file.
 ///
 /// If `--type` is provided, the **first** sheet is used.
-/// Otherwise, sheets whose names contain "objective", "epic", or "stor"
+/// Otherwise, sheets whose names contain "objective", "epic", "stor" or
+/// "label"
 /// (case-insensitive) are parsed automatically.
-pub fn parse(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputFile> {
-    let mut workbook: Xlsx<_> = open_workbook(path)
+pub fn parse(
+    path: &Path,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    let workbook: Xlsx<_> = open_workbook(path)
         .map_err(|e| anyhow!("Cannot open Excel file '{}': {}", path.display(), e))?;
+    parse_workbook(
+        workbook,
+        &path.display().to_string(),
+        resource_type,
+        columns,
+    )
+}
 
+/// [`parse`] for an already-open workbook; `name` identifies it in
+/// messages.
+pub fn parse_workbook<RS: Read + Seek>(
+    mut workbook: Xlsx<RS>,
+    name: &str,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
     let sheet_names: Vec<String> = workbook.sheet_names().to_vec();
 
     if let Some(rt) = resource_type {
@@ -23,20 +48,26 @@ pub fn parse(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputF
             .ok_or_else(|| anyhow!("Excel file has no sheets"))?
             .clone();
         let range = get_range(&mut workbook, &sheet)?;
-        match rt {
-            ResourceType::Objective => Ok(InputFile {
-                objectives: objectives_from_range(&range)?,
+        let (hdr, warnings) = sheet_headers(&range, rt, &sheet, columns)?;
+        let input = match rt {
+            ResourceType::Objective => InputFile {
+                objectives: objectives_from_range(&range, &hdr)?,
                 ..Default::default()
-            }),
-            ResourceType::Epic => Ok(InputFile {
-                epics: epics_from_range(&range)?,
+            },
+            ResourceType::Epic => InputFile {
+                epics: epics_from_range(&range, &hdr)?,
                 ..Default::default()
-            }),
-            ResourceType::Story => Ok(InputFile {
-                stories: stories_from_range(&range)?,
+            },
+            ResourceType::Story => InputFile {
+                stories: stories_from_range(&range, &hdr)?,
                 ..Default::default()
-            }),
-        }
+            },
+            ResourceType::Label => InputFile {
+                labels: labels_from_range(&range, &hdr)?,
+                ..Default::default()
+            },
+        };
+        Ok(InputFile { warnings, ..input })
     } else {
         let mut result = InputFile::default();
         let mut matched = false;
@@ -46,13 +77,25 @@ pub fn parse(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputF
             let range = get_range(&mut workbook, sheet)?;
 
             if lower.contains("objective") {
-                result.objectives = objectives_from_range(&range)?;
+                let (hdr, warnings) =
+                    sheet_headers(&range, &ResourceType::Objective, sheet, columns)?;
+                result.warnings.extend(warnings);
+                result.objectives = objectives_from_range(&range, &hdr)?;
                 matched = true;
             } else if lower.contains("epic") {
-                result.epics = epics_from_range(&range)?;
+                let (hdr, warnings) = sheet_headers(&range, &ResourceType::Epic, sheet, columns)?;
+                result.warnings.extend(warnings);
+                result.epics = epics_from_range(&range, &hdr)?;
                 matched = true;
             } else if lower.contains("stor") {
-                result.stories = stories_from_range(&range)?;
+                let (hdr, warnings) = sheet_headers(&range, &ResourceType::Story, sheet, columns)?;
+                result.warnings.extend(warnings);
+                result.stories = stories_from_range(&range, &hdr)?;
+                matched = true;
+            } else if lower.contains("label") {
+                let (hdr, warnings) = sheet_headers(&range, &ResourceType::Label, sheet, columns)?;
+                result.warnings.extend(warnings);
+                result.labels = labels_from_range(&range, &hdr)?;
                 matched = true;
             }
         }
@@ -60,9 +103,9 @@ pub fn parse(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputF
         if !matched {
             bail!(
                 "No recognized sheet names in '{}'. \
-                 Name sheets 'Objectives', 'Epics', or 'Stories', \
+                 Name sheets 'Objectives', 'Epics', 'Stories' or 'Labels', \
                  or supply --type to use the first sheet.",
-                path.display()
+                name
             );
         }
         Ok(result)
@@ -73,26 +116,47 @@ pub fn parse(path: &Path, resource_type: Option<&ResourceType>) -> Result<InputF
 // Internal helpers
 // ---------------------------------------------------------------------------
 
-fn get_range(
-    workbook: &mut Xlsx<std::io::BufReader<std::fs::File>>,
-    name: &str,
-) -> Result<Range<DataType>> {
+fn get_range<RS: Read + Seek>(workbook: &mut Xlsx<RS>, name: &str) -> Result<Range<DataType>> {
     workbook
         .worksheet_range(name)
         .map_err(|e| anyhow!("Error reading sheet '{}': {}", name, e))
 }
 
-/// Build a header-name → column-index map from the first row of a range.
-fn headers(range: &Range<DataType>) -> HashMap<String, usize> {
-    let mut map = HashMap::new();
-    if let Some(row) = range.rows().next() {
-        for (i, cell) in row.iter().enumerate() {
-            if let DataType::String(s) = cell {
-                map.insert(s.trim().to_lowercase(), i);
-            }
-        }
-    }
-    map
+/// Read and validate a sheet's header row (see [`check_headers`]),
+/// returning a header-name → column-index map and the header warnings.
+///
+/// Headers are matched case-insensitively; column aliases (e.g.
+/// `assignees`) are stored under their canonical name, and headers are
+/// renamed first (see [`rename_headers`]).
+fn sheet_headers(
+    range: &Range<DataType>,
+    resource_type: &ResourceType,
+    sheet: &str,
+    columns: &ColumnOptions,
+) -> Result<(HashMap<String, usize>, Vec<String>)> {
+    let source = format!("Sheet '{sheet}'");
+    // Non-text cells become empty names so indices stay aligned.
+    let mut names: Vec<String> = range
+        .rows()
+        .next()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    DataType::String(s) => s.trim().to_lowercase(),
+                    _ => String::new(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    rename_headers(&mut names, columns, &source)?;
+    let warnings = check_headers(&names, resource_type, &source)?;
+    let map = names
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| !n.is_empty())
+        .map(|(i, n)| (canonical_column(n).to_string(), i))
+        .collect();
+    Ok((map, warnings))
 }
 
 fn cell_str(row: &[DataType], idx: usize) -> String {
@@ -130,13 +194,54 @@ fn split_semi(s: &str) -> Vec<String> {
 // Sheet → model converters
 // ---------------------------------------------------------------------------
 
-fn objectives_from_range(range: &Range<DataType>) -> Result<Vec<InputObjective>> {
-    let hdr = headers(range);
+fn labels_from_range(
+    range: &Range<DataType>,
+    hdr: &HashMap<String, usize>,
+) -> Result<Vec<InputLabel>> {
     let name_col = hdr
         .get("name")
         .copied()
         .ok_or_else(|| anyhow!("Missing 'name' column"))?;
     let mut out = Vec::new();
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
+
+    for (i, row) in range.rows().enumerate() {
+        if i == 0 {
+            continue; // skip header
+        }
+        let name = cell_str(row, name_col);
+        if name.is_empty() {
+            continue;
+        }
+        out.push(InputLabel {
+            id: hdr.get("id").and_then(|&c| opt_cell_i64(row, c)),
+            name,
+            color: hdr.get("color").and_then(|&c| opt_cell(row, c)),
+            description: hdr.get("description").and_then(|&c| opt_cell(row, c)),
+            skip: skip_flag(
+                &hdr.get("skip")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+                &hdr.get("enabled")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+            ),
+            source_row: Some(first_row + i + 1),
+        });
+    }
+    Ok(out)
+}
+
+fn objectives_from_range(
+    range: &Range<DataType>,
+    hdr: &HashMap<String, usize>,
+) -> Result<Vec<InputObjective>> {
+    let name_col = hdr
+        .get("name")
+        .copied()
+        .ok_or_else(|| anyhow!("Missing 'name' column"))?;
+    let mut out = Vec::new();
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
 
     for (i, row) in range.rows().enumerate() {
         if i == 0 {
@@ -147,21 +252,40 @@ fn objectives_from_range(range: &Range<DataType>) -> Result<Vec<InputObjective>>
             continue;
         }
         out.push(InputObjective {
+            id: hdr.get("id").and_then(|&c| opt_cell_i64(row, c)),
             name,
             description: hdr.get("description").and_then(|&c| opt_cell(row, c)),
             state: hdr.get("state").and_then(|&c| opt_cell(row, c)),
+            template: hdr.get("template").and_then(|&c| opt_cell(row, c)),
+            categories: hdr
+                .get("categories")
+                .map(|&c| split_semi(&cell_str(row, c)))
+                .unwrap_or_default(),
+            key_results: Vec::new(),
+            skip: skip_flag(
+                &hdr.get("skip")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+                &hdr.get("enabled")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+            ),
+            source_row: Some(first_row + i + 1),
         });
     }
     Ok(out)
 }
 
-fn epics_from_range(range: &Range<DataType>) -> Result<Vec<InputEpic>> {
-    let hdr = headers(range);
+fn epics_from_range(
+    range: &Range<DataType>,
+    hdr: &HashMap<String, usize>,
+) -> Result<Vec<InputEpic>> {
     let name_col = hdr
         .get("name")
         .copied()
         .ok_or_else(|| anyhow!("Missing 'name' column"))?;
     let mut out = Vec::new();
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
 
     for (i, row) in range.rows().enumerate() {
         if i == 0 {
@@ -172,6 +296,7 @@ fn epics_from_range(range: &Range<DataType>) -> Result<Vec<InputEpic>> {
             continue;
         }
         out.push(InputEpic {
+            id: hdr.get("id").and_then(|&c| opt_cell_i64(row, c)),
             name,
             description: hdr.get("description").and_then(|&c| opt_cell(row, c)),
             objective: hdr.get("objective").and_then(|&c| opt_cell(row, c)),
@@ -179,6 +304,10 @@ fn epics_from_range(range: &Range<DataType>) -> Result<Vec<InputEpic>> {
                 .get("owners")
                 .map(|&c| split_semi(&cell_str(row, c)))
                 .unwrap_or_default(),
+            followers: hdr
+                .get("followers")
+                .map(|&c| split_semi(&cell_str(row, c)))
+                .unwrap_or_default(),
             teams: hdr
                 .get("teams")
                 .map(|&c| split_semi(&cell_str(row, c)))
@@ -191,18 +320,41 @@ fn epics_from_range(range: &Range<DataType>) -> Result<Vec<InputEpic>> {
             start_date: hdr.get("start_date").and_then(|&c| opt_cell(row, c)),
             deadline: hdr.get("deadline").and_then(|&c| opt_cell(row, c)),
             template: hdr.get("template").and_then(|&c| opt_cell(row, c)),
+            position: hdr.get("position").and_then(|&c| opt_cell_i64(row, c)),
+            capacity: hdr.get("capacity").and_then(|&c| opt_cell_i64(row, c)),
+            health: hdr.get("health").and_then(|&c| opt_cell(row, c)),
+            links: hdr
+                .get("links")
+                .map(|&c| InputLink::split(&cell_str(row, c)))
+                .unwrap_or_default(),
+            preset: hdr
+                .get("preset")
+                .map(|&c| split_semi(&cell_str(row, c)))
+                .unwrap_or_default(),
+            skip: skip_flag(
+                &hdr.get("skip")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+                &hdr.get("enabled")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+            ),
+            source_row: Some(first_row + i + 1),
         });
     }
     Ok(out)
 }
 
-fn stories_from_range(range: &Range<DataType>) -> Result<Vec<InputStory>> {
-    let hdr = headers(range);
+fn stories_from_range(
+    range: &Range<DataType>,
+    hdr: &HashMap<String, usize>,
+) -> Result<Vec<InputStory>> {
     let name_col = hdr
         .get("name")
         .copied()
         .ok_or_else(|| anyhow!("Missing 'name' column"))?;
     let mut out = Vec::new();
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
 
     for (i, row) in range.rows().enumerate() {
         if i == 0 {
@@ -213,6 +365,7 @@ fn stories_from_range(range: &Range<DataType>) -> Result<Vec<InputStory>> {
             continue;
         }
         out.push(InputStory {
+            id: hdr.get("id").and_then(|&c| opt_cell_i64(row, c)),
             name,
             story_type: hdr.get("type").and_then(|&c| opt_cell(row, c)),
             description: hdr.get("description").and_then(|&c| opt_cell(row, c)),
@@ -221,6 +374,11 @@ fn stories_from_range(range: &Range<DataType>) -> Result<Vec<InputStory>> {
                 .get("owners")
                 .map(|&c| split_semi(&cell_str(row, c)))
                 .unwrap_or_default(),
+            followers: hdr
+                .get("followers")
+                .map(|&c| split_semi(&cell_str(row, c)))
+                .unwrap_or_default(),
+            requested_by: hdr.get("requested_by").and_then(|&c| opt_cell(row, c)),
             team: hdr.get("team").and_then(|&c| opt_cell(row, c)),
             labels: hdr
                 .get("labels")
@@ -229,6 +387,39 @@ fn stories_from_range(range: &Range<DataType>) -> Result<Vec<InputStory>> {
             estimate: hdr.get("estimate").and_then(|&c| opt_cell_i64(row, c)),
             due_date: hdr.get("due_date").and_then(|&c| opt_cell(row, c)),
             workflow_state: hdr.get("workflow_state").and_then(|&c| opt_cell(row, c)),
+            iteration: hdr.get("iteration").and_then(|&c| opt_cell(row, c)),
+            custom_fields: hdr
+                .get("custom_fields")
+                .map(|&c| split_custom_fields(&cell_str(row, c)))
+                .unwrap_or_default(),
+            project: hdr.get("project").and_then(|&c| opt_cell(row, c)),
+            acceptance_criteria: hdr
+                .get("acceptance_criteria")
+                .and_then(|&c| opt_cell(row, c)),
+            tasks: hdr
+                .get("tasks")
+                .map(|&c| InputTask::split(&cell_str(row, c)))
+                .unwrap_or_default(),
+            comments: Vec::new(),
+            template: hdr.get("template").and_then(|&c| opt_cell(row, c)),
+            links: hdr
+                .get("links")
+                .map(|&c| InputLink::split(&cell_str(row, c)))
+                .unwrap_or_default(),
+            story_links: Default::default(),
+            preset: hdr
+                .get("preset")
+                .map(|&c| split_semi(&cell_str(row, c)))
+                .unwrap_or_default(),
+            skip: skip_flag(
+                &hdr.get("skip")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+                &hdr.get("enabled")
+                    .map(|&c| cell_str(row, c))
+                    .unwrap_or_default(),
+            ),
+            source_row: Some(first_row + i + 1),
         });
     }
     Ok(out)
@@ -1,11 +1,141 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
+use serde::Deserialize;
 use std::path::Path;
 
-use super::models::InputFile;
+use super::models::{InputEpic, InputFile, InputIteration, InputLabel, InputObjective, InputStory};
+use crate::resolver::qualified_epic_key;
 
+/// The YAML layout of an [`InputFile`], where an objective may also list
+/// its epics and an epic its stories.  [`parse`] and [`parse_json`]
+/// flatten it.
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    labels: Vec<InputLabel>,
+    #[serde(default)]
+    iterations: Vec<InputIteration>,
+    #[serde(default)]
+    objectives: Vec<NestedObjective>,
+    #[serde(default)]
+    epics: Vec<NestedEpic>,
+    #[serde(default)]
+    stories: Vec<InputStory>,
+}
+
+/// An objective with the epics declared under it.
+#[derive(Deserialize)]
+struct NestedObjective {
+    #[serde(flatten)]
+    objective: InputObjective,
+    #[serde(default)]
+    epics: Vec<NestedEpic>,
+}
+
+/// An epic with the stories declared under it.
+#[derive(Deserialize)]
+struct NestedEpic {
+    #[serde(flatten)]
+    epic: InputEpic,
+    #[serde(default)]
+    stories: Vec<InputStory>,
+}
+
+/// Parse a YAML manifest.  Epics listed under an objective follow the
+/// top-level epics, with their `objective` set to it; likewise stories
+/// listed under an epic, with their `epic` set to it (qualified by the
+/// epic's objective when it has one).  Each resource's `source_row` is its
+/// position in the flattened list.
 pub fn parse(path: &Path) -> Result<InputFile> {
-    let content = std::fs::read_to_string(path)?;
-    let input: InputFile = serde_yaml::from_str(&content)
-        .map_err(|e| anyhow::anyhow!("Failed to parse YAML file '{}': {}", path.display(), e))?;
+    let content = super::read_to_string(path)?;
+    parse_str(&content, &path.display().to_string())
+}
+
+/// [`parse`] for manifest text already in memory; `name` identifies it in
+/// messages.
+pub fn parse_str(content: &str, name: &str) -> Result<InputFile> {
+    let manifest: Manifest = serde_yaml::from_str(content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse YAML file '{name}': {e}"))?;
+    flatten(manifest)
+}
+
+/// Parse a JSON manifest: the YAML layout, written as JSON.
+pub fn parse_json(path: &Path) -> Result<InputFile> {
+    let content = super::read_to_string(path)?;
+    parse_json_str(&content, &path.display().to_string())
+}
+
+/// [`parse_json`] for manifest text already in memory.
+pub fn parse_json_str(content: &str, name: &str) -> Result<InputFile> {
+    if content.trim_start().starts_with('[') {
+        bail!(
+            "JSON file '{name}' is an array, not a manifest.  For a GitHub issues export, pass --format github"
+        );
+    }
+    let manifest: Manifest = serde_json::from_str(content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse JSON file '{name}': {e}"))?;
+    flatten(manifest)
+}
+
+fn flatten(manifest: Manifest) -> Result<InputFile> {
+    let mut input = InputFile {
+        labels: manifest.labels,
+        iterations: manifest.iterations,
+        stories: manifest.stories,
+        ..Default::default()
+    };
+    let mut epics = manifest.epics;
+    for NestedObjective {
+        objective,
+        epics: nested,
+    } in manifest.objectives
+    {
+        for mut nested_epic in nested {
+            let epic = &mut nested_epic.epic;
+            match &epic.objective {
+                Some(o) if o.trim() != objective.name.trim() => bail!(
+                    "Epic '{}' is listed under objective '{}' but sets objective '{o}'",
+                    epic.name,
+                    objective.name
+                ),
+                _ => epic.objective = Some(objective.name.trim().to_string()),
+            }
+            epics.push(nested_epic);
+        }
+        input.objectives.push(objective);
+    }
+    for NestedEpic { epic, stories } in epics {
+        let reference = match &epic.objective {
+            Some(obj) => qualified_epic_key(obj, &epic.name),
+            None => epic.name.trim().to_string(),
+        };
+        for mut story in stories {
+            match &story.epic {
+                Some(e) if e.trim() != epic.name.trim() && e.trim() != reference => bail!(
+                    "Story '{}' is listed under epic '{}' but sets epic '{e}'",
+                    story.name,
+                    epic.name
+                ),
+                _ => story.epic = Some(reference.clone()),
+            }
+            input.stories.push(story);
+        }
+        input.epics.push(epic);
+    }
+
+    for (i, l) in input.labels.iter_mut().enumerate() {
+        l.source_row = Some(i + 1);
+    }
+    for (i, it) in input.iterations.iter_mut().enumerate() {
+        it.source_row = Some(i + 1);
+    }
+    for (i, o) in input.objectives.iter_mut().enumerate() {
+        o.source_row = Some(i + 1);
+    }
+    for (i, e) in input.epics.iter_mut().enumerate() {
+        e.source_row = Some(i + 1);
+    }
+    for (i, s) in input.stories.iter_mut().enumerate() {
+        s.source_row = Some(i + 1);
+    }
     Ok(input)
 }
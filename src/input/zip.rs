@@ -0,0 +1,182 @@
+use anyhow::{Result, anyhow, bail};
+use calamine::{Reader, Xlsx};
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use super::models::InputFile;
+use super::{ColumnOptions, csv, xlsx, yaml};
+use crate::cli::ResourceType;
+
+/// Extensions of archive members that are parsed; everything else is
+/// ignored.
+const DATA_EXTENSIONS: &[&str] = &["yaml", "yml", "json", "csv", "tsv", "xlsx", "xls"];
+
+/// Upper bound on the total uncompressed size of an archive's data members,
+/// which are read into memory.
+const MAX_UNCOMPRESSED: u64 = 256 << 20;
+
+/// Parse every data file in a ZIP archive and merge them into one input,
+/// in path order.
+///
+/// Members may sit in nested directories.  Each CSV/TSV member's resource
+/// type comes from its file name when that names one (`epics.csv`,
+/// `team-a-stories.tsv`), otherwise from `resource_type`.  Hidden files
+/// and `__MACOSX/` metadata are skipped.  Members are parsed from memory,
+/// never extracted to disk.
+pub fn parse(
+    path: &Path,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| anyhow!("Cannot open '{}': {}", path.display(), e))?;
+    let mut archive = zip::ZipArchive::new(file)
+        .map_err(|e| anyhow!("Cannot read ZIP '{}': {}", path.display(), e))?;
+
+    let members = read_members(&mut archive, path)?;
+    if members.is_empty() {
+        bail!(
+            "ZIP '{}' contains no .yaml, .json, .csv, .tsv or .xlsx files",
+            path.display()
+        );
+    }
+    let mut merged = InputFile::default();
+    for (rel, data) in members {
+        let name = format!("{}:{}", path.display(), rel.display());
+        let rt = type_from_name(&rel).or(resource_type.cloned());
+        merged.merge(parse_member(&rel, data, &name, rt.as_ref(), columns)?);
+    }
+    Ok(merged)
+}
+
+/// Read the data members of `archive`; returns their relative paths and
+/// contents, sorted by path.
+fn read_members(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+    path: &Path,
+) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut members = Vec::new();
+    let mut total = 0u64;
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i)?;
+        let Some(rel) = entry.enclosed_name().map(Path::to_path_buf) else {
+            continue;
+        };
+        if entry.is_dir() || !is_data_file(&rel) {
+            continue;
+        }
+        // The declared size may lie; stop reading just past the limit.
+        let mut data = Vec::new();
+        entry
+            .take(MAX_UNCOMPRESSED - total + 1)
+            .read_to_end(&mut data)?;
+        total += data.len() as u64;
+        if total > MAX_UNCOMPRESSED {
+            bail!(
+                "ZIP '{}' holds more than {} MiB of data files",
+                path.display(),
+                MAX_UNCOMPRESSED >> 20
+            );
+        }
+        members.push((rel, data));
+    }
+    members.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(members)
+}
+
+/// Parse one member, by its extension; `name` identifies it in messages.
+fn parse_member(
+    rel: &Path,
+    data: Vec<u8>,
+    name: &str,
+    resource_type: Option<&ResourceType>,
+    columns: &ColumnOptions,
+) -> Result<InputFile> {
+    let ext = rel
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    let text = |data: Vec<u8>| {
+        String::from_utf8(data).map_err(|_| anyhow!("Cannot read file '{name}': not valid UTF-8"))
+    };
+    match ext.as_str() {
+        "yaml" | "yml" => yaml::parse_str(&text(data)?, name),
+        "json" => yaml::parse_json_str(&text(data)?, name),
+        "csv" | "tsv" => {
+            let Some(rt) = resource_type else {
+                bail!(
+                    "{name}: the file name does not say which resource type it holds; \
+                     pass --type or name it e.g. 'stories.csv'"
+                );
+            };
+            let delimiter = if ext == "tsv" { b'\t' } else { b',' };
+            let reader = csv::reader(Box::new(Cursor::new(data)), delimiter);
+            csv::parse_reader(reader, name, rt, columns)
+        }
+        _ => {
+            let workbook = Xlsx::new(Cursor::new(data))
+                .map_err(|e| anyhow!("Cannot open Excel file '{name}': {e}"))?;
+            xlsx::parse_workbook(workbook, name, resource_type, columns)
+        }
+    }
+}
+
+fn is_data_file(rel: &Path) -> bool {
+    let hidden = rel.components().any(|c| {
+        let c = c.as_os_str().to_string_lossy();
+        c.starts_with('.') || c == "__MACOSX"
+    });
+    let ext = rel
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+    !hidden && DATA_EXTENSIONS.contains(&ext.as_str())
+}
+
+/// The resource type named by a member's file name, if any.
+fn type_from_name(rel: &Path) -> Option<ResourceType> {
+    let stem = rel.file_stem()?.to_str()?.to_lowercase();
+    if stem.contains("objective") || stem.contains("milestone") {
+        Some(ResourceType::Objective)
+    } else if stem.contains("epic") {
+        Some(ResourceType::Epic)
+    } else if stem.contains("stor") {
+        Some(ResourceType::Story)
+    } else if stem.contains("label") {
+        Some(ResourceType::Label)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn parses_members_in_memory() {
+        let mut file = tempfile::Builder::new().suffix(".zip").tempfile().unwrap();
+        let mut zip = zip::ZipWriter::new(file.as_file_mut());
+        let options = zip::write::FileOptions::default();
+        for (member, content) in [
+            ("plan/stories.csv", "name,epic\nShip it,Launch\n"),
+            ("plan/epics.yaml", "epics:\n  - name: Launch\n"),
+            ("__MACOSX/plan/._stories.csv", "junk"),
+            ("notes.txt", "ignored"),
+        ] {
+            zip.start_file(member, options).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        drop(zip);
+
+        let input = parse(file.path(), None, &ColumnOptions::default()).unwrap();
+        assert_eq!(input.epics.len(), 1);
+        assert_eq!(input.epics[0].name, "Launch");
+        assert_eq!(input.stories.len(), 1);
+        assert_eq!(input.stories[0].epic.as_deref(), Some("Launch"));
+    }
+}
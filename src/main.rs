@@ -2,16 +2,38 @@ mod api;
 mod cli;
 mod commands;
 mod config;
+mod dedupe;
+mod echo;
 mod error;
+mod graph;
 mod input;
+mod plan;
+mod policy;
+mod preflight;
+mod preset;
 mod resolver;
+mod schema;
+mod stage;
 mod template;
+mod validate;
+mod verify;
 
 use anyhow::Result;
 use clap::Parser;
 use cli::Cli;
+use error::BypassError;
+
+/// Exit code for authentication failures, distinct from ordinary errors.
+const EXIT_AUTH: i32 = 3;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    commands::run(Cli::parse()).await
+    let result = commands::run(Cli::parse_from(config::args_with_defaults())).await;
+    if let Err(e) = &result
+        && let Some(BypassError::Auth { .. }) = e.downcast_ref::<BypassError>()
+    {
+        eprintln!("Error: {e}");
+        std::process::exit(EXIT_AUTH);
+    }
+    result
 }
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::input::models::{InputEpic, InputFile, InputStory};
+
+/// One API write that a run would perform, as listed by `--dry-run`.
+#[derive(Debug, Serialize)]
+pub struct PlannedWrite {
+    /// HTTP method and path template, e.g. `POST /stories`.
+    pub endpoint: &'static str,
+    /// Human-readable summary, e.g. `create epic 'Y' under objective 'X'`.
+    pub action: String,
+}
+
+/// The writes a run over `input` performs, in execution order:
+/// labels, iterations, objectives, epics, epic ordering, then stories.
+pub fn plan(input: &InputFile) -> Vec<PlannedWrite> {
+    let mut writes = Vec::new();
+
+    for label in &input.labels {
+        writes.push(PlannedWrite {
+            endpoint: "POST /labels",
+            action: match &label.color {
+                Some(color) => format!("create label '{}' ({})", label.name, color.trim()),
+                None => format!("create label '{}'", label.name),
+            },
+        });
+    }
+
+    for iteration in &input.iterations {
+        writes.push(PlannedWrite {
+            endpoint: "POST /iterations",
+            action: format!(
+                "create iteration '{}' ({} to {})",
+                iteration.name, iteration.start_date, iteration.end_date
+            ),
+        });
+    }
+
+    for obj in &input.objectives {
+        writes.push(PlannedWrite {
+            endpoint: "POST /objectives",
+            action: format!("create objective '{}'", obj.name),
+        });
+    }
+
+    for epic in &input.epics {
+        let mut action = format!("create epic '{}'", epic.name);
+        if let Some(obj) = &epic.objective {
+            action.push_str(&format!(" under objective '{obj}'"));
+        }
+        writes.push(PlannedWrite {
+            endpoint: "POST /epics",
+            action,
+        });
+        if let Some(health) = &epic.health {
+            writes.push(PlannedWrite {
+                endpoint: "POST /epics/{id}/health",
+                action: format!("set health of epic '{}' to '{health}'", epic.name),
+            });
+        }
+    }
+
+    for (obj, group) in epic_order(&input.epics) {
+        for pair in group.windows(2) {
+            writes.push(PlannedWrite {
+                endpoint: "PUT /epics/{id}",
+                action: format!(
+                    "move epic '{}' after '{}' under objective '{obj}'",
+                    input.epics[pair[1]].name, input.epics[pair[0]].name
+                ),
+            });
+        }
+    }
+
+    for story in &input.stories {
+        writes.extend(story_writes(story));
+    }
+
+    writes
+}
+
+/// The writes for a single story (also used when streaming): the create,
+/// then one per linked file.
+pub fn story_writes(story: &InputStory) -> Vec<PlannedWrite> {
+    let mut action = format!("create story '{}'", story.name);
+    if let Some(epic) = &story.epic {
+        action.push_str(&format!(" in epic '{epic}'"));
+    }
+    if let Some(iteration) = &story.iteration {
+        action.push_str(&format!(" in iteration '{iteration}'"));
+    }
+    let mut writes = vec![PlannedWrite {
+        endpoint: "POST /stories",
+        action,
+    }];
+    for link in &story.links {
+        writes.push(PlannedWrite {
+            endpoint: "POST /linked-files",
+            action: format!("link '{}' to story '{}'", link.url, story.name),
+        });
+    }
+    for task in &story.tasks {
+        writes.push(PlannedWrite {
+            endpoint: "POST /stories/{id}/tasks",
+            action: format!("add task '{}' to story '{}'", task.name, story.name),
+        });
+    }
+    for (i, _) in story.comments.iter().enumerate() {
+        writes.push(PlannedWrite {
+            endpoint: "POST /stories/{id}/comments",
+            action: format!("post comment {} on story '{}'", i + 1, story.name),
+        });
+    }
+    for (verb, target) in story.story_links.iter() {
+        writes.push(PlannedWrite {
+            endpoint: "POST /story-links",
+            action: format!("link story '{}' {verb} '{target}'", story.name),
+        });
+    }
+    writes
+}
+
+/// Epics grouped by objective reference, each group sorted by `position`
+/// (file order within the objective when absent).  Only groups of two or
+/// more epics are returned, since a single epic needs no ordering.
+/// Groups are sorted by objective for a stable plan.
+pub fn epic_order(epics: &[InputEpic]) -> Vec<(&str, Vec<usize>)> {
+    let mut by_objective: HashMap<&str, Vec<(i64, usize)>> = HashMap::new();
+    for (i, epic) in epics.iter().enumerate() {
+        if let Some(obj) = &epic.objective {
+            let group = by_objective.entry(obj.trim()).or_default();
+            let pos = epic.position.unwrap_or(group.len() as i64 + 1);
+            group.push((pos, i));
+        }
+    }
+
+    let mut groups: Vec<(&str, Vec<usize>)> = by_objective
+        .into_iter()
+        .filter(|(_, group)| group.len() > 1)
+        .map(|(obj, mut group)| {
+            group.sort_by_key(|&(pos, _)| pos);
+            (obj, group.into_iter().map(|(_, i)| i).collect())
+        })
+        .collect();
+    groups.sort_by_key(|(obj, _)| *obj);
+    groups
+}
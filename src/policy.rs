@@ -0,0 +1,47 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Import policy loaded from `--policy <FILE>`.
+///
+/// Policies are organisational rules checked during `--dry-run` on top of
+/// the built-in validators.
+///
+/// ```yaml
+/// team_workflow_states:
+///   Platform: ["Backlog", "In Progress", "Done"]
+///   SRE: ["Triage", "Done"]
+/// story_types: ["feature", "bug"]
+/// ```
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct Policy {
+    /// Team name → workflow state names stories for that team may use.
+    /// Teams not listed here are unrestricted.
+    #[serde(default)]
+    pub team_workflow_states: HashMap<String, Vec<String>>,
+    /// Story types the workspace allows; empty means the built-in
+    /// [`DEFAULT_STORY_TYPES`].  `--story-types` overrides it.
+    #[serde(default)]
+    pub story_types: Vec<String>,
+}
+
+/// The story types every Shortcut workspace has.
+pub const DEFAULT_STORY_TYPES: &[&str] = &["bug", "chore", "feature"];
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read policy '{}': {}", path.display(), e))?;
+        serde_yaml::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Failed to parse policy file '{}': {}", path.display(), e))
+    }
+
+    /// The story types `type` may take.
+    pub fn story_types(&self) -> Vec<&str> {
+        if self.story_types.is_empty() {
+            DEFAULT_STORY_TYPES.to_vec()
+        } else {
+            self.story_types.iter().map(|t| t.trim()).collect()
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use colored::Colorize;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::input::models::InputFile;
+use crate::resolver::{Resolver, epic_key, epic_label_ref, qualified_epic_key};
+use crate::stage::intended_state;
+
+/// Every name in a batch that does not resolve against the workspace (or
+/// the batch itself), grouped by kind.
+///
+/// Built before anything is created so a typo in a user or team name is
+/// reported once, with the number of rows it affects, instead of as one
+/// failure per row halfway through the import.
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Kind ("users", "teams", …) → unresolved name → rows referencing it.
+    pub unresolved: BTreeMap<&'static str, BTreeMap<String, usize>>,
+}
+
+impl Report {
+    /// Attempt every name resolution in `input`.
+    pub fn check(input: &InputFile, resolver: &Resolver) -> Self {
+        let mut report = Self::default();
+
+        let batch_iterations: HashSet<&str> =
+            input.iterations.iter().map(|i| i.name.trim()).collect();
+        let batch_objectives: HashSet<&str> =
+            input.objectives.iter().map(|o| o.name.as_str()).collect();
+        let mut batch_epics: HashSet<String> = HashSet::new();
+        let mut batch_epic_labels: HashSet<&str> = HashSet::new();
+        for epic in &input.epics {
+            batch_epics.insert(epic.name.clone());
+            if let Some(obj) = &epic.objective {
+                batch_epics.insert(qualified_epic_key(obj, &epic.name));
+            }
+            batch_epic_labels.extend(epic.labels.iter().map(String::as_str));
+        }
+
+        for iteration in &input.iterations {
+            let mut row = Row::default();
+            for team in &iteration.teams {
+                row.check("teams", team, resolver.resolve_group(team).is_ok());
+            }
+            report.add(row);
+        }
+
+        for obj in &input.objectives {
+            let mut row = Row::default();
+            for category in &obj.categories {
+                row.check(
+                    "categories",
+                    category,
+                    resolver.resolve_category(category).is_ok(),
+                );
+            }
+            report.add(row);
+        }
+
+        for epic in &input.epics {
+            let mut row = Row::default();
+            for owner in epic.owners.iter().chain(&epic.followers) {
+                row.check("users", owner, resolver.resolve_member(owner).is_ok());
+            }
+            for team in &epic.teams {
+                row.check("teams", team, resolver.resolve_group(team).is_ok());
+            }
+            if let Some(obj) = &epic.objective {
+                let found = obj.trim().parse::<i64>().is_ok()
+                    || batch_objectives.contains(obj.trim())
+                    || resolver.objective_map.contains_key(obj.trim());
+                row.check("objectives", obj, found);
+            }
+            report.add(row);
+        }
+
+        for story in &input.stories {
+            let mut row = Row::default();
+            for owner in story
+                .owners
+                .iter()
+                .chain(&story.followers)
+                .chain(&story.requested_by)
+                .chain(story.tasks.iter().filter_map(|t| t.owner.as_ref()))
+                .chain(story.comments.iter().filter_map(|c| c.author.as_ref()))
+            {
+                row.check("users", owner, resolver.resolve_member(owner).is_ok());
+            }
+            if let Some(team) = &story.team {
+                row.check("teams", team, resolver.resolve_group(team).is_ok());
+            }
+            for (field, value) in &story.custom_fields {
+                row.check(
+                    "custom fields",
+                    &format!("{field}: {value}"),
+                    resolver.resolve_custom_field(field, value).is_ok(),
+                );
+            }
+            if let Some(ws) = &story.workflow_state {
+                row.check(
+                    "workflow states",
+                    ws,
+                    resolver.resolve_workflow_state(ws).is_ok(),
+                );
+            }
+            for intended in story.labels.iter().filter_map(|l| intended_state(l)) {
+                row.check(
+                    "workflow states",
+                    intended,
+                    resolver.resolve_workflow_state(intended).is_ok(),
+                );
+            }
+            if let Some(iteration) = &story.iteration {
+                let found = batch_iterations.contains(iteration.trim())
+                    || resolver.resolve_iteration(iteration).is_ok();
+                row.check("iterations", iteration, found);
+            }
+            if let Some(project) = &story.project {
+                row.check(
+                    "projects",
+                    project,
+                    resolver.resolve_project(project).is_ok(),
+                );
+            }
+            if let Some(epic) = &story.epic {
+                let found = match epic_label_ref(epic) {
+                    Some(label) => {
+                        batch_epic_labels.contains(label)
+                            || resolver.epic_label_map.contains_key(label)
+                    }
+                    None => {
+                        let key = epic_key(epic);
+                        epic.trim().parse::<i64>().is_ok()
+                            || batch_epics.contains(&key)
+                            || resolver.epic_map.contains_key(&key)
+                    }
+                };
+                row.check("epics", epic, found);
+            }
+            report.add(row);
+        }
+
+        report
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+
+    /// Number of distinct unresolved names.
+    pub fn names(&self) -> usize {
+        self.unresolved.values().map(BTreeMap::len).sum()
+    }
+
+    /// Print the report as text on stderr.
+    pub fn print(&self) {
+        eprintln!(
+            "{} {} unresolved name(s):",
+            "✗".red().bold(),
+            self.names().to_string().red()
+        );
+        for (kind, names) in &self.unresolved {
+            eprintln!("  {}:", kind.bold());
+            for (name, rows) in names {
+                eprintln!(
+                    "    {} {name}  {}",
+                    "•".red(),
+                    format!("({rows} row{})", if *rows == 1 { "" } else { "s" }).dimmed()
+                );
+            }
+        }
+    }
+
+    /// The report as a `preflight` event for `--output json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "event": "preflight", "unresolved": self.unresolved })
+    }
+
+    fn add(&mut self, row: Row) {
+        for (kind, name) in row.failures {
+            *self
+                .unresolved
+                .entry(kind)
+                .or_default()
+                .entry(name)
+                .or_default() += 1;
+        }
+    }
+}
+
+/// Failures for one resource; a name repeated within a row counts once.
+#[derive(Default)]
+struct Row {
+    failures: HashSet<(&'static str, String)>,
+}
+
+impl Row {
+    fn check(&mut self, kind: &'static str, name: &str, resolved: bool) {
+        if !resolved {
+            self.failures.insert((kind, name.trim().to_string()));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::input::models::{InputEpic, InputFile, InputStory};
+
+/// Named bundles of labels, owners and teams loaded from `--presets <FILE>`.
+///
+/// Epics and stories reference bundles through their `preset` field; the
+/// bundle's values are merged into the resource's own before resolution.
+///
+/// ```yaml
+/// release:
+///   labels: [release, changelog]
+///   owners: [Release Manager]
+/// security-review:
+///   labels: [security]
+///   teams: [AppSec]
+/// ```
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Presets {
+    bundles: HashMap<String, Preset>,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Preset {
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    owners: Vec<String>,
+    #[serde(default)]
+    teams: Vec<String>,
+}
+
+impl Presets {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read presets '{}': {}", path.display(), e))?;
+        serde_yaml::from_str(&content).map_err(|e| {
+            anyhow::anyhow!("Failed to parse presets file '{}': {}", path.display(), e)
+        })
+    }
+
+    /// Expand the presets referenced by every epic and story in `input`.
+    /// Returns one error per reference to an undefined preset.
+    pub fn expand(&self, input: &mut InputFile) -> Vec<String> {
+        let mut errors = Vec::new();
+        for epic in &mut input.epics {
+            self.expand_epic(epic, &mut errors);
+        }
+        for story in &mut input.stories {
+            self.expand_story(story, &mut errors);
+        }
+        errors
+    }
+
+    fn expand_epic(&self, epic: &mut InputEpic, errors: &mut Vec<String>) {
+        let what = format!("Epic '{}'", epic.name);
+        for preset in self.lookup(&what, &epic.preset, errors) {
+            merge(&mut epic.labels, &preset.labels);
+            merge(&mut epic.owners, &preset.owners);
+            merge(&mut epic.teams, &preset.teams);
+        }
+    }
+
+    /// Expand a single story's presets (also used when streaming rows).
+    /// A story has one team, so a preset's first team applies only when the
+    /// story names none.
+    pub fn expand_story(&self, story: &mut InputStory, errors: &mut Vec<String>) {
+        let what = format!("Story '{}'", story.name);
+        for preset in self.lookup(&what, &story.preset, errors) {
+            merge(&mut story.labels, &preset.labels);
+            merge(&mut story.owners, &preset.owners);
+            if story.team.is_none() {
+                story.team = preset.teams.first().cloned();
+            }
+        }
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        what: &str,
+        names: &[String],
+        errors: &mut Vec<String>,
+    ) -> Vec<&'a Preset> {
+        names
+            .iter()
+            .filter_map(|name| {
+                let preset = self.bundles.get(name.trim());
+                if preset.is_none() {
+                    errors.push(if self.bundles.is_empty() {
+                        format!("{what}: preset '{name}' used but no --presets file was given")
+                    } else {
+                        let mut known: Vec<&str> =
+                            self.bundles.keys().map(String::as_str).collect();
+                        known.sort_unstable();
+                        format!(
+                            "{what}: undefined preset '{name}'. Available: {}",
+                            known.join(", ")
+                        )
+                    });
+                }
+                preset
+            })
+            .collect()
+    }
+}
+
+/// Append the values of `extra` not already in `values`, keeping order.
+fn merge(values: &mut Vec<String>, extra: &[String]) {
+    for value in extra {
+        if !values.iter().any(|v| v.trim() == value.trim()) {
+            values.push(value.clone());
+        }
+    }
+}
@@ -1,8 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
+use serde::Deserialize;
 
 use crate::api::ShortcutClient;
+use crate::api::models::{
+    Category, CustomField, CustomFieldValueParams, Epic, Group, Iteration, Member, Project,
+    Workflow,
+};
 use crate::error::BypassError;
 
 /// Holds lookup tables built from workspace data.
@@ -13,29 +19,267 @@ pub struct Resolver {
     pub member_map: HashMap<String, String>,
     /// Full name and mention name → group UUID.
     pub group_map: HashMap<String, String>,
+    /// Names, mention names, and emails of disabled members → UUID.  Not
+    /// used for resolution; explains misses (and lets `reassign` find
+    /// stories of members who have left).
+    pub disabled_members: HashMap<String, String>,
+    /// Names and mention names of archived groups (as above).
+    pub archived_groups: HashSet<String>,
+    /// Email domain aliases (`old` → `new`) applied by [`normalize_email`].
+    pub email_domain_map: HashMap<String, String>,
+    /// Team alias (e.g. a Slack channel) → group name or mention name.
+    /// Only populated by [`Resolver::load_team_aliases`].
+    pub group_aliases: HashMap<String, String>,
     /// Workflow state name → state integer ID.
     pub workflow_state_map: HashMap<String, i64>,
+    /// Every workflow state ID in the workspace (for numeric references).
+    pub workflow_state_ids: HashSet<i64>,
     /// The first "unstarted" workflow state found – used as the story default.
     pub default_workflow_state_id: Option<i64>,
+    /// Project name → project integer ID.  Only populated by
+    /// [`Resolver::load_projects`], since modern workspaces have no Projects.
+    pub project_map: HashMap<String, i64>,
+    /// Iteration name → ID, for existing iterations and those created in
+    /// this run.  Last-write wins for duplicate names.
+    pub iteration_map: HashMap<String, i64>,
+    /// Iteration ID → end date, for existing iterations that have one.
+    pub iteration_end_dates: HashMap<i64, String>,
+    /// Custom fields with their values, matched by [`fold_name`].
+    pub custom_fields: Vec<CustomField>,
+    /// Objective category name → ID, for unarchived categories.
+    pub category_map: HashMap<String, i64>,
 
     // In-run cross-reference maps (populated as resources are created).
     pub objective_map: HashMap<String, i64>,
+    /// Epic name (and `Objective / Epic` key) → ID.
     pub epic_map: HashMap<String, i64>,
+    /// Epic names created under more than one objective; these must be
+    /// referenced in the qualified form.
+    pub ambiguous_epics: HashSet<String>,
+    /// Name → `app_url` of objectives/epics created in this run, for
+    /// `--link-references`.
+    pub objective_url_map: HashMap<String, String>,
+    pub epic_url_map: HashMap<String, String>,
+    /// Story name → ID, for `story_links`.
+    pub story_map: HashMap<String, i64>,
+    /// Label → IDs of epics carrying it, for `label:<name>` epic references.
+    /// Seeded from a workspace search and extended as epics are created.
+    pub epic_label_map: HashMap<String, Vec<i64>>,
+
+    // [`fold_name`] forms of the member, group, and workflow state keys,
+    // tried when an exact lookup misses.  A folded key reached from more
+    // than one ID maps to `None`, so it only ever resolves exactly.
+    member_folded: HashMap<String, Option<String>>,
+    group_folded: HashMap<String, Option<String>>,
+    workflow_state_folded: HashMap<String, Option<i64>>,
+}
+
+/// Workspace data read from a `--resolver-fixture` file instead of the API.
+/// Each list holds objects in the shape the API returns them.
+#[derive(Debug, Deserialize)]
+pub struct Fixture {
+    #[serde(default)]
+    pub members: Vec<Member>,
+    #[serde(default)]
+    pub groups: Vec<Group>,
+    #[serde(default)]
+    pub workflows: Vec<Workflow>,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub iterations: Vec<Iteration>,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomField>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+    /// Existing epics, searched for `label:<name>` references.
+    #[serde(default)]
+    pub epics: Vec<Epic>,
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read fixture '{}': {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Failed to parse fixture '{}': {}", path.display(), e))
+    }
+}
+
+/// Key for case- and whitespace-insensitive name matching: lowercased,
+/// trimmed, with runs of whitespace collapsed to one space.
+pub fn fold_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Index `map` by [`fold_name`].  Keys that fold together but belong to
+/// different IDs are marked ambiguous (`None`).
+fn fold_keys<V: Clone + PartialEq>(map: &HashMap<String, V>) -> HashMap<String, Option<V>> {
+    let mut folded: HashMap<String, Option<V>> = HashMap::new();
+    for (key, id) in map {
+        folded
+            .entry(fold_name(key))
+            .and_modify(|existing| {
+                if existing.as_ref() != Some(id) {
+                    *existing = None;
+                }
+            })
+            .or_insert_with(|| Some(id.clone()));
+    }
+    folded
+}
+
+/// Up to two keys of `map` closest to `name` by edit distance (compared in
+/// [`fold_name`] form), at most one per ID, for "did you mean" hints.  Keys
+/// further than about a third of the name's length are not offered.
+fn suggest<'a, V: PartialEq>(name: &str, map: &'a HashMap<String, V>) -> Vec<&'a str> {
+    let target = fold_name(name);
+    let limit = (target.chars().count() / 3).max(1);
+    let mut scored: Vec<(usize, &str, &V)> = map
+        .iter()
+        .map(|(key, id)| (levenshtein(&target, &fold_name(key)), key.as_str(), id))
+        .filter(|(d, _, _)| *d <= limit)
+        .collect();
+    scored.sort_unstable_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+    let mut picked: Vec<(&str, &V)> = Vec::new();
+    for (_, key, id) in scored {
+        if !picked.iter().any(|(_, p)| *p == id) {
+            picked.push((key, id));
+        }
+    }
+    picked.into_iter().take(2).map(|(key, _)| key).collect()
+}
+
+/// ` — did you mean 'A'?` or ` — did you mean 'A' or 'B'?`; empty when
+/// there is nothing to suggest.
+pub fn did_you_mean(suggestions: &[&str]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [one] => format!(" — did you mean '{one}'?"),
+        [first, rest @ ..] => format!(
+            " — did you mean '{first}' or {}?",
+            rest.iter()
+                .map(|s| format!("'{s}'"))
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ),
+    }
+}
+
+/// Edit distance between two strings, counted in characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Split a `label:<name>` epic reference, returning the label name.
+pub fn epic_label_ref(reference: &str) -> Option<&str> {
+    reference.trim().strip_prefix("label:").map(str::trim)
+}
+
+/// Split an `Objective Name / Epic Name` epic reference into its parts.
+pub fn epic_qualified_ref(reference: &str) -> Option<(&str, &str)> {
+    reference
+        .split_once(" / ")
+        .map(|(obj, epic)| (obj.trim(), epic.trim()))
+}
+
+/// Lookup key for an epic reference: the trimmed name, or
+/// `Objective / Epic` with whitespace around both parts normalized.
+pub fn epic_key(reference: &str) -> String {
+    match epic_qualified_ref(reference) {
+        Some((obj, epic)) => qualified_epic_key(obj, epic),
+        None => reference.trim().to_string(),
+    }
+}
+
+/// Lookup key for the epic `name` under objective `obj`:
+/// `Objective / Epic`, both parts trimmed.
+pub fn qualified_epic_key(obj: &str, name: &str) -> String {
+    format!("{} / {}", obj.trim(), name.trim())
 }
 
 impl Resolver {
-    /// Fetch members, groups, and workflows in parallel and build lookup maps.
-    pub async fn new(client: &ShortcutClient) -> Result<Self> {
-        let (members, groups, workflows) = tokio::try_join!(
+    /// Fetch members, groups, workflows, iterations, custom fields and
+    /// categories in parallel and build lookup maps.
+    /// A non-empty `workflows` restricts workflow states (and the default
+    /// state) to the named workflows; an unknown name is an error.
+    pub async fn new(client: &ShortcutClient, workflows: &[String]) -> Result<Self> {
+        let (members, groups, all_workflows, iterations, custom_fields, categories) = tokio::try_join!(
             client.list_members(),
             client.list_groups(),
             client.list_workflows(),
+            client.list_iterations(),
+            client.list_custom_fields(),
+            client.list_categories(),
+        )?;
+        let mut resolver =
+            Self::from_data(&members, &groups, &all_workflows, &iterations, workflows)?;
+        resolver.custom_fields = custom_fields;
+        resolver.load_categories(&categories);
+        Ok(resolver)
+    }
+
+    /// Build the resolver from a [`Fixture`] with no network access.
+    /// Projects and `label:` epic references resolve against the fixture's
+    /// lists, as [`Resolver::load_projects`] and
+    /// [`Resolver::load_epic_labels`] would against the workspace.
+    pub fn from_fixture(fixture: &Fixture, workflows: &[String], labels: &[&str]) -> Result<Self> {
+        let mut resolver = Self::from_data(
+            &fixture.members,
+            &fixture.groups,
+            &fixture.workflows,
+            &fixture.iterations,
+            workflows,
         )?;
+        resolver.custom_fields = fixture.custom_fields.clone();
+        resolver.load_categories(&fixture.categories);
+        for p in fixture.projects.iter().filter(|p| !p.archived) {
+            resolver.project_map.insert(p.name.clone(), p.id);
+        }
+        for &label in labels {
+            let ids = fixture
+                .epics
+                .iter()
+                .filter(|e| e.labels.iter().any(|l| l.name == label))
+                .map(|e| e.id)
+                .collect();
+            resolver.epic_label_map.insert(label.to_string(), ids);
+        }
+        Ok(resolver)
+    }
 
+    fn from_data(
+        members: &[Member],
+        groups: &[Group],
+        all_workflows: &[Workflow],
+        iterations: &[Iteration],
+        workflows: &[String],
+    ) -> Result<Self> {
         // ----- members -----
         let mut member_map: HashMap<String, String> = HashMap::new();
-        for m in &members {
+        let mut disabled_members: HashMap<String, String> = HashMap::new();
+        for m in members {
             if m.disabled {
+                disabled_members.insert(m.profile.name.clone(), m.id.clone());
+                disabled_members.insert(m.profile.mention_name.clone(), m.id.clone());
+                if let Some(email) = &m.profile.email_address {
+                    disabled_members.insert(email.clone(), m.id.clone());
+                }
                 continue;
             }
             member_map.insert(m.profile.name.clone(), m.id.clone());
@@ -47,8 +291,11 @@ impl Resolver {
 
         // ----- groups / teams -----
         let mut group_map: HashMap<String, String> = HashMap::new();
-        for g in &groups {
+        let mut archived_groups: HashSet<String> = HashSet::new();
+        for g in groups {
             if g.archived {
+                archived_groups.insert(g.name.clone());
+                archived_groups.insert(g.mention_name.clone());
                 continue;
             }
             group_map.insert(g.name.clone(), g.id.clone());
@@ -56,13 +303,34 @@ impl Resolver {
         }
 
         // ----- workflow states -----
+        let unknown: Vec<&str> = workflows
+            .iter()
+            .map(|name| name.trim())
+            .filter(|name| !all_workflows.iter().any(|wf| wf.name == *name))
+            .collect();
+        if !unknown.is_empty() {
+            let mut available: Vec<&str> =
+                all_workflows.iter().map(|wf| wf.name.as_str()).collect();
+            available.sort_unstable();
+            bail!(
+                "Unknown workflow(s): {}. Available: {}",
+                unknown.join(", "),
+                available.join(", ")
+            );
+        }
+        let selected = all_workflows
+            .iter()
+            .filter(|wf| workflows.is_empty() || workflows.iter().any(|n| n.trim() == wf.name));
+
         let mut workflow_state_map: HashMap<String, i64> = HashMap::new();
+        let mut workflow_state_ids: HashSet<i64> = HashSet::new();
         let mut default_workflow_state_id: Option<i64> = None;
 
-        for wf in &workflows {
+        for wf in selected {
             for state in &wf.states {
                 // Last-write wins for duplicate names across workflows.
                 workflow_state_map.insert(state.name.clone(), state.id);
+                workflow_state_ids.insert(state.id);
                 if default_workflow_state_id.is_none() && state.state_type == "unstarted" {
                     default_workflow_state_id = Some(state.id);
                 }
@@ -73,25 +341,182 @@ impl Resolver {
             }
         }
 
-        Ok(Self {
+        let mut resolver = Self {
             member_map,
             group_map,
+            disabled_members,
+            archived_groups,
+            group_aliases: HashMap::new(),
             workflow_state_map,
+            workflow_state_ids,
             default_workflow_state_id,
+            project_map: HashMap::new(),
+            iteration_map: iterations.iter().map(|i| (i.name.clone(), i.id)).collect(),
+            iteration_end_dates: iterations
+                .iter()
+                .filter_map(|i| i.end_date.clone().map(|d| (i.id, d)))
+                .collect(),
+            custom_fields: Vec::new(),
+            category_map: HashMap::new(),
             objective_map: HashMap::new(),
             epic_map: HashMap::new(),
-        })
+            ambiguous_epics: HashSet::new(),
+            objective_url_map: HashMap::new(),
+            epic_url_map: HashMap::new(),
+            story_map: HashMap::new(),
+            epic_label_map: HashMap::new(),
+            email_domain_map: HashMap::new(),
+            member_folded: HashMap::new(),
+            group_folded: HashMap::new(),
+            workflow_state_folded: HashMap::new(),
+        };
+        resolver.index_normalized_emails();
+        resolver.group_folded = fold_keys(&resolver.group_map);
+        resolver.workflow_state_folded = fold_keys(&resolver.workflow_state_map);
+        Ok(resolver)
+    }
+
+    /// Apply `--email-domain-map` and re-index member emails under it.
+    pub fn set_email_domain_map(&mut self, map: HashMap<String, String>) {
+        self.email_domain_map = map
+            .into_iter()
+            .map(|(old, new)| (old.to_lowercase(), new.to_lowercase()))
+            .collect();
+        self.index_normalized_emails();
+    }
+
+    /// Add the normalized form of every member email as an extra key, so
+    /// that raw and normalized references reach the same member, then
+    /// rebuild the folded member keys.
+    fn index_normalized_emails(&mut self) {
+        let extra: Vec<(String, String)> = self
+            .member_map
+            .iter()
+            .filter(|(key, _)| key.contains('@'))
+            .map(|(key, id)| (self.normalize_email(key), id.clone()))
+            .collect();
+        for (key, id) in extra {
+            self.member_map.entry(key).or_insert(id);
+        }
+        self.member_folded = fold_keys(&self.member_map);
+    }
+
+    /// Lowercase an email, strip any `+tag` from the local part, and map
+    /// its domain through `email_domain_map`:
+    /// `JSmith+shortcut@old-corp.com` → `jsmith@corp.com`.
+    pub fn normalize_email(&self, email: &str) -> String {
+        let email = email.trim().to_lowercase();
+        let Some((local, domain)) = email.split_once('@') else {
+            return email;
+        };
+        let local = local.split_once('+').map_or(local, |(base, _)| base);
+        let domain = self
+            .email_domain_map
+            .get(domain)
+            .map_or(domain, String::as_str);
+        format!("{local}@{domain}")
+    }
+
+    /// Load a `--team-alias` YAML file mapping alias → team name, e.g.
+    /// `"#platform-eng": Platform Engineering`.  Fails if any alias points
+    /// at a team that does not exist.
+    pub fn load_team_aliases(&mut self, path: &Path) -> Result<()> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("Cannot read team alias file '{}': {}", path.display(), e)
+        })?;
+        let aliases: HashMap<String, String> = serde_yaml::from_str(&content).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to parse team alias file '{}': {}",
+                path.display(),
+                e
+            )
+        })?;
+
+        let mut unknown: Vec<String> = aliases
+            .iter()
+            .filter(|(_, team)| !self.group_map.contains_key(team.trim()))
+            .map(|(alias, team)| format!("'{alias}' → '{team}'"))
+            .collect();
+        if !unknown.is_empty() {
+            unknown.sort_unstable();
+            bail!(
+                "Team alias file '{}' maps to unknown team(s): {}",
+                path.display(),
+                unknown.join(", ")
+            );
+        }
+
+        self.group_aliases = aliases
+            .into_iter()
+            .map(|(alias, team)| (alias.trim().to_string(), team))
+            .collect();
+        Ok(())
+    }
+
+    /// Fetch projects and build the project lookup map.  Called only when
+    /// the input references a project.
+    pub async fn load_projects(&mut self, client: &ShortcutClient) -> Result<()> {
+        for p in client.list_projects().await? {
+            if p.archived {
+                continue;
+            }
+            self.project_map.insert(p.name, p.id);
+        }
+        Ok(())
+    }
+
+    /// Search the workspace for epics carrying each of `labels` so that
+    /// `label:<name>` references can be resolved.
+    pub async fn load_epic_labels(
+        &mut self,
+        client: &ShortcutClient,
+        labels: &[&str],
+    ) -> Result<()> {
+        for &label in labels {
+            let ids = client
+                .search_epics_by_label(label)
+                .await?
+                .into_iter()
+                .map(|e| e.id)
+                .collect();
+            self.epic_label_map.insert(label.to_string(), ids);
+        }
+        Ok(())
     }
 
     // ------------------------------------------------------------------
     // Lookups
     // ------------------------------------------------------------------
 
+    /// Look up a member by name, mention name, or email; emails also match
+    /// in normalized form (see [`Resolver::normalize_email`]).  Failing an
+    /// exact match, names match ignoring case and extra whitespace.
     pub fn resolve_member(&self, name: &str) -> Result<String> {
-        self.member_map.get(name.trim()).cloned().ok_or_else(|| {
-            BypassError::NameNotFound {
-                resource_type: "user".into(),
-                name: name.to_string(),
+        let id = self
+            .member_map
+            .get(name.trim())
+            .or_else(|| {
+                name.contains('@')
+                    .then(|| self.member_map.get(&self.normalize_email(name)))
+                    .flatten()
+            })
+            .or_else(|| self.member_folded.get(&fold_name(name))?.as_ref());
+        id.cloned().ok_or_else(|| {
+            let folded = fold_name(name);
+            if self.disabled_members.contains_key(name.trim())
+                || self.disabled_members.keys().any(|k| fold_name(k) == folded)
+            {
+                BypassError::Inactive {
+                    resource_type: "user".into(),
+                    name: name.to_string(),
+                    status: "disabled".into(),
+                }
+            } else {
+                BypassError::NameNotFound {
+                    resource_type: "user".into(),
+                    name: name.to_string(),
+                    hint: did_you_mean(&self.suggest_members(name)),
+                }
             }
             .into()
         })
@@ -101,11 +526,35 @@ impl Resolver {
         names.iter().map(|n| self.resolve_member(n)).collect()
     }
 
+    /// Look up a team by name or mention name, then by `--team-alias`, then
+    /// by name ignoring case and extra whitespace.
     pub fn resolve_group(&self, name: &str) -> Result<String> {
-        self.group_map.get(name.trim()).cloned().ok_or_else(|| {
-            BypassError::NameNotFound {
-                resource_type: "team".into(),
-                name: name.to_string(),
+        let name = name.trim();
+        let id = self
+            .group_map
+            .get(name)
+            .or_else(|| {
+                self.group_aliases
+                    .get(name)
+                    .and_then(|canonical| self.group_map.get(canonical.trim()))
+            })
+            .or_else(|| self.group_folded.get(&fold_name(name))?.as_ref());
+        id.cloned().ok_or_else(|| {
+            let folded = fold_name(name);
+            if self.archived_groups.contains(name)
+                || self.archived_groups.iter().any(|g| fold_name(g) == folded)
+            {
+                BypassError::Inactive {
+                    resource_type: "team".into(),
+                    name: name.to_string(),
+                    status: "archived".into(),
+                }
+            } else {
+                BypassError::NameNotFound {
+                    resource_type: "team".into(),
+                    name: name.to_string(),
+                    hint: did_you_mean(&self.suggest_groups(name)),
+                }
             }
             .into()
         })
@@ -115,14 +564,125 @@ impl Resolver {
         names.iter().map(|n| self.resolve_group(n)).collect()
     }
 
+    /// Resolve a workflow state by name, ignoring case and extra whitespace
+    /// when there is no exact match.  Accepts a raw integer string as a
+    /// workflow state ID, provided it exists in the workspace.
     pub fn resolve_workflow_state(&self, name: &str) -> Result<i64> {
+        if let Ok(id) = name.trim().parse::<i64>() {
+            if self.workflow_state_ids.contains(&id) {
+                return Ok(id);
+            }
+            return Err(BypassError::NameNotFound {
+                resource_type: "workflow state ID".into(),
+                name: name.to_string(),
+                hint: String::new(),
+            }
+            .into());
+        }
         self.workflow_state_map
             .get(name.trim())
+            .or_else(|| self.workflow_state_folded.get(&fold_name(name))?.as_ref())
             .copied()
             .ok_or_else(|| {
                 BypassError::NameNotFound {
                     resource_type: "workflow state".into(),
                     name: name.to_string(),
+                    hint: did_you_mean(&self.suggest_workflow_states(name)),
+                }
+                .into()
+            })
+    }
+
+    /// Resolve a project by name.  Accepts a raw integer string as a
+    /// pass-through numeric ID.
+    pub fn resolve_project(&self, name: &str) -> Result<i64> {
+        if let Ok(id) = name.trim().parse::<i64>() {
+            return Ok(id);
+        }
+        self.project_map.get(name.trim()).copied().ok_or_else(|| {
+            BypassError::NameNotFound {
+                resource_type: "project".into(),
+                name: name.to_string(),
+                hint: String::new(),
+            }
+            .into()
+        })
+    }
+
+    /// Resolve an iteration by name.  Accepts a raw integer string as a
+    /// pass-through numeric ID.
+    pub fn resolve_iteration(&self, name: &str) -> Result<i64> {
+        if let Ok(id) = name.trim().parse::<i64>() {
+            return Ok(id);
+        }
+        self.iteration_map.get(name.trim()).copied().ok_or_else(|| {
+            BypassError::NameNotFound {
+                resource_type: "iteration".into(),
+                name: name.to_string(),
+                hint: String::new(),
+            }
+            .into()
+        })
+    }
+
+    /// The end date of the existing iteration `reference` (a name or
+    /// numeric ID), if it resolves and has one.
+    pub fn iteration_end_date(&self, reference: &str) -> Option<&str> {
+        let id = self.resolve_iteration(reference).ok()?;
+        self.iteration_end_dates.get(&id).map(String::as_str)
+    }
+
+    /// The custom field named `name`, ignoring case and extra whitespace.
+    pub fn custom_field(&self, name: &str) -> Option<&CustomField> {
+        self.custom_fields
+            .iter()
+            .find(|cf| fold_name(&cf.name) == fold_name(name))
+    }
+
+    /// Resolve a custom field and one of its values by name (as
+    /// [`Resolver::custom_field`]).
+    pub fn resolve_custom_field(&self, field: &str, value: &str) -> Result<CustomFieldValueParams> {
+        let cf = self
+            .custom_field(field)
+            .ok_or_else(|| BypassError::NameNotFound {
+                resource_type: "custom field".into(),
+                name: field.to_string(),
+                hint: String::new(),
+            })?;
+        let v = cf
+            .values
+            .iter()
+            .find(|v| fold_name(&v.value) == fold_name(value))
+            .ok_or_else(|| BypassError::NameNotFound {
+                resource_type: format!("'{}' value", cf.name),
+                name: value.to_string(),
+                hint: String::new(),
+            })?;
+        Ok(CustomFieldValueParams {
+            field_id: cf.id.clone(),
+            value_id: v.id.clone(),
+        })
+    }
+
+    fn load_categories(&mut self, categories: &[Category]) {
+        for c in categories.iter().filter(|c| !c.archived) {
+            self.category_map.insert(c.name.clone(), c.id);
+        }
+    }
+
+    /// Resolve a category by name, ignoring case and extra whitespace.
+    /// Returns the name as the workspace spells it, which is what the API
+    /// matches categories by.
+    pub fn resolve_category(&self, name: &str) -> Result<&str> {
+        self.category_map
+            .keys()
+            .find(|n| fold_name(n) == fold_name(name))
+            .map(String::as_str)
+            .ok_or_else(|| {
+                BypassError::NameNotFound {
+                    resource_type: "category".into(),
+                    name: name.to_string(),
+                    hint: String::new(),
                 }
                 .into()
             })
@@ -138,36 +698,130 @@ impl Resolver {
             BypassError::NameNotFound {
                 resource_type: "objective".into(),
                 name: name.to_string(),
+                hint: String::new(),
             }
             .into()
         })
     }
 
     /// Resolve an epic by name.  Accepts a raw integer string as a
-    /// pass-through numeric ID.
+    /// pass-through numeric ID, `label:<name>` for the single epic
+    /// carrying that label, or `Objective / Epic` to pick the epic of that
+    /// name under the given objective.
     pub fn resolve_epic(&self, name: &str) -> Result<i64> {
         if let Ok(id) = name.trim().parse::<i64>() {
             return Ok(id);
         }
-        self.epic_map.get(name.trim()).copied().ok_or_else(|| {
+        if let Some(label) = epic_label_ref(name) {
+            return self.resolve_epic_by_label(label);
+        }
+        if self.ambiguous_epics.contains(name.trim()) {
+            return Err(BypassError::InvalidInput(format!(
+                "epic name '{}' is used under more than one objective; \
+                 qualify it as 'Objective / {}'",
+                name.trim(),
+                name.trim()
+            ))
+            .into());
+        }
+        self.epic_map.get(&epic_key(name)).copied().ok_or_else(|| {
             BypassError::NameNotFound {
                 resource_type: "epic".into(),
                 name: name.to_string(),
+                hint: String::new(),
+            }
+            .into()
+        })
+    }
+
+    /// Resolve a story created in this run by name.  Accepts a raw integer
+    /// string as a pass-through numeric ID.
+    pub fn resolve_story(&self, name: &str) -> Result<i64> {
+        if let Ok(id) = name.trim().parse::<i64>() {
+            return Ok(id);
+        }
+        self.story_map.get(name.trim()).copied().ok_or_else(|| {
+            BypassError::NameNotFound {
+                resource_type: "story".into(),
+                name: name.to_string(),
+                hint: String::new(),
             }
             .into()
         })
     }
 
+    fn resolve_epic_by_label(&self, label: &str) -> Result<i64> {
+        match self.epic_label_map.get(label).map(Vec::as_slice) {
+            Some([id]) => Ok(*id),
+            Some(ids) if ids.len() > 1 => Err(BypassError::InvalidInput(format!(
+                "label '{label}' is on {} epics ({}); it must identify exactly one",
+                ids.len(),
+                ids.iter()
+                    .map(|id| format!("#{id}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+            .into()),
+            _ => Err(BypassError::NameNotFound {
+                resource_type: "epic labelled".into(),
+                name: label.to_string(),
+                hint: String::new(),
+            }
+            .into()),
+        }
+    }
+
     // ------------------------------------------------------------------
     // Registration (called after successful creation)
     // ------------------------------------------------------------------
 
-    pub fn register_objective(&mut self, name: String, id: i64) {
+    pub fn register_iteration(&mut self, name: String, id: i64) {
+        self.iteration_map.insert(name, id);
+    }
+
+    pub fn register_objective(&mut self, name: String, id: i64, app_url: Option<String>) {
+        if let Some(url) = app_url {
+            self.objective_url_map.insert(name.clone(), url);
+        }
         self.objective_map.insert(name, id);
     }
 
-    pub fn register_epic(&mut self, name: String, id: i64) {
-        self.epic_map.insert(name, id);
+    /// Register a created epic under its name and, when it belongs to an
+    /// objective, under `Objective / Epic` as well.
+    pub fn register_epic(
+        &mut self,
+        name: String,
+        objective: Option<&str>,
+        id: i64,
+        app_url: Option<String>,
+    ) {
+        let mut keys = vec![name.clone()];
+        if let Some(obj) = objective {
+            keys.push(qualified_epic_key(obj, &name));
+        }
+        if self.epic_map.get(&name).is_some_and(|&prev| prev != id) {
+            self.ambiguous_epics.insert(name);
+        }
+        for key in keys {
+            if let Some(url) = &app_url {
+                self.epic_url_map.insert(key.clone(), url.clone());
+            }
+            self.epic_map.insert(key, id);
+        }
+    }
+
+    pub fn register_story(&mut self, name: String, id: i64) {
+        self.story_map.insert(name, id);
+    }
+
+    /// Record a created epic's labels so later `label:` references see it.
+    /// Only labels that are actually referenced are tracked.
+    pub fn register_epic_labels(&mut self, labels: &[String], id: i64) {
+        for label in labels {
+            if let Some(ids) = self.epic_label_map.get_mut(label.trim()) {
+                ids.push(id);
+            }
+        }
     }
 
     // ------------------------------------------------------------------
@@ -185,4 +839,170 @@ impl Resolver {
     pub fn available_workflow_states(&self) -> Vec<&str> {
         self.workflow_state_map.keys().map(|s| s.as_str()).collect()
     }
+
+    pub fn available_projects(&self) -> Vec<&str> {
+        self.project_map.keys().map(|s| s.as_str()).collect()
+    }
+
+    pub fn available_iterations(&self) -> Vec<&str> {
+        self.iteration_map.keys().map(|s| s.as_str()).collect()
+    }
+
+    /// Member names closest to an unresolved `name` (see [`suggest`]).
+    pub fn suggest_members(&self, name: &str) -> Vec<&str> {
+        suggest(name, &self.member_map)
+    }
+
+    pub fn suggest_groups(&self, name: &str) -> Vec<&str> {
+        suggest(name, &self.group_map)
+    }
+
+    pub fn suggest_workflow_states(&self, name: &str) -> Vec<&str> {
+        suggest(name, &self.workflow_state_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> Resolver {
+        let fixture: Fixture = serde_json::from_value(serde_json::json!({
+            "members": [
+                {"id": "m-ada", "profile": {"name": "Ada Lovelace", "mention_name": "ada",
+                                            "email_address": "ada@corp.com"}},
+                {"id": "m-old", "profile": {"name": "Old Timer", "mention_name": "old"},
+                 "disabled": true}
+            ],
+            "groups": [
+                {"id": "g-plat", "name": "Platform", "mention_name": "platform"},
+                {"id": "g-gone", "name": "Legacy", "mention_name": "legacy", "archived": true}
+            ],
+            "workflows": [{
+                "id": 500, "name": "Engineering", "default_state_id": 500001,
+                "states": [
+                    {"id": 500001, "name": "Backlog", "type": "unstarted"},
+                    {"id": 500002, "name": "In Progress", "type": "started"}
+                ]
+            }]
+        }))
+        .unwrap();
+        Resolver::from_fixture(&fixture, &[], &[]).unwrap()
+    }
+
+    #[test]
+    fn workflow_state_by_known_id() {
+        assert_eq!(resolver().resolve_workflow_state("500002").unwrap(), 500002);
+    }
+
+    #[test]
+    fn workflow_state_by_unknown_id() {
+        let err = resolver().resolve_workflow_state("999").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BypassError>(),
+            Some(BypassError::NameNotFound { resource_type, .. }) if resource_type == "workflow state ID"
+        ));
+    }
+
+    #[test]
+    fn workflow_state_by_name() {
+        let resolver = resolver();
+        assert_eq!(
+            resolver.resolve_workflow_state("In Progress").unwrap(),
+            500002
+        );
+        assert_eq!(
+            resolver.resolve_workflow_state(" in progress ").unwrap(),
+            500002
+        );
+    }
+
+    #[test]
+    fn disabled_member_is_inactive() {
+        let err = resolver().resolve_member("Old Timer").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BypassError>(),
+            Some(BypassError::Inactive { status, .. }) if status == "disabled"
+        ));
+    }
+
+    #[test]
+    fn archived_group_is_inactive() {
+        let err = resolver().resolve_group("legacy").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BypassError>(),
+            Some(BypassError::Inactive { status, .. }) if status == "archived"
+        ));
+    }
+
+    #[test]
+    fn plus_addressed_email_resolves() {
+        let resolver = resolver();
+        assert_eq!(
+            resolver.normalize_email("Ada+Shortcut@Corp.com"),
+            "ada@corp.com"
+        );
+        assert_eq!(
+            resolver.resolve_member("ada+jira@corp.com").unwrap(),
+            "m-ada"
+        );
+    }
+
+    #[test]
+    fn names_match_ignoring_case_and_spacing() {
+        let resolver = resolver();
+        assert_eq!(
+            resolver.resolve_member("  ADA   lovelace ").unwrap(),
+            "m-ada"
+        );
+        assert_eq!(resolver.resolve_group("PLATFORM").unwrap(), "g-plat");
+    }
+
+    #[test]
+    fn names_folding_together_stay_exact_match_only() {
+        let fixture: Fixture = serde_json::from_value(serde_json::json!({
+            "members": [
+                {"id": "m-1", "profile": {"name": "Sam Lee", "mention_name": "sam"}},
+                {"id": "m-2", "profile": {"name": "sam lee", "mention_name": "slee"}}
+            ]
+        }))
+        .unwrap();
+        let resolver = Resolver::from_fixture(&fixture, &[], &[]).unwrap();
+        assert_eq!(resolver.resolve_member("Sam Lee").unwrap(), "m-1");
+        assert_eq!(resolver.resolve_member("sam lee").unwrap(), "m-2");
+        assert!(resolver.resolve_member("SAM LEE").is_err());
+    }
+
+    #[test]
+    fn one_letter_typo_suggests_the_name() {
+        let resolver = resolver();
+        let err = resolver.resolve_member("Ada Lovelase").unwrap_err();
+        assert!(
+            err.to_string().ends_with(" — did you mean 'Ada Lovelace'?"),
+            "{err}"
+        );
+        assert_eq!(resolver.suggest_workflow_states("Backlg"), ["Backlog"]);
+        assert!(resolver.suggest_workflow_states("Shipped").is_empty());
+        assert_eq!(
+            did_you_mean(&["Backlog", "Blocked"]),
+            " — did you mean 'Backlog' or 'Blocked'?"
+        );
+    }
+
+    #[test]
+    fn aliased_domain_email_resolves() {
+        let mut resolver = resolver();
+        resolver.set_email_domain_map(HashMap::from([(
+            "Old-Corp.com".to_string(),
+            "corp.com".to_string(),
+        )]));
+        assert_eq!(
+            resolver.normalize_email("ada+x@old-corp.com"),
+            "ada@corp.com"
+        );
+        assert_eq!(
+            resolver.resolve_member("ADA@old-corp.com").unwrap(),
+            "m-ada"
+        );
+    }
 }
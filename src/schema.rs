@@ -0,0 +1,50 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::input::models::InputFile;
+
+/// Check `input` against a user-supplied JSON Schema (`--validate-schema`).
+///
+/// The instance is the input as parsed, serialized back into the YAML
+/// layout (`objectives` / `epics` / `stories` lists with canonical field
+/// names), so one schema covers every input format.  The schema file may
+/// be JSON or YAML.
+///
+/// Returns one message per violation, led by the JSON pointer of the
+/// offending value, e.g. `/stories/3/name (story 'fix login'): …`.
+pub fn check(path: &Path, input: &InputFile) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read schema '{}': {}", path.display(), e))?;
+    let schema: serde_json::Value = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse schema '{}': {}", path.display(), e))?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|e| anyhow::anyhow!("Invalid schema '{}': {}", path.display(), e))?;
+
+    let instance = serde_json::to_value(input)?;
+    Ok(validator
+        .iter_errors(&instance)
+        .map(|e| {
+            let pointer = e.instance_path.as_str();
+            match resource_name(input, pointer) {
+                Some(what) => format!("{pointer} ({what}): {e}"),
+                None => format!("{}: {e}", if pointer.is_empty() { "/" } else { pointer }),
+            }
+        })
+        .collect())
+}
+
+/// `kind 'name'` for a pointer into one of the resource lists.
+fn resource_name(input: &InputFile, pointer: &str) -> Option<String> {
+    let mut parts = pointer.trim_start_matches('/').split('/');
+    let list = parts.next()?;
+    let index: usize = parts.next()?.parse().ok()?;
+    let (kind, name) = match list {
+        "labels" => ("label", &input.labels.get(index)?.name),
+        "iterations" => ("iteration", &input.iterations.get(index)?.name),
+        "objectives" => ("objective", &input.objectives.get(index)?.name),
+        "epics" => ("epic", &input.epics.get(index)?.name),
+        "stories" => ("story", &input.stories.get(index)?.name),
+        _ => return None,
+    };
+    Some(format!("{kind} '{name}'"))
+}
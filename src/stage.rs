@@ -0,0 +1,38 @@
+use anyhow::Result;
+
+use crate::input::models::InputStory;
+use crate::resolver::Resolver;
+use crate::validate::list_sample;
+
+/// Label prefix that records a staged story's intended workflow state.
+pub const INTENDED_PREFIX: &str = "intended:";
+
+/// The intended workflow state carried by a label, if it is an
+/// `intended:<state>` label.
+pub fn intended_state(label: &str) -> Option<&str> {
+    label.strip_prefix(INTENDED_PREFIX).map(str::trim)
+}
+
+/// Fail early when the staging workflow state does not exist.
+pub fn check_staging_state(resolver: &Resolver, staging: &str) -> Result<i64> {
+    resolver.resolve_workflow_state(staging).map_err(|_| {
+        anyhow::anyhow!(
+            "Staging workflow state '{staging}' not found. Available: {}",
+            list_sample(resolver.available_workflow_states())
+        )
+    })
+}
+
+/// Force `story` into the staging state for `--stage`, stashing the state it
+/// would otherwise get in an `intended:<state>` label for `bypass promote`.
+/// Stories without a state record the workspace default state by ID.
+pub fn stage_story(story: &mut InputStory, staging: &str, resolver: &Resolver) {
+    let intended = story
+        .workflow_state
+        .take()
+        .or_else(|| resolver.default_workflow_state_id.map(|id| id.to_string()));
+    if let Some(state) = intended {
+        story.labels.push(format!("{INTENDED_PREFIX}{state}"));
+    }
+    story.workflow_state = Some(staging.to_string());
+}
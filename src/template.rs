@@ -1,14 +1,14 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::path::Path;
 
-use crate::input::models::InputEpic;
+use crate::input::models::{CLEAR, InputEpic, InputObjective, InputStory};
 
-/// A markdown template for epic descriptions.
+/// A markdown template for resource descriptions.
 ///
-/// The template file is read once and rendered per-epic by replacing
-/// `{{variable}}` placeholders with the epic's field values.
+/// The template file is read once and rendered per-resource by replacing
+/// `{{variable}}` placeholders with the resource's field values.
 ///
-/// Available variables:
+/// Epic variables:
 /// - `{{name}}`        – epic name
 /// - `{{description}}` – raw description from the input (may be empty)
 /// - `{{objective}}`   – linked objective name (may be empty)
@@ -17,11 +17,28 @@ use crate::input::models::InputEpic;
 /// - `{{labels}}`      – comma-separated label names
 /// - `{{start_date}}`  – planned start date
 /// - `{{deadline}}`    – deadline
+///
+/// Objective variables:
+/// - `{{name}}`, `{{description}}`, `{{state}}`
+///
+/// Story variables:
+/// - `{{name}}`, `{{description}}`, `{{epic}}`, `{{owners}}`, `{{team}}`,
+///   `{{labels}}`, `{{estimate}}`, `{{due_date}}`, `{{workflow_state}}`,
+///   `{{iteration}}`
+///
+/// Any template may also use `{{env.VAR_NAME}}`, which renders the process
+/// environment variable `VAR_NAME` (empty when unset).
 #[derive(Clone)]
 pub struct Template {
     content: String,
 }
 
+/// A resource whose fields can be substituted into a [`Template`].
+pub trait Templatable {
+    /// Placeholder name (without braces) → value.
+    fn template_vars(&self) -> Vec<(&'static str, String)>;
+}
+
 impl Template {
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)
@@ -29,32 +46,194 @@ impl Template {
         Ok(Self { content })
     }
 
-    /// Render the template with values from `epic`.
+    /// Render the template with values from `item` and the environment.
     /// Unrecognised placeholders are left as-is.
-    pub fn render(&self, epic: &InputEpic) -> String {
-        let vars: &[(&str, &str)] = &[
-            ("name", &epic.name),
+    pub fn render<T: Templatable>(&self, item: &T) -> String {
+        self.render_with(item, |var| std::env::var(var).ok())
+    }
+
+    /// [`render`](Self::render), looking variables up with `env`.
+    fn render_with<T: Templatable>(
+        &self,
+        item: &T,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> String {
+        let mut result = self.content.clone();
+        for (key, value) in item.template_vars() {
+            result = result.replace(&format!("{{{{{key}}}}}"), &value);
+        }
+        for var in self.env_vars() {
+            let value = env(var).unwrap_or_default();
+            result = result.replace(&format!("{{{{env.{var}}}}}"), &value);
+        }
+        result
+    }
+
+    /// Fail when an `{{env.VAR_NAME}}` placeholder names an unset variable
+    /// (`--strict-templates`).
+    pub fn check_env(&self) -> Result<()> {
+        self.check_env_with(|var| std::env::var_os(var).is_some())
+    }
+
+    /// [`check_env`](Self::check_env), asking `is_set` whether a variable
+    /// is set.
+    fn check_env_with(&self, is_set: impl Fn(&str) -> bool) -> Result<()> {
+        let mut unset: Vec<&str> = self.env_vars().into_iter().filter(|v| !is_set(v)).collect();
+        if !unset.is_empty() {
+            unset.sort_unstable();
+            unset.dedup();
+            bail!(
+                "Template uses unset environment variable(s): {}",
+                unset.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Placeholders still present in `rendered` output, in order of first
+    /// appearance (`template check`).
+    pub fn leftovers(rendered: &str) -> Vec<String> {
+        let mut found: Vec<String> = Vec::new();
+        for rest in rendered.split("{{").skip(1) {
+            if let Some((inner, _)) = rest.split_once("}}") {
+                let placeholder = format!("{{{{{inner}}}}}");
+                if !found.contains(&placeholder) {
+                    found.push(placeholder);
+                }
+            }
+        }
+        found
+    }
+
+    /// Names referenced by `{{env.VAR_NAME}}` placeholders.
+    fn env_vars(&self) -> Vec<&str> {
+        self.content
+            .split("{{env.")
+            .skip(1)
+            .filter_map(|rest| rest.split_once("}}").map(|(name, _)| name))
+            .filter(|name| {
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+            .collect()
+    }
+}
+
+impl Templatable for InputEpic {
+    fn template_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.clone()),
+            ("description", self.description.clone().unwrap_or_default()),
+            ("objective", self.objective.clone().unwrap_or_default()),
+            ("owners", self.owners.join(", ")),
+            ("teams", self.teams.join(", ")),
+            ("labels", self.labels.join(", ")),
+            ("start_date", date_var(&self.start_date)),
+            ("deadline", date_var(&self.deadline)),
+        ]
+    }
+}
+
+impl Templatable for InputObjective {
+    fn template_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.clone()),
+            ("description", self.description.clone().unwrap_or_default()),
+            ("state", self.state.clone().unwrap_or_default()),
+        ]
+    }
+}
+
+impl Templatable for InputStory {
+    fn template_vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("name", self.name.clone()),
+            ("description", self.description.clone().unwrap_or_default()),
+            ("epic", self.epic.clone().unwrap_or_default()),
+            ("owners", self.owners.join(", ")),
+            ("team", self.team.clone().unwrap_or_default()),
+            ("labels", self.labels.join(", ")),
+            (
+                "estimate",
+                self.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            ),
+            ("due_date", date_var(&self.due_date)),
             (
-                "description",
-                epic.description.as_deref().unwrap_or_default(),
+                "workflow_state",
+                self.workflow_state.clone().unwrap_or_default(),
             ),
-            ("objective", epic.objective.as_deref().unwrap_or_default()),
-            ("start_date", epic.start_date.as_deref().unwrap_or_default()),
-            ("deadline", epic.deadline.as_deref().unwrap_or_default()),
-        ];
+            ("iteration", self.iteration.clone().unwrap_or_default()),
+        ]
+    }
+}
 
-        // Build strings for multi-value fields so we can borrow them.
-        let owners = epic.owners.join(", ");
-        let teams = epic.teams.join(", ");
-        let labels = epic.labels.join(", ");
+/// A date placeholder value; the [`CLEAR`] sentinel renders empty.
+fn date_var(value: &Option<String>) -> String {
+    value
+        .as_deref()
+        .filter(|v| v.trim() != CLEAR)
+        .unwrap_or_default()
+        .to_string()
+}
 
-        let mut result = self.content.clone();
-        for (key, value) in vars {
-            result = result.replace(&format!("{{{{{key}}}}}"), value);
+/// Replace `{{<kind>_url}}` with the `app_url` of a resource created earlier
+/// in the run and `{{<kind>_link}}` with a markdown link to it, e.g.
+/// `[Onboarding Redesign](https://app.shortcut.com/…/epic/123)`.
+///
+/// Used by `--link-references`.  When the URL is unknown (no reference, or
+/// a pre-existing resource given by ID) the URL renders empty and the link
+/// renders as the plain reference text.
+pub fn link_reference(text: &str, kind: &str, name: Option<&str>, url: Option<&str>) -> String {
+    let name = name.unwrap_or_default();
+    let link = match url {
+        Some(u) => format!("[{name}]({u})"),
+        None => name.to_string(),
+    };
+    text.replace(&format!("{{{{{kind}_url}}}}"), url.unwrap_or_default())
+        .replace(&format!("{{{{{kind}_link}}}}"), &link)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(content: &str) -> Template {
+        Template {
+            content: content.to_string(),
         }
-        result = result.replace("{{owners}}", &owners);
-        result = result.replace("{{teams}}", &teams);
-        result = result.replace("{{labels}}", &labels);
-        result
+    }
+
+    /// An environment holding only `STAGE=prod`.
+    fn env(var: &str) -> Option<String> {
+        (var == "STAGE").then(|| "prod".to_string())
+    }
+
+    #[test]
+    fn render_substitutes_set_env_var() {
+        let epic = InputEpic::named("Checkout", 1);
+        let rendered = template("{{name}} in {{env.STAGE}}").render_with(&epic, env);
+        assert_eq!(rendered, "Checkout in prod");
+    }
+
+    #[test]
+    fn render_leaves_unset_env_var_empty() {
+        let epic = InputEpic::named("Checkout", 1);
+        let rendered = template("[{{env.REGION}}]").render_with(&epic, env);
+        assert_eq!(rendered, "[]");
+    }
+
+    #[test]
+    fn check_env_rejects_unset_env_var() {
+        let err = template("{{env.STAGE}} {{env.REGION}} {{env.not-a-var}}")
+            .check_env_with(|v| env(v).is_some())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Template uses unset environment variable(s): REGION"
+        );
+        assert!(
+            template("{{env.STAGE}}")
+                .check_env_with(|v| env(v).is_some())
+                .is_ok()
+        );
     }
 }
@@ -0,0 +1,786 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::BypassError;
+use crate::input::models::{
+    CLEAR, HEALTH_VALUES, InputEpic, InputFile, InputIteration, InputKeyResult, InputLink,
+    InputObjective, InputStory, KEY_RESULT_TYPES, health_status,
+};
+use crate::policy::Policy;
+use crate::resolver::{
+    Resolver, did_you_mean, epic_key, epic_label_ref, epic_qualified_ref, qualified_epic_key,
+};
+use crate::stage::intended_state;
+
+/// Per-resource validation shared by `--dry-run` and `--stream`.
+///
+/// Checks required fields, enum values, file references, name resolution
+/// against the workspace, in-batch cross-references, and policy rules.
+/// Each check appends human-readable messages to `errors`.
+pub struct Validator<'a> {
+    resolver: &'a Resolver,
+    /// Names of iterations/objectives/epics defined in the batch, for
+    /// cross-references.
+    batch_iterations: HashSet<&'a str>,
+    batch_objectives: HashSet<&'a str>,
+    /// Epic name (and `Objective / Epic` key) → number of batch epics.
+    batch_epics: HashMap<String, usize>,
+    /// In-batch epics per label, for `label:<name>` references.
+    batch_epic_labels: HashMap<&'a str, usize>,
+    /// Story name → number of batch stories, for `story_links`.
+    batch_stories: HashMap<&'a str, usize>,
+    /// Team group ID → allowed workflow state names (from the policy).
+    team_states: HashMap<String, &'a [String]>,
+    /// Allowed story `type` values.
+    story_types: Vec<&'a str>,
+}
+
+impl<'a> Validator<'a> {
+    /// Build the validator for `input`.  Problems with the policy itself
+    /// (e.g. unknown team names) are reported into `errors`.
+    pub fn new(
+        input: &'a InputFile,
+        resolver: &'a Resolver,
+        policy: &'a Policy,
+        errors: &mut Vec<String>,
+    ) -> Self {
+        // Resolve policy team names up front so stories can reference a team by
+        // either its name or mention name.
+        let mut team_states: HashMap<String, &[String]> = HashMap::new();
+        for (team, states) in &policy.team_workflow_states {
+            match resolver.resolve_group(team) {
+                Ok(id) => {
+                    team_states.insert(id, states.as_slice());
+                }
+                Err(_) => errors.push(format!(
+                    "Policy: unknown team '{team}' in team_workflow_states. Available: {}",
+                    list_sample(resolver.available_groups())
+                )),
+            }
+        }
+
+        check_positions(input, errors);
+        check_capacity(input, errors);
+
+        let mut batch_epics: HashMap<String, usize> = HashMap::new();
+        for epic in &input.epics {
+            *batch_epics.entry(epic.name.clone()).or_default() += 1;
+            if let Some(obj) = &epic.objective {
+                *batch_epics
+                    .entry(qualified_epic_key(obj, &epic.name))
+                    .or_default() += 1;
+            }
+        }
+
+        let mut batch_epic_labels: HashMap<&str, usize> = HashMap::new();
+        for epic in &input.epics {
+            for label in &epic.labels {
+                *batch_epic_labels.entry(label.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut batch_stories: HashMap<&str, usize> = HashMap::new();
+        for story in &input.stories {
+            *batch_stories.entry(story.name.trim()).or_default() += 1;
+        }
+
+        Self {
+            resolver,
+            batch_iterations: input.iterations.iter().map(|i| i.name.as_str()).collect(),
+            batch_objectives: input.objectives.iter().map(|o| o.name.as_str()).collect(),
+            batch_epics,
+            batch_epic_labels,
+            batch_stories,
+            team_states,
+            story_types: policy.story_types(),
+        }
+    }
+
+    /// Validate every resource in `input`.
+    pub fn all(&self, input: &InputFile, errors: &mut Vec<String>) {
+        check_labels(input, errors);
+        check_iteration_names(input, errors);
+        for iteration in &input.iterations {
+            self.iteration(iteration, errors);
+        }
+        for obj in &input.objectives {
+            self.objective(obj, errors);
+        }
+        for epic in &input.epics {
+            self.epic(epic, errors);
+        }
+        for story in &input.stories {
+            self.story(story, errors);
+        }
+    }
+
+    pub fn iteration(&self, iteration: &InputIteration, errors: &mut Vec<String>) {
+        if !iteration_fields(iteration, errors) {
+            return;
+        }
+        let what = format!("Iteration '{}'", iteration.name);
+        for team in &iteration.teams {
+            check_team(self.resolver, &what, team, errors);
+        }
+    }
+
+    pub fn objective(&self, obj: &InputObjective, errors: &mut Vec<String>) {
+        objective_fields(obj, errors);
+        for category in &obj.categories {
+            if self.resolver.resolve_category(category).is_err() {
+                errors.push(format!(
+                    "Objective '{}': unknown category '{category}'{}",
+                    obj.name,
+                    match self.resolver.category_map.is_empty() {
+                        true => String::new(),
+                        false => format!(
+                            ". Available: {}",
+                            list_sample(
+                                self.resolver
+                                    .category_map
+                                    .keys()
+                                    .map(String::as_str)
+                                    .collect()
+                            )
+                        ),
+                    }
+                ));
+            }
+        }
+    }
+
+    pub fn epic(&self, epic: &InputEpic, errors: &mut Vec<String>) {
+        let resolver = self.resolver;
+        if !epic_fields(epic, errors) {
+            return;
+        }
+        let what = format!("Epic '{}'", epic.name);
+        for owner in &epic.owners {
+            check_owner(resolver, &what, owner, errors);
+        }
+        for follower in &epic.followers {
+            check_owner(resolver, &format!("{what} follower"), follower, errors);
+        }
+        for team in &epic.teams {
+            check_team(resolver, &what, team, errors);
+        }
+        if let Some(obj) = &epic.objective
+            && obj.parse::<i64>().is_err()
+            && !self.batch_objectives.contains(obj.as_str())
+            && !resolver.objective_map.contains_key(obj.trim())
+        {
+            errors.push(format!(
+                "Epic '{}': objective '{obj}' not found in current batch \
+                     (use a numeric ID to reference a pre-existing objective)",
+                epic.name
+            ));
+        }
+    }
+
+    pub fn story(&self, story: &InputStory, errors: &mut Vec<String>) {
+        let resolver = self.resolver;
+        if !story_fields(story, &self.story_types, errors) {
+            return;
+        }
+        let what = format!("Story '{}'", story.name);
+        for owner in &story.owners {
+            check_owner(resolver, &what, owner, errors);
+        }
+        for follower in &story.followers {
+            check_owner(resolver, &format!("{what} follower"), follower, errors);
+        }
+        if let Some(requester) = &story.requested_by {
+            check_owner(resolver, &format!("{what} requester"), requester, errors);
+        }
+        for (i, comment) in story.comments.iter().enumerate() {
+            if let Some(author) = &comment.author {
+                check_owner(
+                    resolver,
+                    &format!("{what} comment {}", i + 1),
+                    author,
+                    errors,
+                );
+            }
+        }
+        for (field, value) in &story.custom_fields {
+            check_custom_field(resolver, &what, field, value, errors);
+        }
+        for task in &story.tasks {
+            if let Some(owner) = &task.owner {
+                check_owner(
+                    resolver,
+                    &format!("{what} task '{}'", task.name),
+                    owner,
+                    errors,
+                );
+            }
+        }
+        if let Some(team) = &story.team {
+            check_team(resolver, &what, team, errors);
+        }
+        for (verb, target) in story.story_links.iter() {
+            if target.parse::<i64>().is_ok() {
+                continue;
+            }
+            match self.batch_stories.get(target.trim()) {
+                Some(1) => {}
+                Some(n) => errors.push(format!(
+                    "{what}: '{verb}' target '{target}' is the name of {n} stories in the \
+                     current batch; use a numeric ID"
+                )),
+                None => errors.push(format!(
+                    "{what}: '{verb}' target '{target}' not found in current batch \
+                     (use a numeric ID to reference a pre-existing story)"
+                )),
+            }
+        }
+        if let Some(label) = story.epic.as_deref().and_then(epic_label_ref) {
+            let existing = resolver.epic_label_map.get(label).map_or(0, Vec::len);
+            let in_batch = self.batch_epic_labels.get(label).copied().unwrap_or(0);
+            match existing + in_batch {
+                1 => {}
+                0 => errors.push(format!(
+                    "Story '{}': no epic carries label '{label}'",
+                    story.name
+                )),
+                n => errors.push(format!(
+                    "Story '{}': label '{label}' is on {n} epics; it must identify exactly one",
+                    story.name
+                )),
+            }
+        } else if let Some(epic) = &story.epic
+            && epic.parse::<i64>().is_err()
+        {
+            let key = epic_key(epic);
+            match self.batch_epics.get(&key) {
+                Some(1) => {}
+                Some(n) if epic_qualified_ref(epic).is_some() => errors.push(format!(
+                    "Story '{}': epic '{key}' matches {n} epics in the current batch",
+                    story.name
+                )),
+                Some(n) => errors.push(format!(
+                    "Story '{}': epic name '{key}' is used by {n} epics in the current batch; \
+                     qualify it as 'Objective / {key}'",
+                    story.name
+                )),
+                None if resolver.epic_map.contains_key(&key) => {}
+                None => errors.push(format!(
+                    "Story '{}': epic '{epic}' not found in current batch \
+                     (use a numeric ID to reference a pre-existing epic)",
+                    story.name
+                )),
+            }
+        }
+        if let Some(ws) = &story.workflow_state
+            && resolver.resolve_workflow_state(ws).is_err()
+        {
+            errors.push(format!(
+                "Story '{}': unknown workflow state '{}'{}",
+                story.name,
+                ws,
+                unknown_hint(
+                    resolver.suggest_workflow_states(ws),
+                    resolver.available_workflow_states()
+                )
+            ));
+        }
+        for intended in story.labels.iter().filter_map(|l| intended_state(l)) {
+            if resolver.resolve_workflow_state(intended).is_err() {
+                errors.push(format!(
+                    "Story '{}': unknown intended workflow state '{intended}'{}",
+                    story.name,
+                    unknown_hint(
+                        resolver.suggest_workflow_states(intended),
+                        resolver.available_workflow_states()
+                    )
+                ));
+            }
+        }
+        if let Some(iteration) = &story.iteration
+            && !self.batch_iterations.contains(iteration.trim())
+            && resolver.resolve_iteration(iteration).is_err()
+        {
+            let available = list_sample(resolver.available_iterations());
+            errors.push(format!(
+                "Story '{}': unknown iteration '{iteration}'{}",
+                story.name,
+                if available.is_empty() {
+                    String::new()
+                } else {
+                    format!(". Available: {available}")
+                }
+            ));
+        }
+        if let Some(project) = &story.project
+            && resolver.resolve_project(project).is_err()
+        {
+            errors.push(format!(
+                "Story '{}': unknown project '{project}'. Available: {}",
+                story.name,
+                list_sample(resolver.available_projects())
+            ));
+        }
+        // A staged story sits in the staging state; the team rule applies to
+        // the state it is promoted to.
+        let final_state = story
+            .labels
+            .iter()
+            .find_map(|l| intended_state(l))
+            .or(story.workflow_state.as_deref());
+        if let (Some(team), Some(ws)) = (&story.team, final_state)
+            && let Ok(team_id) = resolver.resolve_group(team)
+            && let Ok(state_id) = resolver.resolve_workflow_state(ws)
+            && let Some(allowed) = self.team_states.get(&team_id)
+            && !allowed
+                .iter()
+                .any(|s| resolver.resolve_workflow_state(s).ok() == Some(state_id))
+        {
+            errors.push(format!(
+                "Story '{}': workflow state '{ws}' is not allowed for team '{team}'. \
+                 Allowed: {}",
+                story.name,
+                allowed.join(", ")
+            ));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Structural checks
+// ---------------------------------------------------------------------------
+
+/// Checks that need no workspace data: required fields, enum values, dates,
+/// template files and epic positions.  This is all `--dry-run-level
+/// offline` runs; [`Validator`] runs it as part of every check.
+pub fn offline(input: &InputFile, policy: &Policy, errors: &mut Vec<String>) {
+    let story_types = policy.story_types();
+    check_positions(input, errors);
+    check_capacity(input, errors);
+    check_labels(input, errors);
+    check_iteration_names(input, errors);
+    for iteration in &input.iterations {
+        iteration_fields(iteration, errors);
+    }
+    for obj in &input.objectives {
+        objective_fields(obj, errors);
+    }
+    for epic in &input.epics {
+        epic_fields(epic, errors);
+    }
+    for story in &input.stories {
+        story_fields(story, &story_types, errors);
+    }
+}
+
+/// Label names are required and unique in a batch; a color is `#rrggbb`.
+fn check_labels(input: &InputFile, errors: &mut Vec<String>) {
+    let mut seen = HashSet::new();
+    for label in &input.labels {
+        if label.name.trim().is_empty() {
+            errors.push("Label: 'name' is required".into());
+            continue;
+        }
+        if !seen.insert(label.name.trim().to_lowercase()) {
+            errors.push(format!("Label '{}' is defined more than once", label.name));
+        }
+        if let Some(color) = &label.color {
+            let hex = color.trim().strip_prefix('#').unwrap_or_default();
+            if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                errors.push(format!(
+                    "Label '{}': color '{color}' is not a hex color such as '#3b82f6'",
+                    label.name
+                ));
+            }
+        }
+    }
+}
+
+/// Returns false when the iteration has no name.  Both dates are required
+/// and the iteration must not end before it starts.
+fn iteration_fields(iteration: &InputIteration, errors: &mut Vec<String>) -> bool {
+    if iteration.name.is_empty() {
+        errors.push("Iteration: 'name' is required".into());
+        return false;
+    }
+    let what = format!("Iteration '{}'", iteration.name);
+    let mut dated = true;
+    for (field, value) in [
+        ("start_date", &iteration.start_date),
+        ("end_date", &iteration.end_date),
+    ] {
+        let before = errors.len();
+        if value.trim().is_empty() || value.trim() == CLEAR {
+            errors.push(format!("{what}: '{field}' is required"));
+        } else {
+            check_date(&what, field, &Some(value.clone()), errors);
+        }
+        dated &= errors.len() == before;
+    }
+    let day = |d: &str| d.trim().split('T').next().unwrap_or_default().to_string();
+    if dated && day(&iteration.end_date) < day(&iteration.start_date) {
+        errors.push(format!(
+            "{what}: end_date {} is before start_date {}",
+            iteration.end_date.trim(),
+            iteration.start_date.trim()
+        ));
+    }
+    true
+}
+
+/// Iterations are referenced by name, so names must be unique in a batch.
+fn check_iteration_names(input: &InputFile, errors: &mut Vec<String>) {
+    let mut seen = HashSet::new();
+    for iteration in &input.iterations {
+        if !iteration.name.is_empty() && !seen.insert(iteration.name.trim()) {
+            errors.push(format!(
+                "Iteration '{}' is defined more than once",
+                iteration.name
+            ));
+        }
+    }
+}
+
+fn objective_fields(obj: &InputObjective, errors: &mut Vec<String>) {
+    if obj.name.is_empty() {
+        errors.push("Objective: 'name' is required".into());
+    }
+    if let Some(state) = &obj.state {
+        validate_objective_state(state, &obj.name, errors);
+    }
+    let what = format!("Objective '{}'", obj.name);
+    check_template(&what, &obj.template, errors);
+    for kr in &obj.key_results {
+        check_key_result(&what, kr, errors);
+    }
+}
+
+/// A key result needs a name and a known type; a boolean key result takes
+/// no start or target, and percentages lie between 0 and 100.
+fn check_key_result(what: &str, kr: &InputKeyResult, errors: &mut Vec<String>) {
+    if kr.name.is_empty() {
+        errors.push(format!("{what}: every key result needs a name"));
+        return;
+    }
+    let kind = kr.kind.as_deref().unwrap_or("numeric");
+    if !KEY_RESULT_TYPES.contains(&kind) {
+        errors.push(format!(
+            "{what}: key result '{}' has invalid type '{kind}'. Must be one of: {}",
+            kr.name,
+            KEY_RESULT_TYPES.join(", ")
+        ));
+        return;
+    }
+    let values = [kr.start, kr.target];
+    if kind == "boolean" && values.iter().any(Option::is_some) {
+        errors.push(format!(
+            "{what}: boolean key result '{}' takes no start or target",
+            kr.name
+        ));
+    }
+    if kind == "percent" && values.iter().flatten().any(|v| !(0.0..=100.0).contains(v)) {
+        errors.push(format!(
+            "{what}: percent key result '{}' must stay between 0 and 100",
+            kr.name
+        ));
+    }
+}
+
+/// Returns false when the epic has no name, since nothing else about it
+/// can be reported usefully.
+fn epic_fields(epic: &InputEpic, errors: &mut Vec<String>) -> bool {
+    if epic.name.is_empty() {
+        errors.push("Epic: 'name' is required".into());
+        return false;
+    }
+    if let Some(state) = &epic.state {
+        validate_epic_state(state, &epic.name, errors);
+    }
+    if let Some(health) = &epic.health
+        && health_status(health).is_none()
+    {
+        let allowed: Vec<&str> = HEALTH_VALUES.iter().map(|(v, _)| *v).collect();
+        errors.push(format!(
+            "Epic '{}': invalid health '{health}'. Must be one of: {}",
+            epic.name,
+            allowed.join(", ")
+        ));
+    }
+    let what = format!("Epic '{}'", epic.name);
+    check_date(&what, "start_date", &epic.start_date, errors);
+    check_date(&what, "deadline", &epic.deadline, errors);
+    check_template(&what, &epic.template, errors);
+    check_links(&what, &epic.links, errors);
+    true
+}
+
+/// As [`epic_fields`], for stories.
+fn story_fields(story: &InputStory, story_types: &[&str], errors: &mut Vec<String>) -> bool {
+    if story.name.is_empty() {
+        errors.push("Story: 'name' is required".into());
+        return false;
+    }
+    if let Some(t) = &story.story_type
+        && !story_types.contains(&t.as_str())
+    {
+        errors.push(format!(
+            "Story '{}': invalid type '{t}'. Must be one of: {}",
+            story.name,
+            story_types.join(", ")
+        ));
+    }
+    let what = format!("Story '{}'", story.name);
+    check_date(&what, "due_date", &story.due_date, errors);
+    check_template(&what, &story.template, errors);
+    check_links(&what, &story.links, errors);
+    if story.tasks.iter().any(|t| t.name.is_empty()) {
+        errors.push(format!("{what}: every task needs a name"));
+    }
+    for (field, value) in &story.custom_fields {
+        if value.is_empty() {
+            errors.push(format!("{what}: custom field '{field}' has no value"));
+        }
+    }
+    if story.comments.iter().any(|c| c.text.is_empty()) {
+        errors.push(format!("{what}: every comment needs text"));
+    }
+    for (verb, target) in story.story_links.iter() {
+        if target.trim().is_empty() {
+            errors.push(format!("{what}: empty '{verb}' target"));
+        } else if target.trim() == story.name.trim() {
+            errors.push(format!("{what}: '{verb}' target is the story itself"));
+        }
+    }
+    true
+}
+
+/// Explicit epic positions must be unique within an objective.
+fn check_positions(input: &InputFile, errors: &mut Vec<String>) {
+    let mut positions: HashMap<(&str, i64), Vec<&str>> = HashMap::new();
+    for epic in &input.epics {
+        if let (Some(obj), Some(pos)) = (&epic.objective, epic.position) {
+            positions
+                .entry((obj.trim(), pos))
+                .or_default()
+                .push(epic.name.as_str());
+        }
+    }
+    let mut clashes: Vec<_> = positions.into_iter().filter(|(_, v)| v.len() > 1).collect();
+    clashes.sort_unstable();
+    for ((obj, pos), names) in clashes {
+        errors.push(format!(
+            "Objective '{obj}': position {pos} is used by more than one epic ({})",
+            names.join(", ")
+        ));
+    }
+}
+
+/// The estimates of the batch's stories in an epic must not add up to
+/// more than the epic's `capacity`.
+fn check_capacity(input: &InputFile, errors: &mut Vec<String>) {
+    for epic in &input.epics {
+        let Some(capacity) = epic.capacity else {
+            continue;
+        };
+        if capacity < 0 {
+            errors.push(format!(
+                "Epic '{}': capacity must not be negative, got {capacity}",
+                epic.name
+            ));
+            continue;
+        }
+        let qualified = epic
+            .objective
+            .as_ref()
+            .map(|obj| qualified_epic_key(obj, &epic.name));
+        let total: i64 = input
+            .stories
+            .iter()
+            .filter(|s| {
+                s.epic.as_deref().map(epic_key).is_some_and(|key| {
+                    key == epic.name || qualified.as_deref() == Some(key.as_str())
+                })
+            })
+            .filter_map(|s| s.estimate)
+            .sum();
+        if total > capacity {
+            errors.push(format!(
+                "Epic '{}': its stories in this batch total {total} points, \
+                 over its capacity of {capacity}",
+                epic.name
+            ));
+        }
+    }
+}
+
+fn check_template(what: &str, template: &Option<String>, errors: &mut Vec<String>) {
+    if let Some(tmpl_path) = template
+        && !std::path::Path::new(tmpl_path).exists()
+    {
+        errors.push(format!("{what}: template file '{tmpl_path}' not found"));
+    }
+}
+
+/// Link URLs must be absolute `http(s)://` URLs with a host.
+fn check_links(what: &str, links: &[InputLink], errors: &mut Vec<String>) {
+    for link in links {
+        let host = link
+            .url
+            .strip_prefix("https://")
+            .or_else(|| link.url.strip_prefix("http://"))
+            .and_then(|rest| rest.split(['/', '?', '#']).next())
+            .unwrap_or_default();
+        if host.is_empty() || link.url.contains(char::is_whitespace) {
+            errors.push(format!(
+                "{what}: invalid link URL '{}'. Expected an http:// or https:// URL",
+                link.url
+            ));
+        }
+    }
+}
+
+/// Dates must start with an ISO 8601 calendar date (`YYYY-MM-DD`); a time
+/// part after `T` is passed through to the API.
+fn check_date(what: &str, field: &str, value: &Option<String>, errors: &mut Vec<String>) {
+    let Some(value) = value.as_deref().filter(|v| v.trim() != CLEAR) else {
+        return;
+    };
+    let date = value.trim().split('T').next().unwrap_or_default();
+    let parts: Vec<&str> = date.split('-').collect();
+    let valid = match parts.as_slice() {
+        [y, m, d] if y.len() == 4 && m.len() == 2 && d.len() == 2 => {
+            match (y.parse::<u32>(), m.parse::<u32>(), d.parse::<u32>()) {
+                (Ok(y), Ok(m), Ok(d)) => (1..=days_in_month(y, m)).contains(&d),
+                _ => false,
+            }
+        }
+        _ => false,
+    };
+    if !valid {
+        errors.push(format!(
+            "{what}: invalid {field} '{value}'. Expected an ISO 8601 date (YYYY-MM-DD)"
+        ));
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Report an owner that does not resolve, distinguishing disabled members
+/// from unknown names.
+fn check_owner(resolver: &Resolver, what: &str, owner: &str, errors: &mut Vec<String>) {
+    match resolver.resolve_member(owner) {
+        Ok(_) => {}
+        Err(e) if is_inactive(&e) => errors.push(format!("{what}: {e}")),
+        Err(_) => errors.push(format!(
+            "{what}: unknown user '{owner}'{}",
+            unknown_hint(
+                resolver.suggest_members(owner),
+                resolver.available_members()
+            )
+        )),
+    }
+}
+
+/// A story's custom field and value must both exist; an empty value is
+/// reported by [`story_fields`].
+fn check_custom_field(
+    resolver: &Resolver,
+    what: &str,
+    field: &str,
+    value: &str,
+    errors: &mut Vec<String>,
+) {
+    let available = |names: Vec<&str>| match names.is_empty() {
+        true => String::new(),
+        false => format!(". Available: {}", list_sample(names)),
+    };
+    match resolver.custom_field(field) {
+        None => errors.push(format!(
+            "{what}: unknown custom field '{field}'{}",
+            available(
+                resolver
+                    .custom_fields
+                    .iter()
+                    .map(|cf| cf.name.as_str())
+                    .collect()
+            )
+        )),
+        Some(cf) if !value.is_empty() && resolver.resolve_custom_field(field, value).is_err() => {
+            errors.push(format!(
+                "{what}: unknown {} value '{value}'{}",
+                cf.name,
+                available(cf.values.iter().map(|v| v.value.as_str()).collect())
+            ))
+        }
+        Some(_) => {}
+    }
+}
+
+/// As [`check_owner`], for teams (archived vs unknown).
+fn check_team(resolver: &Resolver, what: &str, team: &str, errors: &mut Vec<String>) {
+    match resolver.resolve_group(team) {
+        Ok(_) => {}
+        Err(e) if is_inactive(&e) => errors.push(format!("{what}: {e}")),
+        Err(_) => errors.push(format!(
+            "{what}: unknown team '{team}'{}",
+            unknown_hint(resolver.suggest_groups(team), resolver.available_groups())
+        )),
+    }
+}
+
+/// The tail of an unknown-name error: the closest names as a "did you
+/// mean" hint, or a sample of the available names when none is close.
+fn unknown_hint(suggestions: Vec<&str>, available: Vec<&str>) -> String {
+    match did_you_mean(&suggestions) {
+        hint if hint.is_empty() => format!(". Available: {}", list_sample(available)),
+        hint => hint,
+    }
+}
+
+fn is_inactive(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<BypassError>(),
+        Some(BypassError::Inactive { .. })
+    )
+}
+
+fn validate_objective_state(state: &str, name: &str, errors: &mut Vec<String>) {
+    if !["in progress", "to do", "done"].contains(&state) {
+        errors.push(format!(
+            "Objective '{name}': invalid state '{state}'. \
+             Must be 'in progress', 'to do', or 'done'"
+        ));
+    }
+}
+
+fn validate_epic_state(state: &str, name: &str, errors: &mut Vec<String>) {
+    if !["in progress", "to do", "done"].contains(&state) {
+        errors.push(format!(
+            "Epic '{name}': invalid state '{state}'. \
+             Must be 'in progress', 'to do', or 'done'"
+        ));
+    }
+}
+
+pub fn list_sample(mut items: Vec<&str>) -> String {
+    items.sort_unstable();
+    items.dedup();
+    let preview: Vec<&str> = items.iter().copied().take(5).collect();
+    if items.len() > 5 {
+        format!("{} … (+{})", preview.join(", "), items.len() - 5)
+    } else {
+        preview.join(", ")
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::api::ShortcutClient;
+use crate::input::models::{InputEpic, InputObjective, InputStory};
+use crate::resolver::Resolver;
+
+/// `--verify`: read back a created objective and compare name and state.
+/// Returns one message per mismatch.
+pub async fn objective(
+    client: &ShortcutClient,
+    input: &InputObjective,
+    id: i64,
+) -> Result<Vec<String>> {
+    let got = client.get_objective(id).await?;
+    let mut mismatches = Vec::new();
+    compare(&mut mismatches, "name", &input.name, &got.name);
+    if let Some(state) = &input.state {
+        compare(&mut mismatches, "state", state, &got.state);
+    }
+    Ok(mismatches)
+}
+
+/// As [`objective`], for an epic's name, state, and owners.
+pub async fn epic(
+    client: &ShortcutClient,
+    input: &InputEpic,
+    resolver: &Resolver,
+    id: i64,
+) -> Result<Vec<String>> {
+    let got = client.get_epic(id).await?;
+    let mut mismatches = Vec::new();
+    compare(&mut mismatches, "name", &input.name, &got.name);
+    if let Some(state) = &input.state {
+        compare(&mut mismatches, "state", state, &got.state);
+    }
+    compare_owners(&mut mismatches, resolver, &input.owners, &got.owner_ids)?;
+    Ok(mismatches)
+}
+
+/// As [`objective`], for a story's name, type, owners, workflow state, and
+/// estimate.
+pub async fn story(
+    client: &ShortcutClient,
+    input: &InputStory,
+    resolver: &Resolver,
+    id: i64,
+) -> Result<Vec<String>> {
+    let got = client.get_story(id).await?;
+    let mut mismatches = Vec::new();
+    compare(&mut mismatches, "name", &input.name, &got.name);
+    if let Some(t) = &input.story_type {
+        compare(&mut mismatches, "type", t, &got.story_type);
+    }
+    compare_owners(&mut mismatches, resolver, &input.owners, &got.owner_ids)?;
+    let state = match &input.workflow_state {
+        Some(name) => Some(resolver.resolve_workflow_state(name)?),
+        None => resolver.default_workflow_state_id,
+    };
+    if let Some(state) = state {
+        compare(
+            &mut mismatches,
+            "workflow state ID",
+            &state,
+            &got.workflow_state_id.unwrap_or_default(),
+        );
+    }
+    if let Some(estimate) = input.estimate {
+        compare(
+            &mut mismatches,
+            "estimate",
+            &estimate,
+            &got.estimate.unwrap_or_default(),
+        );
+    }
+    Ok(mismatches)
+}
+
+fn compare<T: PartialEq + Display + ?Sized>(
+    mismatches: &mut Vec<String>,
+    field: &str,
+    sent: &T,
+    got: &T,
+) {
+    if sent != got {
+        mismatches.push(format!("{field} is '{got}', sent '{sent}'"));
+    }
+}
+
+fn compare_owners(
+    mismatches: &mut Vec<String>,
+    resolver: &Resolver,
+    owners: &[String],
+    got: &[String],
+) -> Result<()> {
+    let sent: BTreeSet<String> = resolver.resolve_members(owners)?.into_iter().collect();
+    let got: BTreeSet<String> = got.iter().cloned().collect();
+    if sent != got {
+        mismatches.push(format!(
+            "owners are {} member(s), sent {}",
+            got.len(),
+            sent.len()
+        ));
+    }
+    Ok(())
+}